@@ -10,6 +10,11 @@ export type ThreadRollbackParams = { threadId: string,
  * The number of turns to drop from the end of the thread. Must be >= 1.
  *
  * This only modifies the thread's history and does not revert local file changes
- * that have been made by the agent. Clients are responsible for reverting these changes.
+ * that have been made by the agent. Clients are responsible for reverting these changes,
+ * or can set `undo_file_changes` to have the server restore them from git snapshots.
  */
-numTurns: number, };
+numTurns: number, /**
+ * When true, also restore the git snapshot recorded before the dropped turns so
+ * their local file changes are reverted (best-effort; requires `git_snapshots`).
+ */
+undoFileChanges?: boolean | null, };
@@ -153,7 +153,9 @@ pub(crate) fn command_actions_for_path_uri(
         .iter()
         .cloned()
         .filter_map(|parsed| match parsed {
-            ParsedCommand::Read { cmd, name, path } => match native_cwd.as_ref() {
+            ParsedCommand::Read {
+                cmd, name, path, ..
+            } => match native_cwd.as_ref() {
                 Some(native_cwd) => Some(CommandAction::Read {
                     command: cmd,
                     name,
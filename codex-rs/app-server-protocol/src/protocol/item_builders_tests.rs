@@ -12,6 +12,7 @@ fn foreign_read_is_omitted_without_dropping_other_command_actions() {
             cmd: "cat file.txt".to_string(),
             name: "file.txt".to_string(),
             path: PathBuf::from("file.txt"),
+            range: None,
         },
         ParsedCommand::ListFiles {
             cmd: "ls".to_string(),
@@ -186,6 +186,7 @@ impl CommandAction {
                 cmd,
                 name,
                 path: path.into_path_buf(),
+                range: None,
             },
             CommandAction::ListFiles { command: cmd, path } => {
                 CoreParsedCommand::ListFiles { cmd, path }
@@ -201,7 +202,9 @@ impl CommandAction {
 
     pub fn from_core_with_cwd(value: CoreParsedCommand, cwd: &AbsolutePathBuf) -> Self {
         match value {
-            CoreParsedCommand::Read { cmd, name, path } => CommandAction::Read {
+            CoreParsedCommand::Read {
+                cmd, name, path, ..
+            } => CommandAction::Read {
                 command: cmd,
                 name,
                 path: cwd.join(path),
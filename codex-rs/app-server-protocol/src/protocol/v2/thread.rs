@@ -1048,8 +1048,13 @@ pub struct ThreadRollbackParams {
     /// The number of turns to drop from the end of the thread. Must be >= 1.
     ///
     /// This only modifies the thread's history and does not revert local file changes
-    /// that have been made by the agent. Clients are responsible for reverting these changes.
+    /// that have been made by the agent. Clients are responsible for reverting these changes,
+    /// or can set `undo_file_changes` to have the server restore them from git snapshots.
     pub num_turns: u32,
+    /// When true, also restore the git snapshot recorded before the dropped turns so
+    /// their local file changes are reverted (best-effort; requires `git_snapshots`).
+    #[ts(optional = nullable)]
+    pub undo_file_changes: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
@@ -1773,6 +1773,7 @@ impl ThreadRequestProcessor {
         let ThreadRollbackParams {
             thread_id,
             num_turns,
+            undo_file_changes,
         } = params;
 
         if num_turns == 0 {
@@ -1799,14 +1800,12 @@ impl ThreadRequestProcessor {
             ));
         }
 
-        if let Err(err) = self
-            .submit_core_op(
-                request_id,
-                thread.as_ref(),
-                Op::ThreadRollback { num_turns },
-            )
-            .await
-        {
+        let op = if undo_file_changes.unwrap_or(false) {
+            Op::UndoTurns { num_turns }
+        } else {
+            Op::ThreadRollback { num_turns }
+        };
+        if let Err(err) = self.submit_core_op(request_id, thread.as_ref(), op).await {
             // No ThreadRollback event will arrive if an error occurs.
             // Clean up and reply immediately.
             let thread_state = self.thread_state_manager.thread_state(thread_id).await;
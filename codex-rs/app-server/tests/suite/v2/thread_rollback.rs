@@ -50,6 +50,7 @@ async fn thread_rollback_does_not_emit_deprecation_notice_to_codex_tui() -> Resu
         .send_thread_rollback_request(ThreadRollbackParams {
             thread_id: "00000000-0000-0000-0000-000000000001".to_string(),
             num_turns: 1,
+            undo_file_changes: None,
         })
         .await?;
     loop {
@@ -155,6 +156,7 @@ async fn thread_rollback_drops_last_turns_and_persists_to_rollout() -> Result<()
         .send_thread_rollback_request(ThreadRollbackParams {
             thread_id: thread.id.clone(),
             num_turns: 1,
+            undo_file_changes: None,
         })
         .await?;
     let deprecation_notice = timeout(DEFAULT_READ_TIMEOUT, mcp.read_next_message()).await??;
@@ -789,9 +789,10 @@ fn compute_replacements(
             line_index = start_idx + pattern.len();
         } else {
             return Err(ApplyPatchError::ComputeReplacements(format!(
-                "Failed to find expected lines in {}:\n{}",
+                "Failed to find expected lines in {}:\n{}\n{}",
                 path,
                 chunk.old_lines.join("\n"),
+                describe_closest_mismatch(original_lines, pattern),
             )));
         }
     }
@@ -801,6 +802,51 @@ fn compute_replacements(
     Ok(replacements)
 }
 
+/// Build a structured mismatch report for a context block that could not be
+/// located: find the position where the most leading pattern lines match
+/// (whitespace-insensitively) and name the first divergent line, so the model
+/// can refresh just the stale context instead of retrying blind.
+fn describe_closest_mismatch(lines: &[String], pattern: &[String]) -> String {
+    if pattern.is_empty() || lines.is_empty() {
+        return "(file is empty)".to_string();
+    }
+    let mut best_offset = 0usize;
+    let mut best_matched = 0usize;
+    for offset in 0..lines.len() {
+        let matched = pattern
+            .iter()
+            .zip(&lines[offset..])
+            .take_while(|(expected, actual)| expected.trim() == actual.trim())
+            .count();
+        if matched > best_matched {
+            best_matched = matched;
+            best_offset = offset;
+        }
+    }
+    if best_matched == 0 {
+        return "(no similar context found; the file may have changed substantially)".to_string();
+    }
+    let divergent_line = best_offset + best_matched;
+    match (pattern.get(best_matched), lines.get(divergent_line)) {
+        (Some(expected), Some(actual)) => format!(
+            "Closest match: {best_matched} of {} context line(s) match at line {}. First mismatch at line {}: patch expects `{expected}` but the file has `{actual}`.",
+            pattern.len(),
+            best_offset + 1,
+            divergent_line + 1,
+        ),
+        (Some(expected), None) => format!(
+            "Closest match: {best_matched} of {} context line(s) match at line {}. The file ends before expected line `{expected}`.",
+            pattern.len(),
+            best_offset + 1,
+        ),
+        _ => format!(
+            "Closest match: {best_matched} of {} context line(s) match at line {}.",
+            pattern.len(),
+            best_offset + 1,
+        ),
+    }
+}
+
 /// Apply the `(start_index, old_len, new_lines)` replacements to `original_lines`,
 /// returning the modified file contents as a vector of lines.
 fn apply_replacements(
@@ -0,0 +1,163 @@
+//! `codex config` subcommands for inspecting configuration profiles.
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_config::CONFIG_TOML_FILE;
+use codex_config::config_error_from_ignored_toml_fields;
+use codex_config::config_toml::ConfigToml;
+use codex_config::profile_toml::ConfigProfile;
+use codex_protocol::config_types::SandboxMode;
+use codex_protocol::protocol::AskForApproval;
+use codex_utils_home_dir::find_codex_home;
+
+#[derive(Debug, clap::Parser)]
+pub struct ConfigCli {
+    #[command(subcommand)]
+    pub subcommand: ConfigSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ConfigSubcommand {
+    /// Inspect configuration profiles defined in `config.toml`.
+    #[command(subcommand)]
+    Profiles(ProfilesSubcommand),
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ProfilesSubcommand {
+    /// List profiles and how many keys each one overrides.
+    List,
+    /// Show every key a profile overrides.
+    Show {
+        /// Profile name.
+        name: String,
+    },
+    /// Validate profiles: unknown keys, type errors with TOML spans, and
+    /// conflicting sandbox/approval combinations.
+    Validate,
+}
+
+impl ConfigCli {
+    pub fn run(self) -> Result<()> {
+        let codex_home = find_codex_home().context("failed to resolve CODEX_HOME")?;
+        let config_path = codex_home.as_path().join(CONFIG_TOML_FILE);
+        let contents = match std::fs::read_to_string(&config_path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read {}", config_path.display()));
+            }
+        };
+
+        match self.subcommand {
+            ConfigSubcommand::Profiles(ProfilesSubcommand::List) => {
+                let config = parse_config(&contents, &config_path)?;
+                let mut names: Vec<_> = config.profiles.keys().collect();
+                names.sort();
+                if names.is_empty() {
+                    println!("No profiles defined in {}.", config_path.display());
+                    return Ok(());
+                }
+                for name in names {
+                    let overrides = overridden_keys(&config.profiles[name]);
+                    println!("{name} ({} overrides)", overrides.len());
+                }
+            }
+            ConfigSubcommand::Profiles(ProfilesSubcommand::Show { name }) => {
+                let config = parse_config(&contents, &config_path)?;
+                let Some(profile) = config.profiles.get(&name) else {
+                    bail!("no profile named `{name}` in {}", config_path.display());
+                };
+                let overrides = overridden_keys(profile);
+                if overrides.is_empty() {
+                    println!("Profile `{name}` overrides no keys.");
+                    return Ok(());
+                }
+                for (key, value) in overrides {
+                    println!("{key} = {value}");
+                }
+            }
+            ConfigSubcommand::Profiles(ProfilesSubcommand::Validate) => {
+                let config = parse_config(&contents, &config_path)?;
+                // Unknown keys anywhere in the document, reported with spans.
+                if let Some(error) =
+                    config_error_from_ignored_toml_fields::<ConfigToml>(&config_path, &contents)
+                {
+                    println!(
+                        "{}:{}:{}: {}",
+                        error.path.display(),
+                        error.range.start.line,
+                        error.range.start.column,
+                        error.message
+                    );
+                    bail!("config validation failed");
+                }
+
+                let mut warnings = 0usize;
+                let mut names: Vec<_> = config.profiles.keys().collect();
+                names.sort();
+                for name in names {
+                    let profile = &config.profiles[name];
+                    if matches!(profile.approval_policy, Some(AskForApproval::Never))
+                        && matches!(profile.sandbox_mode, Some(SandboxMode::DangerFullAccess))
+                    {
+                        println!(
+                            "warning: profile `{name}` combines approval_policy=never with sandbox_mode=danger-full-access; commands run unsandboxed without any approval"
+                        );
+                        warnings += 1;
+                    }
+                }
+                if warnings == 0 {
+                    println!("Profiles OK ({} checked).", config.profiles.len());
+                } else {
+                    println!(
+                        "Profiles checked: {} ({warnings} warning(s)).",
+                        config.profiles.len()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_config(contents: &str, config_path: &std::path::Path) -> Result<ConfigToml> {
+    toml::from_str(contents).with_context(|| format!("failed to parse {}", config_path.display()))
+}
+
+/// The keys a profile overrides, as sorted `(key, rendered value)` pairs.
+fn overridden_keys(profile: &ConfigProfile) -> Vec<(String, String)> {
+    let Ok(toml::Value::Table(table)) = toml::Value::try_from(profile) else {
+        return Vec::new();
+    };
+    let mut overrides: Vec<(String, String)> = table
+        .into_iter()
+        .map(|(key, value)| (key, value.to_string()))
+        .collect();
+    overrides.sort();
+    overrides
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn overridden_keys_lists_only_set_fields() {
+        let profile: ConfigProfile = toml::from_str(
+            r#"
+model = "gpt-5"
+approval_policy = "never"
+"#,
+        )
+        .expect("profile should parse");
+
+        let overrides = overridden_keys(&profile);
+        let keys: Vec<&str> = overrides.iter().map(|(key, _)| key.as_str()).collect();
+
+        assert_eq!(keys, vec!["approval_policy", "model"]);
+    }
+}
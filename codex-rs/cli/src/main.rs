@@ -46,6 +46,7 @@ use supports_color::Stream;
 
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod app_cmd;
+mod config_cmd;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod desktop_app;
 mod doctor;
@@ -56,6 +57,7 @@ mod plugin_cmd;
 mod remote_control_cmd;
 #[cfg(target_os = "windows")]
 mod sandbox_setup;
+mod sessions_cmd;
 mod state_db_recovery;
 #[cfg(not(windows))]
 mod wsl_paths;
@@ -135,6 +137,12 @@ enum Subcommand {
     /// Remove stored authentication credentials.
     Logout(LogoutCommand),
 
+    /// Inspect Codex configuration (profiles).
+    Config(crate::config_cmd::ConfigCli),
+
+    /// Inspect recorded sessions (aggregate statistics over rollouts).
+    Sessions(crate::sessions_cmd::SessionsCli),
+
     /// Manage external MCP servers for Codex.
     Mcp(McpCli),
 
@@ -1049,6 +1057,27 @@ async fn cli_main(
             )
             .await?;
         }
+        Some(Subcommand::Sessions(sessions_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "sessions",
+            )?;
+            let codex_home = find_codex_home()?;
+            match sessions_cli.command {
+                sessions_cmd::SessionsSubcommand::Stats(args) => {
+                    sessions_cmd::run_sessions_stats(&codex_home, args).await?;
+                }
+            }
+        }
+        Some(Subcommand::Config(config_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "config",
+            )?;
+            config_cli.run()?;
+        }
         Some(Subcommand::Mcp(mut mcp_cli)) => {
             reject_remote_mode_for_subcommand(
                 root_remote.as_deref(),
@@ -2122,6 +2151,7 @@ fn unsupported_subcommand_name_for_strict_config(
             Some(app_server_subcommand_name(app_server.subcommand.as_ref()))
         }
         Some(Subcommand::RemoteControl(remote_control)) => Some(remote_control.subcommand_name()),
+        Some(Subcommand::Config(_)) => Some("config"),
         Some(Subcommand::Mcp(_)) => Some("mcp"),
         Some(Subcommand::Plugin(_)) => Some("plugin"),
         #[cfg(any(target_os = "macos", target_os = "windows"))]
@@ -30,6 +30,7 @@ use codex_mcp::resolve_oauth_scopes;
 use codex_mcp::should_retry_without_scopes;
 use codex_protocol::protocol::McpAuthStatus;
 use codex_rmcp_client::delete_oauth_tokens;
+use codex_rmcp_client::perform_oauth_device_login;
 use codex_rmcp_client::perform_oauth_login;
 use codex_utils_cli::CliConfigOverrides;
 use codex_utils_cli::format_env_display;
@@ -56,6 +57,8 @@ pub enum McpSubcommand {
     Get(GetArgs),
     Add(AddArgs),
     Remove(RemoveArgs),
+    Enable(ToggleArgs),
+    Disable(ToggleArgs),
     Login(LoginArgs),
     Logout(LogoutArgs),
 }
@@ -154,6 +157,12 @@ pub struct RemoveArgs {
     pub name: String,
 }
 
+#[derive(Debug, clap::Parser)]
+pub struct ToggleArgs {
+    /// Name of the MCP server configuration to enable or disable.
+    pub name: String,
+}
+
 #[derive(Debug, clap::Parser)]
 pub struct LoginArgs {
     /// Name of the MCP server to authenticate with oauth.
@@ -162,6 +171,11 @@ pub struct LoginArgs {
     /// Comma-separated list of OAuth scopes to request.
     #[arg(long, value_delimiter = ',', value_name = "SCOPE,SCOPE")]
     pub scopes: Vec<String>,
+
+    /// Use the OAuth device code flow instead of a browser redirect. Useful on
+    /// headless or remote hosts; requires `oauth_client_id` to be configured.
+    #[arg(long = "device-code", default_value_t = false)]
+    pub device_code: bool,
 }
 
 #[derive(Debug, clap::Parser)]
@@ -194,6 +208,12 @@ impl McpCli {
             McpSubcommand::Remove(args) => {
                 run_remove(&config_overrides, args).await?;
             }
+            McpSubcommand::Enable(args) => {
+                run_set_enabled(args, /*enabled*/ true).await?;
+            }
+            McpSubcommand::Disable(args) => {
+                run_set_enabled(args, /*enabled*/ false).await?;
+            }
             McpSubcommand::Login(args) => {
                 run_login(&config_overrides, args).await?;
             }
@@ -356,6 +376,7 @@ async fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Re
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
+        max_retries: None,
         default_tools_approval_mode: None,
         enabled_tools: None,
         disabled_tools: None,
@@ -412,6 +433,21 @@ async fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Re
     Ok(())
 }
 
+/// Flip a server's `enabled` flag in config.toml, preserving the entry's
+/// comments and formatting. Running sessions pick the change up on their next
+/// MCP refresh (or config hot-reload).
+async fn run_set_enabled(args: ToggleArgs, enabled: bool) -> Result<()> {
+    let codex_home = find_codex_home().context("failed to resolve CODEX_HOME")?;
+    ConfigEditsBuilder::new(codex_home.as_path())
+        .set_mcp_server_enabled(&args.name, enabled)
+        .apply()
+        .await
+        .with_context(|| format!("failed to update MCP server `{}`", args.name))?;
+    let state = if enabled { "enabled" } else { "disabled" };
+    println!("MCP server '{}' is now {state}.", args.name);
+    Ok(())
+}
+
 async fn run_remove(config_overrides: &CliConfigOverrides, remove_args: RemoveArgs) -> Result<()> {
     config_overrides
         .parse_overrides()
@@ -457,7 +493,11 @@ async fn run_login(config_overrides: &CliConfigOverrides, login_args: LoginArgs)
     )));
     let mcp_servers = mcp_manager.configured_servers(&config).await;
 
-    let LoginArgs { name, scopes } = login_args;
+    let LoginArgs {
+        name,
+        scopes,
+        device_code,
+    } = login_args;
 
     let Some(server) = mcp_servers.get(&name) else {
         bail!("No MCP server named '{name}' found.");
@@ -482,20 +522,34 @@ async fn run_login(config_overrides: &CliConfigOverrides, login_args: LoginArgs)
     let resolved_scopes =
         resolve_oauth_scopes(explicit_scopes, server.scopes.clone(), discovered_scopes);
 
-    perform_oauth_login_retry_without_scopes(
-        &name,
-        &url,
-        config.mcp_oauth_credentials_store_mode,
-        config.auth_keyring_backend_kind(),
-        http_headers,
-        env_http_headers,
-        &resolved_scopes,
-        server.oauth_client_id(),
-        server.oauth_resource.as_deref(),
-        config.mcp_oauth_callback_port,
-        config.mcp_oauth_callback_url.as_deref(),
-    )
-    .await?;
+    if device_code {
+        perform_oauth_device_login(
+            &name,
+            &url,
+            config.mcp_oauth_credentials_store_mode,
+            config.auth_keyring_backend_kind(),
+            http_headers,
+            env_http_headers,
+            &resolved_scopes.scopes,
+            server.oauth_client_id(),
+        )
+        .await?;
+    } else {
+        perform_oauth_login_retry_without_scopes(
+            &name,
+            &url,
+            config.mcp_oauth_credentials_store_mode,
+            config.auth_keyring_backend_kind(),
+            http_headers,
+            env_http_headers,
+            &resolved_scopes,
+            server.oauth_client_id(),
+            server.oauth_resource.as_deref(),
+            config.mcp_oauth_callback_port,
+            config.mcp_oauth_callback_url.as_deref(),
+        )
+        .await?;
+    }
     println!("Successfully logged in to MCP server '{name}'.");
     Ok(())
 }
@@ -0,0 +1,223 @@
+//! `codex sessions` subcommands: aggregate statistics over recorded rollouts.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use clap::Parser;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::parse_command::ParsedCommand;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+
+#[derive(Debug, Parser)]
+pub struct SessionsCli {
+    #[command(subcommand)]
+    pub command: SessionsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SessionsSubcommand {
+    /// Aggregate statistics over recorded sessions: sessions per day, turn
+    /// counts, most-used tools, most-read files, and token totals.
+    Stats(StatsArgs),
+}
+
+#[derive(Debug, Parser)]
+pub struct StatsArgs {
+    /// Only include sessions recorded on or after this date (YYYY-MM-DD).
+    #[arg(long = "since", value_name = "DATE")]
+    pub since: Option<String>,
+}
+
+#[derive(Default)]
+struct Aggregates {
+    sessions: usize,
+    sessions_per_day: BTreeMap<String, usize>,
+    total_turns: usize,
+    tool_calls: HashMap<String, usize>,
+    read_files: HashMap<String, usize>,
+    total_input_tokens: i64,
+    total_output_tokens: i64,
+    skipped_files: usize,
+}
+
+pub async fn run_sessions_stats(codex_home: &Path, args: StatsArgs) -> anyhow::Result<()> {
+    let sessions_dir = codex_home.join(codex_core::SESSIONS_SUBDIR);
+    let mut aggregates = Aggregates::default();
+    let mut stack = vec![sessions_dir.clone()];
+    // Keyed by the plain `.jsonl` path so a rollout present in compressed
+    // form (`.jsonl.zst`) is aggregated once; the reader resolves whichever
+    // representation is on disk.
+    let mut files: BTreeSet<PathBuf> = BTreeSet::new();
+    while let Some(dir) = stack.pop() {
+        let Ok(mut entries) = tokio::fs::read_dir(&dir).await else {
+            continue;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "jsonl")
+                || path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.ends_with(".jsonl.zst"))
+            {
+                files.insert(codex_rollout::plain_rollout_path(&path));
+            }
+        }
+    }
+
+    for path in files {
+        if let Err(err) =
+            aggregate_session_file(&path, args.since.as_deref(), &mut aggregates).await
+        {
+            tracing::debug!("skipping unreadable rollout {}: {err}", path.display());
+            aggregates.skipped_files += 1;
+        }
+    }
+
+    print_aggregates(&aggregates);
+    Ok(())
+}
+
+async fn aggregate_session_file(
+    path: &Path,
+    since: Option<&str>,
+    aggregates: &mut Aggregates,
+) -> anyhow::Result<()> {
+    // The rollout reader transparently handles compressed files and
+    // decrypts encrypted lines when the sessions encryption key is in the
+    // environment; without the key the read fails and the file is skipped.
+    let mut reader = codex_rollout::open_rollout_line_reader(path).await?;
+    let mut counted_session = false;
+    let mut last_total: Option<(i64, i64)> = None;
+    while let Some(line) = reader.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Ok(parsed) = serde_json::from_str::<RolloutLine>(&line) else {
+            continue;
+        };
+        let day = parsed
+            .timestamp
+            .split('T')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        if !counted_session {
+            if let Some(since) = since
+                && day.as_str() < since
+            {
+                // Session predates the window; skip the whole file.
+                return Ok(());
+            }
+            counted_session = true;
+            aggregates.sessions += 1;
+            *aggregates.sessions_per_day.entry(day).or_default() += 1;
+        }
+        match parsed.item {
+            RolloutItem::EventMsg(EventMsg::TurnStarted(_)) => {
+                aggregates.total_turns += 1;
+            }
+            RolloutItem::EventMsg(EventMsg::TokenCount(event)) => {
+                if let Some(info) = event.info {
+                    last_total = Some((
+                        info.total_token_usage.input_tokens,
+                        info.total_token_usage.output_tokens,
+                    ));
+                }
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                name, arguments, ..
+            }) => {
+                *aggregates.tool_calls.entry(name.clone()).or_default() += 1;
+                record_read_files(&name, &arguments, aggregates);
+            }
+            _ => {}
+        }
+    }
+    if let Some((input, output)) = last_total {
+        aggregates.total_input_tokens += input.max(0);
+        aggregates.total_output_tokens += output.max(0);
+    }
+    Ok(())
+}
+
+/// Counts files read through the shell-style tools by reusing the shared
+/// command parser on their command arguments.
+fn record_read_files(tool_name: &str, arguments: &str, aggregates: &mut Aggregates) {
+    if !matches!(tool_name, "shell" | "shell_command" | "exec_command") {
+        return;
+    }
+    let Ok(arguments) = serde_json::from_str::<serde_json::Value>(arguments) else {
+        return;
+    };
+    let command = arguments
+        .get("command")
+        .or_else(|| arguments.get("cmd"))
+        .and_then(serde_json::Value::as_str);
+    let tokens = match (command, arguments.get("command").and_then(|v| v.as_array())) {
+        (Some(command), _) => match shlex::split(command) {
+            Some(tokens) => tokens,
+            None => return,
+        },
+        (None, Some(tokens)) => tokens
+            .iter()
+            .filter_map(serde_json::Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        (None, None) => return,
+    };
+    for parsed in codex_shell_command::parse_command::parse_command(&tokens) {
+        if let ParsedCommand::Read { path, .. } = parsed {
+            *aggregates
+                .read_files
+                .entry(path.display().to_string())
+                .or_default() += 1;
+        }
+    }
+}
+
+#[allow(clippy::print_stdout)]
+fn print_aggregates(aggregates: &Aggregates) {
+    println!("sessions: {}", aggregates.sessions);
+    if aggregates.sessions > 0 {
+        let average_turns = aggregates.total_turns as f64 / aggregates.sessions as f64;
+        println!(
+            "turns: {} total, {average_turns:.1} per session",
+            aggregates.total_turns
+        );
+    }
+    println!(
+        "tokens: {} input, {} output",
+        aggregates.total_input_tokens, aggregates.total_output_tokens
+    );
+
+    println!("\nsessions per day:");
+    for (day, count) in &aggregates.sessions_per_day {
+        println!("  {day}  {count}");
+    }
+
+    let mut top = |label: &str, map: &HashMap<String, usize>| {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        println!("\n{label}:");
+        for (name, count) in entries.into_iter().take(10) {
+            println!("  {count:>5}  {name}");
+        }
+    };
+    top("most-used tools", &aggregates.tool_calls);
+    top("most-read files", &aggregates.read_files);
+
+    if aggregates.skipped_files > 0 {
+        println!(
+            "\nskipped {} unreadable or encrypted rollout file(s)",
+            aggregates.skipped_files
+        );
+    }
+}
@@ -850,6 +850,9 @@ mod tests {
             service_tier: Some("priority".to_string()),
             prompt_cache_key: Some("cache-key".to_string()),
             text: None,
+            temperature: None,
+            top_p: None,
+            seed: None,
             generate: Some(false),
             client_metadata: Some(HashMap::from([(
                 "traceparent".to_string(),
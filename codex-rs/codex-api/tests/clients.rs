@@ -326,6 +326,9 @@ async fn responses_client_stream_request_preserves_item_ids() -> Result<()> {
         service_tier: None,
         prompt_cache_key: None,
         text: None,
+        temperature: None,
+        top_p: None,
+        seed: None,
         client_metadata: None,
     };
     let expected = serde_json::to_value(&request)?;
@@ -413,6 +416,9 @@ async fn streaming_client_retries_on_transport_error() -> Result<()> {
         service_tier: None,
         prompt_cache_key: None,
         text: None,
+        temperature: None,
+        top_p: None,
+        seed: None,
         client_metadata: None,
     };
     let client = ResponsesClient::new(transport.clone(), provider, Arc::new(NoAuth));
@@ -533,6 +539,9 @@ async fn azure_store_sends_ids_and_headers() -> Result<()> {
         service_tier: None,
         prompt_cache_key: None,
         text: None,
+        temperature: None,
+        top_p: None,
+        seed: None,
         client_metadata: None,
     };
 
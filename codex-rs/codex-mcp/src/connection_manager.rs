@@ -25,6 +25,7 @@ use crate::mcp::ToolPluginProvenance;
 use crate::rmcp_client::AsyncManagedClient;
 use crate::rmcp_client::CODEX_APPS_REFRESH_DURATION_METRIC;
 use crate::rmcp_client::DEFAULT_STARTUP_TIMEOUT;
+use crate::rmcp_client::DEFAULT_TOOL_TIMEOUT;
 use crate::rmcp_client::MCP_TOOLS_LIST_DURATION_METRIC;
 use crate::rmcp_client::ManagedClient;
 use crate::rmcp_client::StartupOutcomeError;
@@ -55,6 +56,7 @@ use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::McpServerHealth;
 use codex_protocol::protocol::McpStartupCompleteEvent;
 use codex_protocol::protocol::McpStartupFailure;
 use codex_protocol::protocol::McpStartupFailureReason;
@@ -751,6 +753,84 @@ impl McpConnectionManager {
         aggregated
     }
 
+    /// Tear down and re-spawn the named server's client in the background.
+    /// Startup progress is reported through the usual MCP startup events.
+    pub fn restart_server(&self, server_name: &str) -> bool {
+        self.clients
+            .get(server_name)
+            .is_some_and(AsyncManagedClient::restart)
+    }
+
+    /// Number of servers whose startup has completed (successfully or not).
+    pub fn started_server_count(&self) -> usize {
+        self.clients
+            .values()
+            .filter(|client| client.startup_complete.load(Ordering::Acquire))
+            .count()
+    }
+
+    /// Probe each configured server with an MCP `ping` request and report
+    /// per-server health, measuring round-trip latency. Servers that have not
+    /// finished starting up are reported as disconnected rather than awaited.
+    pub async fn server_health(&self) -> Vec<McpServerHealth> {
+        const PING_TIMEOUT: Duration = Duration::from_secs(5);
+
+        let mut join_set = JoinSet::new();
+        for (server_name, async_managed_client) in &self.clients {
+            let server_name = server_name.clone();
+            let managed_client = if async_managed_client
+                .startup_complete
+                .load(Ordering::Acquire)
+            {
+                async_managed_client.client().await.ok()
+            } else {
+                None
+            };
+            let Some(managed_client) = managed_client else {
+                join_set.spawn(async move {
+                    McpServerHealth {
+                        server: server_name,
+                        connected: false,
+                        tool_count: 0,
+                        latency_ms: None,
+                    }
+                });
+                continue;
+            };
+
+            let tool_count = managed_client.tools.len() as u64;
+            let client = managed_client.client.clone();
+            join_set.spawn(async move {
+                let ping_start = Instant::now();
+                let connected = matches!(
+                    tokio::time::timeout(PING_TIMEOUT, client.send_custom_request("ping", None))
+                        .await,
+                    Ok(Ok(_))
+                );
+                let latency_ms = connected
+                    .then(|| u64::try_from(ping_start.elapsed().as_millis()).unwrap_or(u64::MAX));
+                McpServerHealth {
+                    server: server_name,
+                    connected,
+                    tool_count,
+                    latency_ms,
+                }
+            });
+        }
+
+        let mut statuses = Vec::new();
+        while let Some(join_res) = join_set.join_next().await {
+            match join_res {
+                Ok(status) => statuses.push(status),
+                Err(err) => {
+                    warn!("Task panic when probing MCP server health: {err:#}");
+                }
+            }
+        }
+        statuses.sort_by(|a, b| a.server.cmp(&b.server));
+        statuses
+    }
+
     /// Invoke the tool indicated by the (server, tool) pair.
     pub async fn call_tool(
         &self,
@@ -766,11 +846,50 @@ impl McpConnectionManager {
             ));
         }
 
-        let result: rmcp::model::CallToolResult = client
-            .client
-            .call_tool(tool.to_string(), arguments, meta, client.tool_timeout)
-            .await
-            .with_context(|| format!("tool call failed for `{server}/{tool}`"))?;
+        let timeout = client.tool_timeout.unwrap_or(DEFAULT_TOOL_TIMEOUT);
+        let max_retries = client.max_retries;
+        let mut attempt: u32 = 0;
+        let result: rmcp::model::CallToolResult = loop {
+            match client
+                .client
+                .call_tool(
+                    tool.to_string(),
+                    arguments.clone(),
+                    meta.clone(),
+                    client.tool_timeout,
+                )
+                .await
+            {
+                Ok(result) => break result,
+                // Only transport-level send failures are retried: the request
+                // never reached the server, so re-sending cannot re-execute a
+                // non-idempotent tool. Timeouts and server-reported errors are
+                // surfaced immediately because the call may have run.
+                Err(error)
+                    if attempt < max_retries
+                        && codex_rmcp_client::is_transport_send_error(&error) =>
+                {
+                    attempt += 1;
+                    let delay = tool_call_retry_backoff(attempt);
+                    warn!(
+                        attempt,
+                        max_retries,
+                        delay_ms = delay.as_millis(),
+                        "tool call for `{server}/{tool}` failed to send; retrying: {error:#}"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(error) => {
+                    return Err(error).with_context(|| {
+                        format!(
+                            "tool call failed for `{server}/{tool}` after {attempts} attempt(s) with a {timeout_secs}s timeout",
+                            attempts = attempt + 1,
+                            timeout_secs = timeout.as_secs(),
+                        )
+                    });
+                }
+            }
+        };
 
         let content = result
             .content
@@ -918,6 +1037,17 @@ impl Drop for McpConnectionManager {
     }
 }
 
+/// Exponential backoff between tool-call retry attempts, capped so a
+/// misbehaving server cannot stall a turn indefinitely.
+fn tool_call_retry_backoff(attempt: u32) -> Duration {
+    const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+    const MAX_BACKOFF: Duration = Duration::from_secs(10);
+    let exponent = attempt.saturating_sub(1).min(5);
+    INITIAL_BACKOFF
+        .saturating_mul(1 << exponent)
+        .min(MAX_BACKOFF)
+}
+
 /// Makes ChatGPT authentication available to servers that explicitly opt in.
 /// The HTTP transport applies it only when no configured authorization resolves.
 fn chatgpt_auth_provider_for_server(
@@ -118,6 +118,7 @@ async fn create_test_managed_client(tools: Vec<ToolInfo>) -> ManagedClient {
         tools,
         tool_filter: ToolFilter::default(),
         tool_timeout: None,
+        max_retries: 0,
         server_instructions: None,
         server_supports_sandbox_state_meta_capability: false,
         codex_apps_tools_cache_context: None,
@@ -1497,6 +1498,7 @@ async fn no_local_runtime_fails_local_stdio_but_keeps_local_http_server() {
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
+                max_retries: None,
                 default_tools_approval_mode: None,
                 enabled_tools: None,
                 disabled_tools: None,
@@ -1523,6 +1525,7 @@ async fn no_local_runtime_fails_local_stdio_but_keeps_local_http_server() {
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
+                max_retries: None,
                 default_tools_approval_mode: None,
                 enabled_tools: None,
                 disabled_tools: None,
@@ -1635,6 +1638,7 @@ fn mcp_init_error_display_prompts_for_github_pat() {
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
@@ -1733,6 +1737,7 @@ fn mcp_init_error_display_reports_generic_errors() {
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
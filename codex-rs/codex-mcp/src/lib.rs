@@ -87,4 +87,5 @@ mod resource_client;
 pub(crate) mod rmcp_client;
 pub(crate) mod runtime;
 pub(crate) mod server;
+pub(crate) mod template;
 pub(crate) mod tools;
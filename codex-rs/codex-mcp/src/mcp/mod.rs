@@ -542,6 +542,7 @@ fn mcp_server_config_for_url(
         disabled_reason: None,
         startup_timeout_sec: Some(Duration::from_secs(30)),
         tool_timeout_sec: None,
+        max_retries: None,
         default_tools_approval_mode: None,
         enabled_tools: None,
         disabled_tools: None,
@@ -310,6 +310,7 @@ async fn effective_mcp_servers_preserve_runtime_servers() {
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
@@ -336,6 +337,7 @@ async fn effective_mcp_servers_preserve_runtime_servers() {
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
@@ -47,6 +47,7 @@ fn stdio_server(
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
+        max_retries: None,
         default_tools_approval_mode: None,
         enabled_tools: None,
         disabled_tools: None,
@@ -81,6 +82,7 @@ fn declared_placement_preserves_local_plugin_normalization() {
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
+        max_retries: None,
         default_tools_approval_mode: None,
         enabled_tools: None,
         disabled_tools: None,
@@ -353,6 +355,7 @@ fn local_environment_placement_preserves_http_env_references() {
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: None,
                     disabled_tools: None,
@@ -85,6 +85,7 @@ pub(crate) const MCP_TOOLS_FETCH_UNCACHED_DURATION_METRIC: &str =
 pub(crate) const CODEX_APPS_REFRESH_DURATION_METRIC: &str = "codex.apps.refresh.duration_ms";
 pub(crate) const DEFAULT_STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
 pub(crate) const DEFAULT_TOOL_TIMEOUT: Duration = Duration::from_secs(300);
+pub(crate) const DEFAULT_TOOL_CALL_MAX_RETRIES: u32 = 0;
 
 pub(crate) const CODEX_APPS_RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
 const CODEX_APPS_RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(30);
@@ -104,6 +105,7 @@ pub(crate) struct ManagedClient {
     pub(crate) tools: Vec<ToolInfo>,
     pub(crate) tool_filter: ToolFilter,
     pub(crate) tool_timeout: Option<Duration>,
+    pub(crate) max_retries: u32,
     pub(crate) server_instructions: Option<String>,
     pub(crate) server_supports_sandbox_state_meta_capability: bool,
     pub(crate) codex_apps_tools_cache_context: Option<CodexAppsToolsCacheContext>,
@@ -192,6 +194,25 @@ impl CodexAppsStartupReconnect {
             .clone()
     }
 
+    /// Drop the current client (shutting it down in the background) and
+    /// spawn a fresh startup immediately, regardless of backoff state.
+    fn restart(self: &Arc<Self>) {
+        let previous = {
+            let mut state = self
+                .state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            state.retry_not_before = None;
+            state.current_client.take()
+        };
+        if let Some(previous) = previous {
+            tokio::spawn(async move {
+                previous.client.shutdown().await;
+            });
+        }
+        self.reconnect_in_background();
+    }
+
     fn reconnect_in_background(self: &Arc<Self>) {
         {
             let mut state = self
@@ -335,6 +356,10 @@ impl ManagedClientStartup {
                             .configured_config()
                             .and_then(|config| config.tool_timeout_sec)
                             .unwrap_or(DEFAULT_TOOL_TIMEOUT),
+                        max_retries: server
+                            .configured_config()
+                            .and_then(|config| config.max_retries)
+                            .unwrap_or(DEFAULT_TOOL_CALL_MAX_RETRIES),
                         tool_filter,
                         tx_event,
                         elicitation_requests,
@@ -435,17 +460,19 @@ impl AsyncManagedClient {
             startup_complete: Arc::clone(&startup_complete),
         });
         let client = startup.start();
-        let startup_reconnect = is_codex_apps_mcp_server.then(|| {
+        // Every server keeps a reconnect handle so failed startups can retry
+        // in the background and callers can force a restart.
+        let startup_reconnect = {
             let startup = Arc::clone(&startup);
-            Arc::new(
+            Some(Arc::new(
                 CodexAppsStartupReconnect::new(Arc::new(move || startup.start()))
                     .with_startup_status_context(
                         startup_submit_id,
                         reconnect_server_name,
                         reconnect_tx_event,
                     ),
-            )
-        });
+            ))
+        };
         if codex_apps_tools_cache_context
             .as_ref()
             .is_some_and(CodexAppsToolsCacheContext::has_current_tools)
@@ -480,6 +507,29 @@ impl AsyncManagedClient {
         self.client.clone().await
     }
 
+    /// Tear down the current client and re-spawn this server. Returns false
+    /// when no reconnect handle exists.
+    pub(crate) fn restart(&self) -> bool {
+        match self.startup_reconnect.as_ref() {
+            Some(startup_reconnect) => {
+                if startup_reconnect.current_client().is_none() {
+                    // The live client (if any) still comes from the original
+                    // startup future; shut it down before the replacement
+                    // spawns so the old server process does not linger.
+                    let original = self.client.clone();
+                    tokio::spawn(async move {
+                        if let Ok(client) = original.await {
+                            client.client.shutdown().await;
+                        }
+                    });
+                }
+                startup_reconnect.restart();
+                true
+            }
+            None => false,
+        }
+    }
+
     pub(crate) async fn reconnect_failed_startup(&self) {
         let Some(startup_reconnect) = self.startup_reconnect.as_ref() else {
             return;
@@ -820,6 +870,7 @@ async fn start_server_task(
         is_codex_apps_mcp_server,
         startup_timeout,
         tool_timeout,
+        max_retries,
         tool_filter,
         tx_event,
         elicitation_requests,
@@ -881,6 +932,7 @@ async fn start_server_task(
         server_info,
         tools,
         tool_timeout: Some(tool_timeout),
+        max_retries,
         tool_filter,
         server_instructions: initialize_result.instructions,
         server_supports_sandbox_state_meta_capability,
@@ -929,6 +981,7 @@ struct StartServerTaskParams {
     is_codex_apps_mcp_server: bool,
     startup_timeout: Option<Duration>, // TODO: cancel_token should handle this.
     tool_timeout: Duration,
+    max_retries: u32,
     tool_filter: ToolFilter,
     tx_event: Sender<Event>,
     elicitation_requests: ElicitationRequestManager,
@@ -953,7 +1006,9 @@ async fn make_rmcp_client(
         .resolve_server_environment(server_name, &config)
         .map_err(|err| StartupOutcomeError::from(anyhow!(err)))?;
     let is_local_environment = config.is_local_environment();
-    let McpServerConfig { transport, .. } = config;
+    let McpServerConfig { mut transport, .. } = config;
+    crate::template::expand_server_transport(server_name, &mut transport)
+        .map_err(|err| StartupOutcomeError::from(anyhow!(err)))?;
 
     match transport {
         McpServerTransportConfig::Stdio {
@@ -135,6 +135,7 @@ mod tests {
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
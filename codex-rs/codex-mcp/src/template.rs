@@ -0,0 +1,94 @@
+//! `${env:VAR}` and `${codex_home}` interpolation for MCP server configs.
+//!
+//! Expansion happens when the connection manager spawns a server, so secrets
+//! and machine-specific paths do not need to be hardcoded into `config.toml`.
+
+use std::sync::LazyLock;
+
+use codex_config::McpServerTransportConfig;
+use codex_utils_home_dir::find_codex_home;
+use regex_lite::Captures;
+use regex_lite::Regex;
+
+static PLACEHOLDER: LazyLock<Regex> =
+    LazyLock::new(
+        || match Regex::new(r"\$\{(?:env:([A-Za-z_][A-Za-z0-9_]*)|codex_home)\}") {
+            Ok(regex) => regex,
+            Err(error) => panic!("invalid MCP config placeholder regex: {error}"),
+        },
+    );
+
+/// Expand `${env:VAR}` and `${codex_home}` placeholders in the launch-relevant
+/// parts of an MCP server transport: the stdio command, args and env values,
+/// and the streamable HTTP URL.
+pub(crate) fn expand_server_transport(
+    server_name: &str,
+    transport: &mut McpServerTransportConfig,
+) -> Result<(), String> {
+    match transport {
+        McpServerTransportConfig::Stdio {
+            command, args, env, ..
+        } => {
+            expand_in_place(server_name, command)?;
+            for arg in args {
+                expand_in_place(server_name, arg)?;
+            }
+            if let Some(env) = env {
+                for value in env.values_mut() {
+                    expand_in_place(server_name, value)?;
+                }
+            }
+        }
+        McpServerTransportConfig::StreamableHttp { url, .. } => {
+            expand_in_place(server_name, url)?;
+        }
+    }
+    Ok(())
+}
+
+fn expand_in_place(server_name: &str, value: &mut String) -> Result<(), String> {
+    if !value.contains("${") {
+        return Ok(());
+    }
+    *value = expand_placeholders(server_name, value)?;
+    Ok(())
+}
+
+fn expand_placeholders(server_name: &str, value: &str) -> Result<String, String> {
+    let mut error = None;
+    let expanded = PLACEHOLDER.replace_all(value, |captures: &Captures| {
+        if error.is_some() {
+            return String::new();
+        }
+        match resolve_placeholder(server_name, captures.get(1).map(|name| name.as_str())) {
+            Ok(replacement) => replacement,
+            Err(err) => {
+                error = Some(err);
+                String::new()
+            }
+        }
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(expanded.into_owned()),
+    }
+}
+
+fn resolve_placeholder(server_name: &str, env_var: Option<&str>) -> Result<String, String> {
+    let Some(env_var) = env_var else {
+        return find_codex_home()
+            .map(|codex_home| codex_home.to_string_lossy().into_owned())
+            .map_err(|err| {
+                format!("failed to resolve `${{codex_home}}` for MCP server `{server_name}`: {err}")
+            });
+    };
+    std::env::var(env_var).map_err(|_| {
+        format!(
+            "environment variable `{env_var}` referenced by MCP server `{server_name}` is not set"
+        )
+    })
+}
+
+#[cfg(test)]
+#[path = "template_tests.rs"]
+mod tests;
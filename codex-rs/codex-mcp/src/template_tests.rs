@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use codex_config::McpServerTransportConfig;
+use pretty_assertions::assert_eq;
+
+use super::expand_server_transport;
+
+fn stdio_transport(
+    command: &str,
+    args: Vec<&str>,
+    env: Option<HashMap<String, String>>,
+) -> McpServerTransportConfig {
+    McpServerTransportConfig::Stdio {
+        command: command.to_string(),
+        args: args.into_iter().map(str::to_string).collect(),
+        env,
+        env_vars: Vec::new(),
+        cwd: None,
+    }
+}
+
+#[test]
+fn expands_env_placeholders_in_command_args_and_env() {
+    // SAFETY: test-local env var with a unique name.
+    unsafe {
+        std::env::set_var("CODEX_MCP_TEMPLATE_TEST_TOKEN", "secret-token");
+    }
+
+    let mut transport = stdio_transport(
+        "${env:CODEX_MCP_TEMPLATE_TEST_TOKEN}-server",
+        vec!["--token", "${env:CODEX_MCP_TEMPLATE_TEST_TOKEN}"],
+        Some(HashMap::from([(
+            "API_TOKEN".to_string(),
+            "${env:CODEX_MCP_TEMPLATE_TEST_TOKEN}".to_string(),
+        )])),
+    );
+
+    expand_server_transport("docs", &mut transport).expect("expansion should succeed");
+
+    let McpServerTransportConfig::Stdio {
+        command, args, env, ..
+    } = transport
+    else {
+        panic!("transport should remain stdio");
+    };
+    assert_eq!(command, "secret-token-server");
+    assert_eq!(
+        args,
+        vec!["--token".to_string(), "secret-token".to_string()]
+    );
+    assert_eq!(
+        env,
+        Some(HashMap::from([(
+            "API_TOKEN".to_string(),
+            "secret-token".to_string(),
+        )]))
+    );
+}
+
+#[test]
+fn unset_env_placeholder_is_an_error() {
+    let mut transport =
+        stdio_transport("server", vec!["${env:CODEX_MCP_TEMPLATE_TEST_UNSET}"], None);
+
+    let error = expand_server_transport("docs", &mut transport).expect_err("expansion should fail");
+
+    assert_eq!(
+        error,
+        "environment variable `CODEX_MCP_TEMPLATE_TEST_UNSET` referenced by MCP server `docs` is not set"
+    );
+}
+
+#[test]
+fn expands_codex_home_in_streamable_http_url() {
+    let mut transport = McpServerTransportConfig::StreamableHttp {
+        url: "${codex_home}/mcp".to_string(),
+        bearer_token_env_var: None,
+        http_headers: None,
+        env_http_headers: None,
+    };
+
+    expand_server_transport("docs", &mut transport).expect("expansion should succeed");
+
+    let McpServerTransportConfig::StreamableHttp { url, .. } = transport else {
+        panic!("transport should remain streamable HTTP");
+    };
+    assert!(
+        !url.contains("${codex_home}"),
+        "url was not expanded: {url}"
+    );
+    assert!(url.ends_with("/mcp"));
+}
+
+#[test]
+fn values_without_placeholders_are_untouched() {
+    let mut transport = stdio_transport("server", vec!["--flag", "$HOME/literal"], None);
+
+    expand_server_transport("docs", &mut transport).expect("expansion should succeed");
+
+    let McpServerTransportConfig::Stdio { command, args, .. } = transport else {
+        panic!("transport should remain stdio");
+    };
+    assert_eq!(command, "server");
+    assert_eq!(
+        args,
+        vec!["--flag".to_string(), "$HOME/literal".to_string()]
+    );
+}
@@ -30,11 +30,13 @@ use crate::types::WindowsToml;
 use codex_features::FeaturesToml;
 use codex_model_provider_info::AMAZON_BEDROCK_PROVIDER_ID;
 use codex_model_provider_info::LEGACY_OLLAMA_CHAT_PROVIDER_ID;
+use codex_model_provider_info::LLAMACPP_OSS_PROVIDER_ID;
 use codex_model_provider_info::LMSTUDIO_OSS_PROVIDER_ID;
 use codex_model_provider_info::ModelProviderInfo;
 use codex_model_provider_info::OLLAMA_CHAT_PROVIDER_REMOVED_ERROR;
 use codex_model_provider_info::OLLAMA_OSS_PROVIDER_ID;
 use codex_model_provider_info::OPENAI_PROVIDER_ID;
+use codex_model_provider_info::VLLM_OSS_PROVIDER_ID;
 use codex_protocol::config_types::AutoCompactTokenLimitScope;
 use codex_protocol::config_types::ForcedLoginMethod;
 use codex_protocol::config_types::Personality;
@@ -58,11 +60,13 @@ use serde::Serialize;
 use serde::de::Error as SerdeError;
 use serde_json::Value as JsonValue;
 
-const RESERVED_MODEL_PROVIDER_IDS: [&str; 4] = [
+const RESERVED_MODEL_PROVIDER_IDS: [&str; 6] = [
     AMAZON_BEDROCK_PROVIDER_ID,
     OPENAI_PROVIDER_ID,
     OLLAMA_OSS_PROVIDER_ID,
     LMSTUDIO_OSS_PROVIDER_ID,
+    LLAMACPP_OSS_PROVIDER_ID,
+    VLLM_OSS_PROVIDER_ID,
 ];
 
 pub const DEFAULT_PROJECT_DOC_MAX_BYTES: usize = 32 * 1024;
@@ -152,6 +156,11 @@ pub struct OrchestratorFeatureToml {
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
 #[schemars(deny_unknown_fields)]
 pub struct ConfigToml {
+    /// Additional TOML files merged beneath this one, resolved relative to
+    /// the including file; glob patterns are allowed. Expanded at load time.
+    #[serde(default)]
+    pub include: Option<Vec<String>>,
+
     /// Optional override of model selection.
     pub model: Option<String>,
     /// Review model override used by the `/review` feature.
@@ -166,6 +175,19 @@ pub struct ConfigToml {
     /// Token usage threshold triggering auto-compaction of conversation history.
     pub model_auto_compact_token_limit: Option<i64>,
 
+    /// Sampling temperature forwarded to providers that support it.
+    pub model_temperature: Option<f64>,
+
+    /// Nucleus sampling parameter forwarded to providers that support it.
+    pub model_top_p: Option<f64>,
+
+    /// Deterministic sampling seed forwarded to providers that support it.
+    pub model_seed: Option<i64>,
+
+    /// Token budget for verbatim user messages retained alongside the
+    /// compaction summary. Defaults to 20000.
+    pub compact_user_message_max_tokens: Option<usize>,
+
     /// Controls whether the auto-compaction limit applies to the full context or
     /// only to tokens after the carried prefix in the current compaction window.
     pub model_auto_compact_token_limit_scope: Option<AutoCompactTokenLimitScope>,
@@ -302,6 +324,21 @@ pub struct ConfigToml {
     /// Default: `300000` (5 minutes).
     pub background_terminal_max_timeout: Option<u64>,
 
+    /// When `true` (the default), collected PTY output is normalized before
+    /// truncation and events: ANSI escape sequences are stripped,
+    /// carriage-return progress updates are collapsed to their final state,
+    /// and CRLF line endings become LF.
+    pub normalize_pty_output: Option<bool>,
+
+    /// When `true`, human-facing output (exec logs and TUI markers) uses
+    /// ASCII-only glyphs instead of symbols like ✓/✗ that render
+    /// badly on some terminals.
+    pub ascii_output: Option<bool>,
+
+    /// When `true`, human-facing output uses a colorblind-safe palette that
+    /// does not rely on red/green distinctions.
+    pub colorblind_output: Option<bool>,
+
     /// Deprecated: ignored.
     #[schemars(skip)]
     pub js_repl_node_path: Option<AbsolutePathBuf>,
@@ -325,6 +362,10 @@ pub struct ConfigToml {
     /// Defaults to `$CODEX_SQLITE_HOME` when set. Otherwise uses `$CODEX_HOME`.
     pub sqlite_home: Option<AbsolutePathBuf>,
 
+    /// Hex-encoded 32-byte key enabling encryption-at-rest for session
+    /// rollout files under `$CODEX_HOME/sessions`.
+    pub sessions_encryption_key: Option<String>,
+
     /// Directory where Codex writes log files. Setting this value explicitly
     /// also enables the TUI text log in this directory.
     /// Defaults to `$CODEX_HOME/log`.
@@ -349,6 +390,55 @@ pub struct ConfigToml {
     /// Defaults to `false`.
     pub show_raw_agent_reasoning: Option<bool>,
 
+    /// When set to `true`, well-known secret patterns (API keys, bearer
+    /// tokens, PEM blocks, .env-style assignments) are redacted from rollout
+    /// files and `codex exec` output. Defaults to `false`.
+    pub redact_secrets: Option<bool>,
+
+    /// When set to `true`, Codex records a lightweight git snapshot of the
+    /// working tree before each turn and `Op::RevertLastTurn` can restore it.
+    /// Defaults to `false`.
+    pub git_snapshots: Option<bool>,
+
+    /// When set to `true`, Codex watches the user config files and re-applies
+    /// safe-to-change settings mid-session when they change. Defaults to
+    /// `false`.
+    pub config_watch: Option<bool>,
+
+    /// When set to `true`, the model is offered a built-in `search_workspace`
+    /// tool (fuzzy file-name and capped content search honoring ignore rules)
+    /// instead of shelling out to rg/find. Defaults to `false`.
+    pub search_workspace_tool: Option<bool>,
+
+    /// When set to `true`, the model is offered built-in `read_file`,
+    /// `write_file`, and `list_dir` tools with line-range reads, byte caps,
+    /// and sandbox-path enforcement instead of shelling out to cat/sed.
+    /// Defaults to `false`.
+    pub file_io_tools: Option<bool>,
+
+    /// When `true`, registers the built-in `fetch_url` web-fetch tool,
+    /// which converts pages to markdown with caps and per-session caching.
+    pub fetch_url_tool: Option<bool>,
+
+    /// Pluggable backend for the local `web_search` tool (SearxNG URL or
+    /// Brave/Bing API keys). Registering the tool requires this section.
+    #[serde(default)]
+    pub web_search_provider: Option<crate::types::WebSearchProviderConfig>,
+
+    /// When `true`, output of Read-classified commands that looks like HTML
+    /// or troff man-page source is converted to plain text/markdown before
+    /// it is added to the model context.
+    pub render_read_documents: Option<bool>,
+
+    /// When `true` (the default), unnamed threads are given a heuristic
+    /// title derived from the first prompt so sessions are identifiable by
+    /// more than timestamps and ids.
+    pub auto_name_threads: Option<bool>,
+
+    /// Limits for parallel tool-call dispatch.
+    #[serde(default)]
+    pub parallel: Option<crate::types::ParallelToml>,
+
     pub model_reasoning_effort: Option<ReasoningEffort>,
     pub plan_mode_reasoning_effort: Option<ReasoningEffort>,
     pub model_reasoning_summary: Option<ReasoningSummary>,
@@ -784,6 +874,9 @@ impl ConfigToml {
             SandboxMode::WorkspaceWrite => match self.sandbox_workspace_write.as_ref() {
                 Some(SandboxWorkspaceWrite {
                     writable_roots,
+                    // Readable roots are folded into the effective policy
+                    // during config compilation, not profile selection.
+                    readable_roots: _,
                     network_access,
                     exclude_tmpdir_env_var,
                     exclude_slash_tmp,
@@ -953,7 +1046,10 @@ where
 
 pub fn validate_oss_provider(provider: &str) -> std::io::Result<()> {
     match provider {
-        LMSTUDIO_OSS_PROVIDER_ID | OLLAMA_OSS_PROVIDER_ID => Ok(()),
+        LMSTUDIO_OSS_PROVIDER_ID
+        | OLLAMA_OSS_PROVIDER_ID
+        | LLAMACPP_OSS_PROVIDER_ID
+        | VLLM_OSS_PROVIDER_ID => Ok(()),
         LEGACY_OLLAMA_CHAT_PROVIDER_ID => Err(std::io::Error::new(
             std::io::ErrorKind::InvalidInput,
             OLLAMA_CHAT_PROVIDER_REMOVED_ERROR,
@@ -110,10 +110,13 @@ pub(super) async fn read_config_from_path(
     let path_uri = PathUri::from_abs_path(path);
     match fs.read_file_text(&path_uri, /*sandbox*/ None).await {
         Ok(contents) => match toml::from_str::<TomlValue>(&contents) {
-            Ok(value) => {
+            Ok(mut value) => {
                 if strict_config {
                     validate_config_toml_strictly(path, &contents, &value)?;
                 }
+                super::preprocess::expand_env_placeholders(&mut value);
+                let value =
+                    super::preprocess::expand_includes(fs, path, value, /*depth*/ 0).await?;
                 Ok(Some(value))
             }
             Err(err) => {
@@ -1,6 +1,7 @@
 mod layer_io;
 #[cfg(target_os = "macos")]
 mod macos;
+mod preprocess;
 #[cfg(test)]
 mod tests;
 
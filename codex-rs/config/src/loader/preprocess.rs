@@ -0,0 +1,202 @@
+//! `${env:VAR}` interpolation and `include` expansion for config layers.
+//!
+//! Both run when a layer file is read, before merging: string values may
+//! reference environment variables with `${env:VAR}` (unset variables are
+//! left literal so the problem is visible), and a top-level
+//! `include = ["teams/*.toml"]` array pulls in other TOML files, resolved
+//! relative to the including file and merged deterministically in listed
+//! order, with the including file's own keys winning.
+
+use std::io;
+use std::path::Path;
+
+use codex_file_system::ExecutorFileSystem;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use codex_utils_path_uri::PathUri;
+use toml::Value as TomlValue;
+
+use crate::merge::merge_toml_values;
+
+const INCLUDE_KEY: &str = "include";
+/// Guard against `include` cycles.
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Expand `${env:VAR}` placeholders in every string value in place.
+pub(super) fn expand_env_placeholders(value: &mut TomlValue) {
+    match value {
+        TomlValue::String(text) => {
+            if text.contains("${env:") {
+                *text = expand_env_in_string(text);
+            }
+        }
+        TomlValue::Array(items) => {
+            for item in items {
+                expand_env_placeholders(item);
+            }
+        }
+        TomlValue::Table(table) => {
+            for item in table.values_mut() {
+                expand_env_placeholders(item);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn expand_env_in_string(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("${env:") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + "${env:".len()..];
+        let Some(end) = after.find('}') else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let name = &after[..end];
+        match std::env::var(name) {
+            Ok(value) => out.push_str(&value),
+            // Leave unset variables literal so the problem is visible.
+            Err(_) => out.push_str(&rest[start..start + "${env:".len() + end + 1]),
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Expand a layer's top-level `include` array by loading each referenced file
+/// (glob patterns allowed), merging them in listed order, and finally merging
+/// the including file's own keys on top.
+pub(super) async fn expand_includes(
+    fs: &dyn ExecutorFileSystem,
+    config_path: &AbsolutePathBuf,
+    value: TomlValue,
+    depth: usize,
+) -> io::Result<TomlValue> {
+    let TomlValue::Table(mut table) = value else {
+        return Ok(value);
+    };
+    let Some(include_value) = table.remove(INCLUDE_KEY) else {
+        return Ok(TomlValue::Table(table));
+    };
+    if depth >= MAX_INCLUDE_DEPTH {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "config include depth exceeds {MAX_INCLUDE_DEPTH} at {}",
+                config_path.as_path().display()
+            ),
+        ));
+    }
+    let TomlValue::Array(patterns) = include_value else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "`include` must be an array of paths in {}",
+                config_path.as_path().display()
+            ),
+        ));
+    };
+
+    let base_dir = config_path
+        .as_path()
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    let mut merged = TomlValue::Table(Default::default());
+    for pattern in patterns {
+        let TomlValue::String(pattern) = pattern else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "`include` entries must be strings in {}",
+                    config_path.as_path().display()
+                ),
+            ));
+        };
+        let pattern_path = base_dir.join(&pattern);
+        let mut matched_paths = Vec::new();
+        if pattern.contains('*') || pattern.contains('?') || pattern.contains('[') {
+            let matches = glob::glob(&pattern_path.to_string_lossy())
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            for entry in matches {
+                match entry {
+                    Ok(path) => matched_paths.push(path),
+                    Err(err) => return Err(io::Error::other(err)),
+                }
+            }
+            // Glob results are filesystem-order; sort for determinism.
+            matched_paths.sort();
+        } else {
+            matched_paths.push(pattern_path);
+        }
+
+        for included_path in matched_paths {
+            let included_path = AbsolutePathBuf::try_from(included_path.as_path())
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            let path_uri = PathUri::from_abs_path(&included_path);
+            let contents = fs.read_file_text(&path_uri, /*sandbox*/ None).await?;
+            let included: TomlValue = toml::from_str(&contents).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "failed to parse included config {}: {err}",
+                        included_path.as_path().display()
+                    ),
+                )
+            })?;
+            let included =
+                Box::pin(expand_includes(fs, &included_path, included, depth + 1)).await?;
+            merge_toml_values(&mut merged, &included);
+        }
+    }
+
+    // The including file's own keys win over everything it includes.
+    merge_toml_values(&mut merged, &TomlValue::Table(table));
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn expands_env_placeholders_in_nested_strings() {
+        // SAFETY: test-local env var with a unique name.
+        unsafe {
+            std::env::set_var("CODEX_CONFIG_PREPROCESS_TEST", "expanded");
+        }
+        let mut value: TomlValue = toml::from_str(
+            r#"
+model = "${env:CODEX_CONFIG_PREPROCESS_TEST}"
+[mcp_servers.docs]
+url = "https://${env:CODEX_CONFIG_PREPROCESS_TEST}.example.com"
+"#,
+        )
+        .expect("toml should parse");
+
+        expand_env_placeholders(&mut value);
+
+        assert_eq!(value["model"].as_str(), Some("expanded"));
+        assert_eq!(
+            value["mcp_servers"]["docs"]["url"].as_str(),
+            Some("https://expanded.example.com")
+        );
+    }
+
+    #[test]
+    fn unset_env_placeholders_stay_literal() {
+        let mut value: TomlValue =
+            toml::from_str(r#"model = "${env:CODEX_CONFIG_PREPROCESS_UNSET}""#)
+                .expect("toml should parse");
+
+        expand_env_placeholders(&mut value);
+
+        assert_eq!(
+            value["model"].as_str(),
+            Some("${env:CODEX_CONFIG_PREPROCESS_UNSET}")
+        );
+    }
+}
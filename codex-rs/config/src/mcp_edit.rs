@@ -63,6 +63,7 @@ fn ensure_no_inline_bearer_tokens(value: &TomlValue) -> std::io::Result<()> {
 pub struct ConfigEditsBuilder {
     codex_home: PathBuf,
     mcp_servers: Option<BTreeMap<String, McpServerConfig>>,
+    mcp_server_enabled: Vec<(String, bool)>,
 }
 
 impl ConfigEditsBuilder {
@@ -70,9 +71,18 @@ impl ConfigEditsBuilder {
         Self {
             codex_home: codex_home.to_path_buf(),
             mcp_servers: None,
+            mcp_server_enabled: Vec::new(),
         }
     }
 
+    /// Toggle a single server's `enabled` flag in place, preserving the
+    /// entry's comments and formatting. Fails on apply when the server is not
+    /// defined in the user config file.
+    pub fn set_mcp_server_enabled(mut self, name: &str, enabled: bool) -> Self {
+        self.mcp_server_enabled.push((name.to_string(), enabled));
+        self
+    }
+
     pub fn replace_mcp_servers(mut self, servers: &BTreeMap<String, McpServerConfig>) -> Self {
         self.mcp_servers = Some(servers.clone());
         self
@@ -92,11 +102,35 @@ impl ConfigEditsBuilder {
         if let Some(servers) = self.mcp_servers.as_ref() {
             replace_mcp_servers(&mut doc, servers);
         }
+        for (name, enabled) in &self.mcp_server_enabled {
+            set_mcp_server_enabled(&mut doc, name, *enabled)?;
+        }
         fs::create_dir_all(&self.codex_home)?;
         fs::write(config_path, doc.to_string())
     }
 }
 
+fn set_mcp_server_enabled(doc: &mut DocumentMut, name: &str, enabled: bool) -> std::io::Result<()> {
+    let entry = doc
+        .get_mut("mcp_servers")
+        .and_then(|servers| servers.as_table_like_mut())
+        .and_then(|servers| servers.get_mut(name))
+        .and_then(|entry| entry.as_table_like_mut())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                ErrorKind::NotFound,
+                format!("MCP server `{name}` is not defined in the user config"),
+            )
+        })?;
+    if enabled {
+        // `enabled` defaults to true; drop the key instead of writing noise.
+        entry.remove("enabled");
+    } else {
+        entry.insert("enabled", value(false));
+    }
+    Ok(())
+}
+
 fn read_or_create_document(config_path: &Path) -> std::io::Result<DocumentMut> {
     match fs::read_to_string(config_path) {
         Ok(raw) => raw
@@ -31,6 +31,7 @@ async fn replace_mcp_servers_serializes_per_tool_approval_overrides() -> anyhow:
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: Some(AppToolApproval::Auto),
             enabled_tools: None,
             disabled_tools: None,
@@ -110,6 +111,7 @@ async fn replace_mcp_servers_serializes_oauth_client_id() -> anyhow::Result<()>
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
@@ -146,3 +148,55 @@ client_id = "eci-prd-pub-codex-123"
 
     Ok(())
 }
+
+#[tokio::test]
+async fn set_mcp_server_enabled_preserves_comments_and_formatting() {
+    let codex_home = tempfile::TempDir::new().expect("create codex home");
+    let config_path = codex_home.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        r#"# user config
+[mcp_servers.docs]
+# launches the docs server
+command = "docs-server"
+"#,
+    )
+    .expect("write config");
+
+    ConfigEditsBuilder::new(codex_home.path())
+        .set_mcp_server_enabled("docs", false)
+        .apply()
+        .await
+        .expect("disable server");
+
+    let disabled = std::fs::read_to_string(&config_path).expect("read config");
+    assert!(disabled.contains("# user config"), "{disabled}");
+    assert!(
+        disabled.contains("# launches the docs server"),
+        "{disabled}"
+    );
+    assert!(disabled.contains("enabled = false"), "{disabled}");
+
+    ConfigEditsBuilder::new(codex_home.path())
+        .set_mcp_server_enabled("docs", true)
+        .apply()
+        .await
+        .expect("enable server");
+
+    let enabled = std::fs::read_to_string(&config_path).expect("read config");
+    assert!(!enabled.contains("enabled"), "{enabled}");
+    assert!(enabled.contains("# launches the docs server"), "{enabled}");
+}
+
+#[tokio::test]
+async fn set_mcp_server_enabled_rejects_unknown_server() {
+    let codex_home = tempfile::TempDir::new().expect("create codex home");
+
+    let error = ConfigEditsBuilder::new(codex_home.path())
+        .set_mcp_server_enabled("missing", false)
+        .apply()
+        .await
+        .expect_err("unknown server should fail");
+
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+}
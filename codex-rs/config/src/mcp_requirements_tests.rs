@@ -20,6 +20,7 @@ fn stdio_server(command: &str, args: &[&str]) -> McpServerConfig {
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
+        max_retries: None,
         default_tools_approval_mode: None,
         enabled_tools: None,
         disabled_tools: None,
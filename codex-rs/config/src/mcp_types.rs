@@ -193,6 +193,13 @@ pub struct McpServerConfig {
     #[serde(default, with = "option_duration_secs")]
     pub tool_timeout_sec: Option<Duration>,
 
+    /// Maximum number of automatic retries for a tool call that failed to
+    /// send at the transport layer before the error is surfaced to the model.
+    /// Calls that reached the server (including timeouts) are never retried
+    /// automatically, so non-idempotent tools are not executed twice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+
     /// Approval mode for tools in this server unless a tool override exists.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default_tools_approval_mode: Option<AppToolApproval>,
@@ -279,6 +286,10 @@ pub struct RawMcpServerConfig {
     #[schemars(with = "Option<f64>")]
     pub tool_timeout_sec: Option<Duration>,
     #[serde(default)]
+    pub tool_timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    #[serde(default)]
     pub enabled: Option<bool>,
     #[serde(default)]
     pub required: Option<bool>,
@@ -323,6 +334,8 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
             startup_timeout_sec,
             startup_timeout_ms,
             tool_timeout_sec,
+            tool_timeout_ms,
+            max_retries,
             enabled,
             required,
             supports_parallel_tool_calls,
@@ -344,6 +357,12 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
             (None, None) => None,
         };
 
+        let tool_timeout_sec = match (tool_timeout_sec, tool_timeout_ms) {
+            (Some(sec), _) => Some(sec),
+            (None, Some(ms)) => Some(Duration::from_millis(ms)),
+            (None, None) => None,
+        };
+
         fn throw_if_set<T>(transport: &str, field: &str, value: Option<&T>) -> Result<(), String> {
             if value.is_none() {
                 return Ok(());
@@ -400,6 +419,7 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
             environment_id,
             startup_timeout_sec,
             tool_timeout_sec,
+            max_retries,
             enabled: enabled.unwrap_or_else(default_enabled),
             required: required.unwrap_or_default(),
             supports_parallel_tool_calls: supports_parallel_tool_calls.unwrap_or_default(),
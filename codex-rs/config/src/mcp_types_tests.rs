@@ -441,6 +441,7 @@ fn deserialize_ignores_unknown_server_fields() {
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
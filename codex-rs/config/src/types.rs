@@ -679,6 +679,45 @@ pub struct ModelAvailabilityNuxConfig {
 /// Fallback resize-reflow row cap when Codex cannot identify a terminal-specific scrollback size.
 pub const DEFAULT_TERMINAL_RESIZE_REFLOW_FALLBACK_MAX_ROWS: usize = 1_000;
 
+/// Named UI color preset applied before per-element overrides.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TuiColorPreset {
+    /// Standard ANSI accents.
+    #[default]
+    Default,
+    /// Darker accents that stay readable on light terminal backgrounds.
+    Light,
+    /// Bright accents for high-contrast needs.
+    HighContrast,
+    /// Colorblind-safe accents that avoid red/green distinctions.
+    Colorblind,
+}
+
+/// Per-element ANSI color overrides for TUI chrome (pager headers, key
+/// hints, popup rules). Values are ANSI color names such as `cyan` or
+/// `bright-blue`; RGB values are deliberately unsupported so output stays
+/// legible across terminal palettes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct TuiThemeOverrides {
+    /// Preset applied before individual overrides.
+    #[serde(default)]
+    pub preset: TuiColorPreset,
+    /// Accent color for active/selected chrome.
+    #[serde(default)]
+    pub accent: Option<String>,
+    /// Color for success indicators.
+    #[serde(default)]
+    pub success: Option<String>,
+    /// Color for warnings.
+    #[serde(default)]
+    pub warning: Option<String>,
+    /// Color for errors.
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
 /// Collection of settings that are specific to the TUI.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
 #[schemars(deny_unknown_fields)]
@@ -686,6 +725,10 @@ pub struct Tui {
     #[serde(default, flatten)]
     pub notification_settings: TuiNotificationSettings,
 
+    /// UI chrome color preset and per-element overrides.
+    #[serde(default)]
+    pub theme_overrides: Option<TuiThemeOverrides>,
+
     /// Enable animations (welcome screen, shimmer effects, spinners).
     /// Defaults to `true`.
     #[serde(default = "default_true")]
@@ -913,6 +956,10 @@ pub enum MarketplaceSourceType {
 pub struct SandboxWorkspaceWrite {
     #[serde(default)]
     pub writable_roots: Vec<AbsolutePathBuf>,
+    /// Additional roots the sandbox may read (but not write), e.g.
+    /// `/usr/share` or a shared toolchain directory outside the workspace.
+    #[serde(default)]
+    pub readable_roots: Vec<AbsolutePathBuf>,
     #[serde(default)]
     pub network_access: bool,
     #[serde(default)]
@@ -974,3 +1021,41 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
 #[cfg(test)]
 #[path = "types_tests.rs"]
 mod tests;
+
+/// Backend selection for the local `web_search` tool.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebSearchBackend {
+    /// Self-hosted SearxNG instance queried via its JSON API.
+    Searxng,
+    /// Brave Search API (requires `api_key`).
+    Brave,
+    /// Bing Web Search API (requires `api_key`).
+    Bing,
+}
+
+/// Pluggable web-search provider configuration for the local `web_search`
+/// tool, returning structured title/url/snippet results.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct WebSearchProviderConfig {
+    pub backend: WebSearchBackend,
+    /// Base URL for self-hosted backends (SearxNG).
+    pub url: Option<String>,
+    /// API key for hosted backends (Brave, Bing).
+    pub api_key: Option<String>,
+    /// Maximum results returned per query. Defaults to 5.
+    pub max_results: Option<usize>,
+}
+
+/// Limits for parallel tool-call dispatch, set from `[parallel]`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ParallelToml {
+    /// Disable parallel dispatch entirely when `false`.
+    pub enabled: Option<bool>,
+    /// Maximum tool calls running concurrently; `0` or unset means unlimited.
+    pub max_concurrent_calls: Option<usize>,
+    /// Minimum delay between parallel dispatches, in milliseconds.
+    pub min_delay_ms: Option<u64>,
+}
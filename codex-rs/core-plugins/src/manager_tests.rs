@@ -884,6 +884,7 @@ async fn load_plugins_loads_default_skills_and_mcp_servers() {
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: None,
                     disabled_tools: None,
@@ -976,6 +977,7 @@ enabled = true
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
+                max_retries: None,
                 default_tools_approval_mode: None,
                 enabled_tools: None,
                 disabled_tools: None,
@@ -1868,6 +1870,7 @@ async fn load_plugins_uses_manifest_configured_component_paths() {
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: None,
                     disabled_tools: None,
@@ -2049,6 +2052,7 @@ async fn load_plugins_ignores_manifest_component_paths_without_dot_slash() {
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
+                max_retries: None,
                 default_tools_approval_mode: None,
                 enabled_tools: None,
                 disabled_tools: None,
@@ -2300,6 +2304,7 @@ fn capability_index_filters_inactive_and_zero_capability_plugins() {
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
+        max_retries: None,
         default_tools_approval_mode: None,
         enabled_tools: None,
         disabled_tools: None,
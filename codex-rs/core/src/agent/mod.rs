@@ -2,6 +2,7 @@ pub(crate) mod agent_resolver;
 pub(crate) mod control;
 mod registry;
 pub(crate) mod role;
+pub use role::apply_agent_role;
 pub(crate) mod status;
 
 pub(crate) use codex_protocol::protocol::AgentStatus;
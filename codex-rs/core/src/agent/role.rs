@@ -27,6 +27,16 @@ use toml::Value as TomlValue;
 
 /// The role name used when a caller omits `agent_type`.
 pub const DEFAULT_ROLE_NAME: &str = "default";
+
+/// Applies a named role preset to a session config before a thread starts.
+///
+/// Public entry for harnesses (`codex exec --role`, the TUI `/role` command)
+/// that reuses the same role resolution and layering as sub-agent spawning,
+/// so a role can constrain instructions, toolsets, and sandbox/approval
+/// policy without hand-tuned override flags.
+pub async fn apply_agent_role(config: &mut Config, role_name: &str) -> Result<(), String> {
+    apply_role_to_config(config, Some(role_name)).await
+}
 const AGENT_TYPE_UNAVAILABLE_ERROR: &str = "agent type is currently not available";
 
 /// Applies a named role layer to `config` while preserving caller-owned provider settings.
@@ -0,0 +1,135 @@
+//! Append-only audit log of approval decisions.
+//!
+//! Every auto-approval, user approval, and denial that flows through the tool
+//! orchestrator is appended as one JSON line to `$CODEX_HOME/approvals.jsonl`
+//! so security teams can audit what a "full-auto" run actually executed.
+
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::warn;
+
+pub(crate) const APPROVAL_AUDIT_FILE: &str = "approvals.jsonl";
+
+/// Where an approval decision came from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ApprovalAuditSource {
+    /// Auto-approved by the configured approval policy / trusted list.
+    Config,
+    /// Decided by the user (or an approval-driving wrapper).
+    User,
+    /// Forbidden outright by policy before any prompt.
+    Policy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApprovalAuditEntry {
+    /// Unix timestamp in milliseconds.
+    pub timestamp_ms: i64,
+    pub tool_name: String,
+    pub call_id: String,
+    /// Serialized approval keys; for shell tools this is the command argv.
+    pub subject: Value,
+    /// Serialized `ReviewDecision` (or `"denied"` for policy-forbidden calls).
+    pub decision: Value,
+    pub source: ApprovalAuditSource,
+    /// Approval policy in effect for the turn.
+    pub approval_policy: Value,
+    /// File-system sandbox policy in effect for the turn.
+    pub sandbox_policy: Value,
+    /// Rationale: trusted-list match, execpolicy reason, or forbid reason.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Append an entry to the audit log without blocking the caller. Failures are
+/// logged and never fail the tool call itself.
+pub(crate) fn record_approval(codex_home: &Path, entry: ApprovalAuditEntry) {
+    let path = audit_log_path(codex_home);
+    tokio::task::spawn_blocking(move || {
+        if let Err(err) = append_entry(&path, &entry) {
+            warn!("failed to append approval audit entry: {err}");
+        }
+    });
+}
+
+fn append_entry(path: &Path, entry: &ApprovalAuditEntry) -> std::io::Result<()> {
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(line.as_bytes())
+}
+
+/// Read every parseable entry from the audit log; missing file yields an
+/// empty log.
+pub(crate) async fn read_approval_log(codex_home: &Path) -> std::io::Result<Vec<Value>> {
+    let path = audit_log_path(codex_home);
+    let contents = match tokio::fs::read_to_string(&path).await {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    Ok(contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+fn audit_log_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(APPROVAL_AUDIT_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn entry(decision: &str) -> ApprovalAuditEntry {
+        ApprovalAuditEntry {
+            timestamp_ms: 1,
+            tool_name: "shell".to_string(),
+            call_id: "call-1".to_string(),
+            subject: serde_json::json!([["rm", "-rf", "target"]]),
+            decision: serde_json::json!(decision),
+            source: ApprovalAuditSource::User,
+            approval_policy: serde_json::json!("on-request"),
+            sandbox_policy: serde_json::json!({}),
+            reason: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn appended_entries_round_trip() {
+        let codex_home = TempDir::new().expect("create codex home");
+        append_entry(&audit_log_path(codex_home.path()), &entry("approved")).expect("append entry");
+        append_entry(&audit_log_path(codex_home.path()), &entry("denied")).expect("append entry");
+
+        let log = read_approval_log(codex_home.path())
+            .await
+            .expect("read log");
+
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0]["decision"], "approved");
+        assert_eq!(log[1]["decision"], "denied");
+        assert_eq!(log[1]["tool_name"], "shell");
+    }
+
+    #[tokio::test]
+    async fn missing_log_reads_as_empty() {
+        let codex_home = TempDir::new().expect("create codex home");
+        let log = read_approval_log(codex_home.path())
+            .await
+            .expect("read log");
+        assert_eq!(log, Vec::<Value>::new());
+    }
+}
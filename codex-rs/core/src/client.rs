@@ -54,6 +54,7 @@ use codex_api::ResponsesOptions as ApiResponsesOptions;
 use codex_api::ResponsesWebsocketClient as ApiWebSocketResponsesClient;
 use codex_api::ResponsesWebsocketConnection as ApiWebSocketConnection;
 use codex_api::ResponsesWsRequest;
+use codex_api::SamplingControls;
 use codex_api::SharedAuthProvider;
 use codex_api::SseTelemetry;
 use codex_api::StreamOptions;
@@ -203,6 +204,7 @@ struct ModelClientState {
     session_source: SessionSource,
     originator: String,
     model_verbosity: Option<VerbosityConfig>,
+    sampling: SamplingControls,
     enable_request_compression: bool,
     include_timing_metrics: bool,
     beta_features_header: Option<String>,
@@ -322,6 +324,9 @@ fn responses_request_properties_match(
         service_tier: previous_service_tier,
         prompt_cache_key: previous_prompt_cache_key,
         text: previous_text,
+        temperature: previous_temperature,
+        top_p: previous_top_p,
+        seed: previous_seed,
         client_metadata: _,
     } = previous;
     let ResponsesApiRequest {
@@ -339,6 +344,9 @@ fn responses_request_properties_match(
         service_tier: current_service_tier,
         prompt_cache_key: current_prompt_cache_key,
         text: current_text,
+        temperature: current_temperature,
+        top_p: current_top_p,
+        seed: current_seed,
         client_metadata: _,
     } = current;
 
@@ -356,6 +364,9 @@ fn responses_request_properties_match(
         && previous_service_tier == current_service_tier
         && previous_prompt_cache_key == current_prompt_cache_key
         && previous_text == current_text
+        && previous_temperature == current_temperature
+        && previous_top_p == current_top_p
+        && previous_seed == current_seed
 }
 
 impl WebsocketSession {
@@ -417,6 +428,7 @@ impl ModelClient {
         session_source: SessionSource,
         originator: String,
         model_verbosity: Option<VerbosityConfig>,
+        sampling: SamplingControls,
         enable_request_compression: bool,
         include_timing_metrics: bool,
         beta_features_header: Option<String>,
@@ -441,6 +453,7 @@ impl ModelClient {
                 session_source,
                 originator,
                 model_verbosity,
+                sampling,
                 enable_request_compression,
                 include_timing_metrics,
                 beta_features_header,
@@ -909,6 +922,9 @@ impl ModelClient {
             service_tier,
             prompt_cache_key,
             text,
+            temperature: self.state.sampling.temperature,
+            top_p: self.state.sampling.top_p,
+            seed: self.state.sampling.seed,
             client_metadata: Some(responses_metadata.client_metadata()),
         };
         Ok(request)
@@ -1782,6 +1798,41 @@ impl ModelClientSession {
         service_tier: Option<String>,
         responses_metadata: &CodexResponsesMetadata,
         inference_trace: &InferenceTraceContext,
+    ) -> Result<ResponseStream> {
+        if let Some(stream) = crate::model_cassette::replay_stream() {
+            return Ok(stream);
+        }
+        let traffic_seq = crate::provider_traffic_log::log_request(prompt, model_info);
+        let stream = self
+            .stream_via_provider(
+                prompt,
+                model_info,
+                session_telemetry,
+                effort,
+                summary,
+                service_tier,
+                responses_metadata,
+                inference_trace,
+            )
+            .await?;
+        let stream = crate::model_cassette::wrap_for_recording(stream);
+        Ok(crate::provider_traffic_log::wrap_for_response_log(
+            traffic_seq,
+            stream,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn stream_via_provider(
+        &mut self,
+        prompt: &Prompt,
+        model_info: &ModelInfo,
+        session_telemetry: &SessionTelemetry,
+        effort: Option<ReasoningEffortConfig>,
+        summary: ReasoningSummaryConfig,
+        service_tier: Option<String>,
+        responses_metadata: &CodexResponsesMetadata,
+        inference_trace: &InferenceTraceContext,
     ) -> Result<ResponseStream> {
         let wire_api = self.client.state.provider.info().wire_api;
         match wire_api {
@@ -124,6 +124,9 @@ fn serializes_text_verbosity_when_set() {
             verbosity: Some(OpenAiVerbosity::Low),
             format: None,
         }),
+        temperature: None,
+        top_p: None,
+        seed: None,
         client_metadata: None,
     };
 
@@ -169,6 +172,9 @@ fn serializes_text_schema_with_strict_format() {
         prompt_cache_key: None,
         service_tier: None,
         text: Some(text_controls),
+        temperature: None,
+        top_p: None,
+        seed: None,
         client_metadata: None,
     };
 
@@ -231,6 +237,9 @@ fn omits_text_when_not_set() {
         prompt_cache_key: None,
         service_tier: None,
         text: None,
+        temperature: None,
+        top_p: None,
+        seed: None,
         client_metadata: None,
     };
 
@@ -255,6 +264,9 @@ fn serializes_flex_service_tier_when_set() {
         prompt_cache_key: None,
         service_tier: Some(ServiceTier::Flex.to_string()),
         text: None,
+        temperature: None,
+        top_p: None,
+        seed: None,
         client_metadata: None,
     };
 
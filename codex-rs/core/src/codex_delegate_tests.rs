@@ -360,6 +360,7 @@ async fn handle_exec_approval_uses_call_id_for_guardian_review_and_approval_id_f
                         ReviewDecision::Abort,
                     ]),
                     parsed_cmd: Vec::new(),
+                    explanation: None,
                 },
                 &cancel_token,
             )
@@ -163,6 +163,7 @@ pub struct CodexThread {
     pub(crate) session_source: SessionSource,
     session_configured: SessionConfiguredEvent,
     rollout_path: Option<PathBuf>,
+    created_at: std::time::Instant,
     out_of_band_elicitations: Mutex<OutOfBandElicitations>,
 }
 
@@ -194,6 +195,7 @@ impl CodexThread {
             session_source,
             session_configured,
             rollout_path,
+            created_at: std::time::Instant::now(),
             out_of_band_elicitations: Mutex::new(OutOfBandElicitations::default()),
         }
     }
@@ -506,6 +508,16 @@ impl CodexThread {
         !self.codex.tx_sub.is_closed()
     }
 
+    /// Time since this thread was loaded into the manager.
+    pub(crate) fn age(&self) -> std::time::Duration {
+        self.created_at.elapsed()
+    }
+
+    /// Whether a turn is currently executing on this thread.
+    pub(crate) async fn has_active_turn(&self) -> bool {
+        self.codex.session.active_turn.lock().await.is_some()
+    }
+
     pub async fn guardian_trunk_rollout_path(&self) -> Option<PathBuf> {
         self.codex
             .session
@@ -325,7 +325,12 @@ async fn run_compact_task_inner_impl(
     let summary_text = format!("{SUMMARY_PREFIX}\n{summary_suffix}");
     let user_messages = collect_user_messages(history_items);
 
-    let mut new_history = build_compacted_history(Vec::new(), &user_messages, &summary_text);
+    let mut new_history = build_compacted_history_with_config(
+        Vec::new(),
+        &user_messages,
+        &summary_text,
+        turn_context.config.compact_user_message_max_tokens,
+    );
     if let Some(summary_item) = new_history.last_mut() {
         // This replacement history skips `record_conversation_items`; only the appended summary
         // belongs to this compaction turn.
@@ -595,6 +600,22 @@ pub(crate) fn build_compacted_history(
     )
 }
 
+/// Like [`build_compacted_history`], honoring the configured verbatim
+/// user-message token budget when one is set.
+pub(crate) fn build_compacted_history_with_config(
+    initial_context: Vec<ResponseItem>,
+    user_messages: &[CompactedUserMessage],
+    summary_text: &str,
+    configured_max_tokens: Option<usize>,
+) -> Vec<ResponseItem> {
+    build_compacted_history_with_limit(
+        initial_context,
+        user_messages,
+        summary_text,
+        configured_max_tokens.unwrap_or(COMPACT_USER_MESSAGE_MAX_TOKENS),
+    )
+}
+
 fn build_compacted_history_with_limit(
     mut history: Vec<ResponseItem>,
     user_messages: &[CompactedUserMessage],
@@ -50,6 +50,7 @@ use codex_config::types::ToolSuggestDiscoverable;
 use codex_config::types::TuiKeymap;
 use codex_config::types::TuiNotificationSettings;
 use codex_config::types::TuiPetAnchor;
+use codex_config::types::TuiThemeOverrides;
 use codex_config::types::UriBasedFileOpener;
 use codex_config::types::WindowsSandboxModeToml;
 use codex_core_plugins::PluginLoadOutcome;
@@ -181,6 +182,40 @@ pub(crate) use resolved_permission_profile::PermissionProfileState;
 const DEFAULT_IGNORE_LARGE_UNTRACKED_DIRS: i64 = 200;
 const DEFAULT_IGNORE_LARGE_UNTRACKED_FILES: i64 = 10 * 1024 * 1024;
 
+/// Resolved limits for parallel tool-call dispatch (`[parallel]`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParallelDispatchConfig {
+    /// `false` forces every tool call to run serially.
+    pub enabled: bool,
+    /// Maximum tool calls running concurrently; `None` means unlimited.
+    pub max_concurrent_calls: Option<usize>,
+    /// Minimum delay between parallel dispatches, in milliseconds.
+    pub min_delay_ms: u64,
+}
+
+impl Default for ParallelDispatchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_concurrent_calls: None,
+            min_delay_ms: 0,
+        }
+    }
+}
+
+impl ParallelDispatchConfig {
+    fn from_toml(toml: Option<&codex_config::types::ParallelToml>) -> Self {
+        let Some(toml) = toml else {
+            return Self::default();
+        };
+        Self {
+            enabled: toml.enabled.unwrap_or(true),
+            max_concurrent_calls: toml.max_concurrent_calls.filter(|&calls| calls > 0),
+            min_delay_ms: toml.min_delay_ms.unwrap_or(0),
+        }
+    }
+}
+
 /// Compatibility-only config retained so legacy `ghost_snapshot` settings
 /// continue to load even though snapshots are no longer produced.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -678,6 +713,43 @@ pub struct Config {
     /// Defaults to `false`.
     pub show_raw_agent_reasoning: bool,
 
+    /// When set to `true`, well-known secret patterns are redacted from
+    /// rollout files and `codex exec` output. Defaults to `false`.
+    pub redact_secrets: bool,
+
+    /// When set to `true`, Codex records a git snapshot of the working tree
+    /// before each turn so `Op::RevertLastTurn` can restore it.
+    pub git_snapshots: bool,
+
+    /// When set to `true`, the session watches user config files and
+    /// re-applies safe-to-change settings when they change on disk.
+    pub config_watch: bool,
+
+    /// When set to `true`, the model is offered the built-in
+    /// `search_workspace` tool.
+    pub search_workspace_tool: bool,
+
+    /// When set to `true`, the model is offered built-in `read_file`,
+    /// `write_file`, and `list_dir` tools.
+    pub file_io_tools: bool,
+
+    /// When `true`, registers the built-in `fetch_url` web-fetch tool.
+    pub fetch_url_tool: bool,
+
+    /// Backend configuration for the local `web_search` tool.
+    pub web_search_provider: Option<codex_config::types::WebSearchProviderConfig>,
+
+    /// When `true`, Read-classified command output that looks like HTML or
+    /// troff is rendered to plain text/markdown for the model.
+    pub render_read_documents: bool,
+
+    /// When `true` (the default), unnamed threads get a heuristic title
+    /// derived from the first prompt.
+    pub auto_name_threads: bool,
+
+    /// Resolved limits for parallel tool-call dispatch.
+    pub parallel: ParallelDispatchConfig,
+
     /// Base instructions override.
     pub base_instructions: Option<String>,
 
@@ -781,6 +853,9 @@ pub struct Config {
     /// Syntax highlighting theme override (kebab-case name).
     pub tui_theme: Option<String>,
 
+    /// UI chrome color preset and per-element overrides for the TUI.
+    pub tui_theme_overrides: Option<TuiThemeOverrides>,
+
     /// Pet id preselected by the terminal pet picker.
     pub tui_pet: Option<String>,
 
@@ -882,6 +957,10 @@ pub struct Config {
     /// Directory where Codex stores the SQLite state DB.
     pub sqlite_home: PathBuf,
 
+    /// Hex-encoded 32-byte key enabling encryption-at-rest for session
+    /// rollout files.
+    pub sessions_encryption_key: Option<String>,
+
     /// Directory where Codex writes log files (defaults to `$CODEX_HOME/log`).
     pub log_dir: PathBuf,
 
@@ -905,6 +984,18 @@ pub struct Config {
     /// When true, session is not persisted on disk. Default to `false`
     pub ephemeral: bool,
 
+    /// Token budget for verbatim user messages retained alongside the
+    /// compaction summary.
+    pub compact_user_message_max_tokens: Option<usize>,
+
+    /// When `true`, patches are verified and proposed but never applied, and
+    /// non-read-only commands are echoed instead of executed.
+    pub dry_run: bool,
+
+    /// Directory where each proposed `apply_patch` call is additionally
+    /// written as a numbered unified diff file.
+    pub patch_output_dir: Option<PathBuf>,
+
     /// Optional extra configuration fields for the thread.
     pub extra_config: Option<ExtraConfig>,
 
@@ -1031,6 +1122,26 @@ pub struct Config {
     /// Default: `300000` (5 minutes).
     pub background_terminal_max_timeout: u64,
 
+    /// When `true` (the default), collected PTY output is normalized (ANSI
+    /// escapes stripped, carriage-return progress collapsed, CRLF -> LF)
+    /// before truncation and before events are emitted.
+    pub normalize_pty_output: bool,
+
+    /// When `true`, human-facing output uses ASCII-only glyphs.
+    pub ascii_output: bool,
+
+    /// When `true`, human-facing output avoids red/green-reliant styling.
+    pub colorblind_output: bool,
+
+    /// Sampling temperature forwarded to providers that support it.
+    pub model_temperature: Option<f64>,
+
+    /// Nucleus sampling parameter forwarded to providers that support it.
+    pub model_top_p: Option<f64>,
+
+    /// Deterministic sampling seed forwarded to providers that support it.
+    pub model_seed: Option<i64>,
+
     /// Compatibility-only settings retained for legacy `ghost_snapshot`
     /// config loading.
     pub ghost_snapshot: GhostSnapshotConfig,
@@ -2396,6 +2507,49 @@ fn apply_managed_filesystem_constraints(
     }
 }
 
+/// Serialize the fully merged configuration (profiles, CLI overrides, and
+/// defaults applied) as JSON with secret-bearing values masked, for
+/// debugging effective settings in automation.
+pub fn effective_config_json(config: &Config) -> serde_json::Value {
+    let mut value =
+        serde_json::to_value(config.config_layer_stack.effective_config()).unwrap_or_default();
+    mask_secret_values(&mut value);
+    value
+}
+
+fn mask_secret_values(value: &mut serde_json::Value) {
+    const SECRET_KEY_MARKERS: &[&str] = &[
+        "token",
+        "secret",
+        "password",
+        "api_key",
+        "apikey",
+        "encryption_key",
+    ];
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, entry) in map.iter_mut() {
+                let key_lower = key.to_ascii_lowercase();
+                if entry.is_string()
+                    && SECRET_KEY_MARKERS
+                        .iter()
+                        .any(|marker| key_lower.contains(marker))
+                {
+                    *entry = serde_json::Value::String("[REDACTED]".to_string());
+                } else {
+                    mask_secret_values(entry);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                mask_secret_values(item);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Optional overrides for user configuration (e.g., from CLI flags).
 #[derive(Default, Debug, Clone)]
 pub struct ConfigOverrides {
@@ -2421,6 +2575,14 @@ pub struct ConfigOverrides {
     pub tools_web_search_request: Option<bool>,
     pub ephemeral: Option<bool>,
     pub bypass_hook_trust: Option<bool>,
+    pub dry_run: Option<bool>,
+    pub patch_output_dir: Option<PathBuf>,
+    /// Sampling temperature override for this run.
+    pub model_temperature: Option<f64>,
+    /// Nucleus sampling override for this run.
+    pub model_top_p: Option<f64>,
+    /// Deterministic sampling seed override for this run.
+    pub model_seed: Option<i64>,
     /// Additional directories that should be treated as writable roots for this session.
     pub additional_writable_roots: Vec<PathBuf>,
     /// Explicit absolute runtime workspace roots for this session. When set,
@@ -3014,6 +3176,11 @@ impl Config {
             tools_web_search_request: override_tools_web_search_request,
             ephemeral,
             bypass_hook_trust,
+            dry_run,
+            patch_output_dir,
+            model_temperature: model_temperature_override,
+            model_top_p: model_top_p_override,
+            model_seed: model_seed_override,
             additional_writable_roots,
             workspace_roots: workspace_roots_override,
         } = overrides;
@@ -3026,6 +3193,17 @@ impl Config {
             );
         }
 
+        // Termux/Android has no landlock/seccomp backend; surface the
+        // degraded-sandbox status up front instead of failing at exec time.
+        if crate::util::is_termux()
+            && codex_sandboxing::get_platform_sandbox(/*windows_sandbox_enabled*/ false).is_none()
+        {
+            startup_warnings.push(
+                "Running under Termux: no OS-level sandbox backend is available, so commands run without kernel isolation. Approval policies still apply."
+                    .to_string(),
+            );
+        }
+
         if sandbox_mode.is_some() && permission_profile.is_some() {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
@@ -3749,6 +3927,9 @@ impl Config {
         if features.enabled(Feature::MemoryTool) && memories_config.use_memories {
             helper_readable_roots.push(memories_root);
         }
+        if let Some(workspace_write) = cfg.sandbox_workspace_write.as_ref() {
+            helper_readable_roots.extend(workspace_write.readable_roots.iter().cloned());
+        }
         let effective_permission_profile = constrained_permission_profile.value.get().clone();
         let (mut effective_file_system_sandbox_policy, effective_network_sandbox_policy) =
             effective_permission_profile.to_runtime_permissions();
@@ -3864,6 +4045,7 @@ impl Config {
             agent_interrupt_message_enabled,
             codex_home,
             sqlite_home,
+            sessions_encryption_key: cfg.sessions_encryption_key,
             log_dir,
             config_lock_export_dir: cfg
                 .debug
@@ -3886,6 +4068,9 @@ impl Config {
             config_layer_stack,
             history,
             ephemeral: ephemeral.unwrap_or_default(),
+            compact_user_message_max_tokens: cfg.compact_user_message_max_tokens,
+            dry_run: dry_run.unwrap_or_default(),
+            patch_output_dir,
             extra_config: None,
             bypass_hook_trust,
             file_opener: cfg.file_opener.unwrap_or(UriBasedFileOpener::VsCode),
@@ -3899,6 +4084,16 @@ impl Config {
                 .show_raw_agent_reasoning
                 .or(show_raw_agent_reasoning)
                 .unwrap_or(false),
+            redact_secrets: cfg.redact_secrets.unwrap_or(false),
+            git_snapshots: cfg.git_snapshots.unwrap_or(false),
+            config_watch: cfg.config_watch.unwrap_or(false),
+            search_workspace_tool: cfg.search_workspace_tool.unwrap_or(false),
+            file_io_tools: cfg.file_io_tools.unwrap_or(false),
+            fetch_url_tool: cfg.fetch_url_tool.unwrap_or(false),
+            web_search_provider: cfg.web_search_provider.clone(),
+            render_read_documents: cfg.render_read_documents.unwrap_or(false),
+            auto_name_threads: cfg.auto_name_threads.unwrap_or(true),
+            parallel: ParallelDispatchConfig::from_toml(cfg.parallel.as_ref()),
             guardian_policy_config,
             model_reasoning_effort: cfg.model_reasoning_effort,
             plan_mode_reasoning_effort: cfg.plan_mode_reasoning_effort,
@@ -3945,6 +4140,12 @@ impl Config {
             code_mode,
             use_experimental_unified_exec_tool,
             background_terminal_max_timeout,
+            normalize_pty_output: cfg.normalize_pty_output.unwrap_or(true),
+            ascii_output: cfg.ascii_output.unwrap_or(false),
+            colorblind_output: cfg.colorblind_output.unwrap_or(false),
+            model_temperature: model_temperature_override.or(cfg.model_temperature),
+            model_top_p: model_top_p_override.or(cfg.model_top_p),
+            model_seed: model_seed_override.or(cfg.model_seed),
             ghost_snapshot,
             multi_agent_v2,
             token_budget,
@@ -4000,6 +4201,7 @@ impl Config {
                 .unwrap_or(true),
             tui_terminal_title: cfg.tui.as_ref().and_then(|t| t.terminal_title.clone()),
             tui_theme: cfg.tui.as_ref().and_then(|t| t.theme.clone()),
+            tui_theme_overrides: cfg.tui.as_ref().and_then(|t| t.theme_overrides.clone()),
             tui_pet: cfg.tui.as_ref().and_then(|t| t.pet.clone()),
             tui_pet_anchor: cfg
                 .tui
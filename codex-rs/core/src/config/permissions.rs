@@ -76,6 +76,9 @@ pub(crate) fn builtin_permission_profile(
         BUILT_IN_WORKSPACE_PROFILE => Some(match workspace_write {
             Some(SandboxWorkspaceWrite {
                 writable_roots: _,
+                // Readable roots apply to every profile; they are folded into
+                // the effective policy after profile compilation.
+                readable_roots: _,
                 network_access,
                 exclude_tmpdir_env_var,
                 exclude_slash_tmp,
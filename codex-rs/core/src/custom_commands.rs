@@ -0,0 +1,288 @@
+//! Discovery of user- and project-defined custom commands.
+//!
+//! Custom commands live as files so teams can version-control their slash
+//! commands: `$CODEX_HOME/commands/*.md` (user scope) and
+//! `<dir>/.codex/commands/*` discovered from the cwd upward (project scope,
+//! nearest directory winning). Markdown files may start with a simple
+//! front-matter block for metadata, with the body used as the prompt:
+//!
+//! ```markdown
+//! ---
+//! description: Summarize the current diff
+//! argument-hint: [base branch]
+//! ---
+//! Summarize the changes against $ARGUMENTS.
+//! ```
+//!
+//! TOML files declare `description`/`prompt` keys instead. Project commands
+//! override user commands with the same name.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::warn;
+
+pub const COMMANDS_SUBDIR: &str = "commands";
+
+/// Where a custom command was discovered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomCommandScope {
+    /// `$CODEX_HOME/commands`.
+    User,
+    /// `<dir>/.codex/commands` for a directory between cwd and the root.
+    Project,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomCommand {
+    /// Command name (the file stem); invoked as `/<name>`.
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Hint describing expected arguments, shown next to the command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub argument_hint: Option<String>,
+    /// Prompt text sent as the user message; `$ARGUMENTS` is replaced with
+    /// whatever follows the command name.
+    pub prompt: String,
+    pub scope: CustomCommandScope,
+    /// File the command was loaded from.
+    pub path: PathBuf,
+}
+
+impl CustomCommand {
+    /// Expand the command into the prompt to submit for the given arguments.
+    pub fn expand(&self, arguments: &str) -> String {
+        let arguments = arguments.trim();
+        if self.prompt.contains("$ARGUMENTS") {
+            self.prompt.replace("$ARGUMENTS", arguments)
+        } else if arguments.is_empty() {
+            self.prompt.clone()
+        } else {
+            format!("{}\n\n{arguments}", self.prompt.trim_end())
+        }
+    }
+}
+
+/// Load user and project custom commands, with project commands (nearest
+/// directory first) overriding user commands of the same name. Returns the
+/// commands sorted by name.
+pub fn load_custom_commands(codex_home: &Path, cwd: &Path) -> Vec<CustomCommand> {
+    let mut commands: BTreeMap<String, CustomCommand> = BTreeMap::new();
+    load_commands_from_dir(
+        &codex_home.join(COMMANDS_SUBDIR),
+        CustomCommandScope::User,
+        &mut commands,
+    );
+
+    // Walk root-down so directories closer to cwd override their ancestors.
+    let ancestors: Vec<&Path> = cwd.ancestors().collect();
+    for dir in ancestors.into_iter().rev() {
+        let commands_dir = dir.join(".codex").join(COMMANDS_SUBDIR);
+        load_commands_from_dir(&commands_dir, CustomCommandScope::Project, &mut commands);
+    }
+
+    commands.into_values().collect()
+}
+
+fn load_commands_from_dir(
+    dir: &Path,
+    scope: CustomCommandScope,
+    commands: &mut BTreeMap<String, CustomCommand>,
+) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect();
+    // Directory order is platform-dependent; sort so a name defined by both
+    // `.md` and `.toml` resolves deterministically (the `.toml` wins).
+    paths.sort();
+    for path in paths {
+        let (Some(stem), Some(extension)) = (
+            path.file_stem().and_then(|stem| stem.to_str()),
+            path.extension().and_then(|ext| ext.to_str()),
+        ) else {
+            continue;
+        };
+        let command = match extension {
+            "md" => parse_markdown_command(stem, &path, scope.clone()),
+            "toml" => parse_toml_command(stem, &path, scope.clone()),
+            _ => continue,
+        };
+        match command {
+            Ok(Some(command)) => {
+                commands.insert(command.name.clone(), command);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!("skipping custom command {}: {err}", path.display());
+            }
+        }
+    }
+}
+
+fn parse_markdown_command(
+    stem: &str,
+    path: &Path,
+    scope: CustomCommandScope,
+) -> std::io::Result<Option<CustomCommand>> {
+    let contents = std::fs::read_to_string(path)?;
+    let (front_matter, body) = split_front_matter(&contents);
+    let prompt = body.trim().to_string();
+    if prompt.is_empty() {
+        return Ok(None);
+    }
+    let mut description = None;
+    let mut argument_hint = None;
+    if let Some(front_matter) = front_matter {
+        for line in front_matter.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "description" => description = Some(value.to_string()),
+                "argument-hint" | "argument_hint" => argument_hint = Some(value.to_string()),
+                _ => {}
+            }
+        }
+    }
+    Ok(Some(CustomCommand {
+        name: stem.to_string(),
+        description,
+        argument_hint,
+        prompt,
+        scope,
+        path: path.to_path_buf(),
+    }))
+}
+
+#[derive(Deserialize)]
+struct TomlCommand {
+    prompt: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    argument_hint: Option<String>,
+}
+
+fn parse_toml_command(
+    stem: &str,
+    path: &Path,
+    scope: CustomCommandScope,
+) -> std::io::Result<Option<CustomCommand>> {
+    let contents = std::fs::read_to_string(path)?;
+    let command: TomlCommand = toml::from_str(&contents)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+    if command.prompt.trim().is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(CustomCommand {
+        name: stem.to_string(),
+        description: command.description,
+        argument_hint: command.argument_hint,
+        prompt: command.prompt.trim().to_string(),
+        scope,
+        path: path.to_path_buf(),
+    }))
+}
+
+/// Split a leading `---` front-matter block from a markdown document.
+fn split_front_matter(contents: &str) -> (Option<&str>, &str) {
+    let Some(rest) = contents.strip_prefix("---\n") else {
+        return (None, contents);
+    };
+    match rest.split_once("\n---") {
+        Some((front_matter, body)) => {
+            let body = body.strip_prefix('\n').unwrap_or(body);
+            (Some(front_matter), body)
+        }
+        None => (None, contents),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn write_command(dir: &Path, name: &str, contents: &str) {
+        std::fs::create_dir_all(dir).expect("create commands dir");
+        std::fs::write(dir.join(name), contents).expect("write command file");
+    }
+
+    #[test]
+    fn loads_markdown_with_front_matter_and_toml() {
+        let home = tempfile::TempDir::new().expect("create home");
+        let commands_dir = home.path().join(COMMANDS_SUBDIR);
+        write_command(
+            &commands_dir,
+            "summarize.md",
+            "---\ndescription: Summarize the diff\nargument-hint: [base]\n---\nSummarize $ARGUMENTS.\n",
+        );
+        write_command(
+            &commands_dir,
+            "triage.toml",
+            "prompt = \"Triage the failing tests\"\ndescription = \"Triage\"\n",
+        );
+
+        let cwd = tempfile::TempDir::new().expect("create cwd");
+        let commands = load_custom_commands(home.path(), cwd.path());
+
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].name, "summarize");
+        assert_eq!(
+            commands[0].description.as_deref(),
+            Some("Summarize the diff")
+        );
+        assert_eq!(commands[0].argument_hint.as_deref(), Some("[base]"));
+        assert_eq!(commands[0].expand("main"), "Summarize main.");
+        assert_eq!(commands[1].name, "triage");
+        assert_eq!(commands[1].scope, CustomCommandScope::User);
+    }
+
+    #[test]
+    fn project_commands_override_user_commands() {
+        let home = tempfile::TempDir::new().expect("create home");
+        write_command(
+            &home.path().join(COMMANDS_SUBDIR),
+            "deploy.md",
+            "user deploy prompt\n",
+        );
+
+        let repo = tempfile::TempDir::new().expect("create repo");
+        write_command(
+            &repo.path().join(".codex").join(COMMANDS_SUBDIR),
+            "deploy.md",
+            "project deploy prompt\n",
+        );
+
+        let commands = load_custom_commands(home.path(), repo.path());
+
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].prompt, "project deploy prompt");
+        assert_eq!(commands[0].scope, CustomCommandScope::Project);
+    }
+
+    #[test]
+    fn arguments_append_when_no_placeholder() {
+        let command = CustomCommand {
+            name: "fix".to_string(),
+            description: None,
+            argument_hint: None,
+            prompt: "Fix the build.".to_string(),
+            scope: CustomCommandScope::User,
+            path: PathBuf::from("fix.md"),
+        };
+
+        assert_eq!(command.expand(""), "Fix the build.");
+        assert_eq!(command.expand("on main"), "Fix the build.\n\non main");
+    }
+}
@@ -400,12 +400,37 @@ impl ExecPolicyManager {
             source,
         })?;
 
+        self.apply_allow_prefix_rule_in_memory(&amendment.command)
+    }
+
+    /// Add an allow-prefix rule to the in-memory policy only, so the trust
+    /// lasts for the rest of this session without touching the rules file.
+    pub(crate) async fn add_session_allow_prefix_rule(
+        &self,
+        amendment: &ExecPolicyAmendment,
+    ) -> Result<(), ExecPolicyUpdateError> {
+        let _update_guard =
+            self.update_lock
+                .acquire()
+                .await
+                .map_err(|_| ExecPolicyUpdateError::AddRule {
+                    source: ExecPolicyRuleError::InvalidRule(
+                        "exec policy update semaphore closed".to_string(),
+                    ),
+                })?;
+        self.apply_allow_prefix_rule_in_memory(&amendment.command)
+    }
+
+    fn apply_allow_prefix_rule_in_memory(
+        &self,
+        command: &[String],
+    ) -> Result<(), ExecPolicyUpdateError> {
         let current_policy = self.current();
         let match_options = MatchOptions {
             resolve_host_executables: true,
         };
         let existing_evaluation = current_policy.check_multiple_with_options(
-            [&amendment.command],
+            [command],
             &|_| Decision::Forbidden,
             &match_options,
         );
@@ -418,7 +443,7 @@ impl ExecPolicyManager {
         }
 
         let mut updated_policy = current_policy.as_ref().clone();
-        updated_policy.add_prefix_rule(&amendment.command, Decision::Allow)?;
+        updated_policy.add_prefix_rule(command, Decision::Allow)?;
         self.policy.store(Arc::new(updated_policy));
         Ok(())
     }
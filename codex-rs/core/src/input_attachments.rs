@@ -0,0 +1,88 @@
+//! Expansion of attachment inputs (`UserInput::LocalFile` / `UserInput::Url`)
+//! into text items before a user message is recorded, so non-image context
+//! can be attached without pasting contents into the prompt.
+
+use codex_http_client::build_reqwest_client_with_custom_ca;
+use codex_protocol::user_input::MAX_USER_INPUT_TEXT_CHARS;
+use codex_protocol::user_input::UserInput;
+use codex_utils_string::take_bytes_at_char_boundary;
+use std::path::Path;
+use std::time::Duration;
+
+const URL_FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+/// Bound fetched pages separately from the overall user-input cap so one URL
+/// cannot monopolize the message.
+const MAX_ATTACHMENT_CHARS: usize = MAX_USER_INPUT_TEXT_CHARS / 4;
+
+/// Expands `LocalFile` and `Url` inputs into `Text` items; other inputs pass
+/// through unchanged. Failures become inline placeholders so the model sees
+/// what was attempted instead of silently missing context.
+pub(crate) async fn expand_attachment_inputs(items: Vec<UserInput>) -> Vec<UserInput> {
+    let mut out = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            UserInput::LocalFile { path } => {
+                out.push(text_input(file_attachment_text(&path).await))
+            }
+            UserInput::Url { url } => out.push(text_input(url_attachment_text(&url).await)),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn text_input(text: String) -> UserInput {
+    UserInput::Text {
+        text,
+        text_elements: Vec::new(),
+    }
+}
+
+fn truncated(content: &str) -> (&str, bool) {
+    let truncated = take_bytes_at_char_boundary(content, MAX_ATTACHMENT_CHARS);
+    (truncated, truncated.len() < content.len())
+}
+
+async fn file_attachment_text(path: &Path) -> String {
+    match tokio::fs::read(path).await {
+        Ok(bytes) => {
+            let content = String::from_utf8_lossy(&bytes);
+            let (content, was_truncated) = truncated(&content);
+            let suffix = if was_truncated { "\n[truncated]" } else { "" };
+            format!(
+                "<attached-file path=\"{}\">\n{content}{suffix}\n</attached-file>",
+                path.display()
+            )
+        }
+        Err(err) => format!("[failed to read attached file {}: {err}]", path.display()),
+    }
+}
+
+async fn url_attachment_text(url: &str) -> String {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return format!("[unsupported attachment url scheme: {url}]");
+    }
+    let client = match build_reqwest_client_with_custom_ca(
+        reqwest::Client::builder()
+            .timeout(URL_FETCH_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::limited(5)),
+    ) {
+        Ok(client) => client,
+        Err(err) => return format!("[failed to build HTTP client for {url}: {err}]"),
+    };
+    match client.get(url).send().await {
+        Ok(response) => {
+            let status = response.status();
+            match response.text().await {
+                Ok(body) if status.is_success() => {
+                    let (content, was_truncated) = truncated(&body);
+                    let suffix = if was_truncated { "\n[truncated]" } else { "" };
+                    format!("<attached-url href=\"{url}\">\n{content}{suffix}\n</attached-url>")
+                }
+                Ok(_) => format!("[failed to fetch {url}: HTTP {status}]"),
+                Err(err) => format!("[failed to read body of {url}: {err}]"),
+            }
+        }
+        Err(err) => format!("[failed to fetch {url}: {err}]"),
+    }
+}
@@ -32,7 +32,9 @@ pub use codex_thread::TryStartTurnIfIdleError;
 pub use codex_thread::TryStartTurnIfIdleRejectionReason;
 pub use session::turn_context::TurnContext;
 mod agent;
+pub use agent::apply_agent_role;
 mod agent_communication;
+mod approval_audit;
 mod attestation;
 mod codex_delegate;
 mod command_canonicalization;
@@ -41,6 +43,7 @@ pub mod connectors;
 pub mod context;
 mod context_manager;
 mod current_time;
+pub mod custom_commands;
 mod elicitation;
 mod environment_selection;
 pub mod exec;
@@ -51,6 +54,7 @@ mod git_info_tests;
 mod guardian;
 mod hook_runtime;
 mod image_preparation;
+mod input_attachments;
 mod installation_id;
 pub(crate) mod landlock;
 pub use landlock::spawn_command_under_linux_sandbox;
@@ -58,6 +62,9 @@ pub(crate) mod mcp;
 mod mcp_skill_dependencies;
 mod mcp_tool_approval_templates;
 mod mcp_tool_exposure;
+mod model_cassette;
+mod provider_traffic_log;
+pub use provider_traffic_log::init_provider_traffic_log;
 mod network_policy_decision;
 pub(crate) mod network_proxy_loader;
 pub use mcp::McpManager;
@@ -117,8 +124,10 @@ pub(crate) mod windows_sandbox_read_grants;
 pub use thread_manager::ForkSnapshot;
 pub use thread_manager::NewThread;
 pub use thread_manager::StartThreadOptions;
+pub use thread_manager::ThreadActivityState;
 pub use thread_manager::ThreadManager;
 pub use thread_manager::ThreadShutdownReport;
+pub use thread_manager::ThreadSummary;
 pub use thread_manager::build_models_manager;
 pub use thread_manager::local_agent_graph_store_from_state_db;
 pub use thread_manager::thread_store_from_config;
@@ -191,6 +200,7 @@ pub use client::X_CODEX_TURN_METADATA_HEADER;
 pub use client_common::Prompt;
 pub use client_common::ResponseEvent;
 pub use client_common::ResponseStream;
+pub use codex_api::SamplingControls;
 pub use codex_prompts::REVIEW_PROMPT;
 pub use compact::content_items_to_text;
 pub use current_time::SleepFuture;
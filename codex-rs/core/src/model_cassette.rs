@@ -0,0 +1,194 @@
+//! Record/replay cassettes for model-provider responses.
+//!
+//! `CODEX_RECORD=path` appends every [`ResponseEvent`] streamed for each
+//! request to a JSONL cassette; `CODEX_REPLAY=path` serves the recorded
+//! responses back in order without touching the network. This gives
+//! integration tests and user bug reproductions a deterministic provider
+//! without per-test wiremock scaffolding.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use codex_protocol::error::CodexErr;
+use codex_protocol::error::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::client_common::ResponseEvent;
+use crate::client_common::ResponseStream;
+
+const RECORD_ENV_VAR: &str = "CODEX_RECORD";
+const REPLAY_ENV_VAR: &str = "CODEX_REPLAY";
+const REPLAY_CHANNEL_CAPACITY: usize = 1600;
+
+/// One line of a cassette file: a streamed event, or the marker separating
+/// successive responses.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CassetteLine {
+    Event(ResponseEvent),
+    ResponseEnd,
+}
+
+fn recorder() -> Option<&'static Mutex<File>> {
+    static RECORDER: OnceLock<Option<Mutex<File>>> = OnceLock::new();
+    RECORDER
+        .get_or_init(|| {
+            let path = std::env::var_os(RECORD_ENV_VAR)?;
+            match OpenOptions::new().create(true).append(true).open(&path) {
+                Ok(file) => Some(Mutex::new(file)),
+                Err(err) => {
+                    warn!("failed to open {RECORD_ENV_VAR} cassette {path:?}: {err}");
+                    None
+                }
+            }
+        })
+        .as_ref()
+}
+
+/// Writes one recorded response (events plus the end marker) as a contiguous
+/// block so concurrent streams cannot interleave their events in the file.
+fn record_response(lines: Vec<String>) {
+    let Some(file) = recorder() else {
+        return;
+    };
+    let marker = match serde_json::to_string(&CassetteLine::ResponseEnd) {
+        Ok(marker) => marker,
+        Err(err) => {
+            warn!("failed to serialize cassette marker: {err}");
+            return;
+        }
+    };
+    let Ok(mut file) = file.lock() else {
+        return;
+    };
+    for line in lines.iter().chain(std::iter::once(&marker)) {
+        if let Err(err) = writeln!(file, "{line}") {
+            warn!("failed to write cassette line: {err}");
+            return;
+        }
+    }
+}
+
+fn replay_queue() -> Option<&'static Mutex<VecDeque<Vec<ResponseEvent>>>> {
+    static REPLAYER: OnceLock<Option<Mutex<VecDeque<Vec<ResponseEvent>>>>> = OnceLock::new();
+    REPLAYER
+        .get_or_init(|| {
+            let path = std::env::var_os(REPLAY_ENV_VAR)?;
+            match load_cassette(Path::new(&path)) {
+                Ok(responses) => Some(Mutex::new(responses)),
+                Err(err) => {
+                    warn!("failed to load {REPLAY_ENV_VAR} cassette {path:?}: {err}");
+                    None
+                }
+            }
+        })
+        .as_ref()
+}
+
+fn load_cassette(path: &Path) -> std::io::Result<VecDeque<Vec<ResponseEvent>>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut responses = VecDeque::new();
+    let mut current = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<CassetteLine>(&line) {
+            Ok(CassetteLine::Event(event)) => current.push(event),
+            Ok(CassetteLine::ResponseEnd) => responses.push_back(std::mem::take(&mut current)),
+            Err(err) => {
+                return Err(std::io::Error::other(format!(
+                    "invalid cassette line: {err}"
+                )));
+            }
+        }
+    }
+    // A trailing response without an end marker (e.g. the recording process
+    // was interrupted) is still replayable.
+    if !current.is_empty() {
+        responses.push_back(current);
+    }
+    Ok(responses)
+}
+
+/// Returns the next recorded response as a stream when `CODEX_REPLAY` is
+/// active, or `None` to fall through to the real provider. An exhausted
+/// cassette yields a stream that fails with a descriptive error instead of
+/// silently hitting the network.
+pub(crate) fn replay_stream() -> Option<ResponseStream> {
+    let queue = replay_queue()?;
+    let events = queue.lock().ok()?.pop_front();
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent>>(REPLAY_CHANNEL_CAPACITY);
+    tokio::spawn(async move {
+        match events {
+            Some(events) => {
+                for event in events {
+                    if tx_event.send(Ok(event)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            None => {
+                let _ = tx_event
+                    .send(Err(CodexErr::Stream(
+                        "cassette exhausted: no recorded response left to replay".to_string(),
+                        None,
+                    )))
+                    .await;
+            }
+        }
+    });
+    Some(ResponseStream {
+        rx_event,
+        consumer_dropped: CancellationToken::new(),
+    })
+}
+
+/// Tees the stream's events into the `CODEX_RECORD` cassette, terminated by a
+/// response-end marker once the provider stream finishes. A no-op when
+/// recording is not active.
+pub(crate) fn wrap_for_recording(inner: ResponseStream) -> ResponseStream {
+    if recorder().is_none() {
+        return inner;
+    }
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent>>(REPLAY_CHANNEL_CAPACITY);
+    let consumer_dropped = inner.consumer_dropped.clone();
+    let mut inner = inner;
+    tokio::spawn(async move {
+        use futures::StreamExt;
+
+        let mut recorded: Vec<String> = Vec::new();
+        while let Some(event) = inner.next().await {
+            if let Ok(event) = &event {
+                match serde_json::to_string(&CassetteLine::Event(event.clone())) {
+                    Ok(json) => recorded.push(json),
+                    Err(err) => warn!("failed to serialize cassette event: {err}"),
+                }
+            }
+            if tx_event.send(event).await.is_err() {
+                return;
+            }
+        }
+        record_response(recorded);
+    });
+    ResponseStream {
+        rx_event,
+        consumer_dropped,
+    }
+}
+
+#[cfg(test)]
+#[path = "model_cassette_tests.rs"]
+mod tests;
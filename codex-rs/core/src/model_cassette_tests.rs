@@ -0,0 +1,63 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn cassette_roundtrip_preserves_responses() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cassette.jsonl");
+    let mut file = File::create(&path).unwrap();
+    let events = vec![
+        ResponseEvent::Created,
+        ResponseEvent::OutputTextDelta("hello".to_string()),
+        ResponseEvent::Completed {
+            response_id: "resp-1".to_string(),
+            token_usage: None,
+            end_turn: Some(true),
+        },
+    ];
+    for event in events {
+        writeln!(
+            file,
+            "{}",
+            serde_json::to_string(&CassetteLine::Event(event)).unwrap()
+        )
+        .unwrap();
+    }
+    writeln!(
+        file,
+        "{}",
+        serde_json::to_string(&CassetteLine::ResponseEnd).unwrap()
+    )
+    .unwrap();
+    writeln!(
+        file,
+        "{}",
+        serde_json::to_string(&CassetteLine::Event(ResponseEvent::Created)).unwrap()
+    )
+    .unwrap();
+
+    let responses = load_cassette(&path).unwrap();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0].len(), 3);
+    match &responses[0][2] {
+        ResponseEvent::Completed {
+            response_id,
+            end_turn,
+            ..
+        } => {
+            assert_eq!(response_id, "resp-1");
+            assert_eq!(*end_turn, Some(true));
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+    // A trailing response without an end marker is preserved.
+    assert_eq!(responses[1].len(), 1);
+}
+
+#[test]
+fn invalid_cassette_line_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cassette.jsonl");
+    std::fs::write(&path, "not json\n").unwrap();
+    assert!(load_cassette(&path).is_err());
+}
@@ -69,10 +69,12 @@ async fn write_rollout_with_user_event(dir: &Path, thread_id: ThreadId) -> io::R
     };
     let meta_line = RolloutLine {
         timestamp: TEST_TIMESTAMP.to_string(),
+        turn: None,
         item: RolloutItem::SessionMeta(session_meta),
     };
     let user_event = RolloutLine {
         timestamp: TEST_TIMESTAMP.to_string(),
+        turn: None,
         item: RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
             client_id: None,
             message: "hello".to_string(),
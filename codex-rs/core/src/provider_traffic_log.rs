@@ -0,0 +1,133 @@
+//! Debug tap that writes each model request/response pair to numbered JSON
+//! files for "why did the model do that" diagnosis — the rollout alone does
+//! not show what the provider actually received.
+//!
+//! Enabled by `codex exec --log-provider-traffic <dir>` (or the
+//! `CODEX_LOG_PROVIDER_TRAFFIC` environment variable). Request `NNNN` is
+//! written as `NNNN-request.json` (instructions, input items, and tool
+//! schemas) and the streamed events for the matching response as
+//! `NNNN-response.json`. Secrets are redacted before anything reaches disk.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use codex_protocol::openai_models::ModelInfo;
+use codex_utils_string::take_bytes_at_char_boundary;
+use serde_json::json;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::client_common::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::client_common::ResponseStream;
+
+const TRAFFIC_DIR_ENV_VAR: &str = "CODEX_LOG_PROVIDER_TRAFFIC";
+const RESPONSE_CHANNEL_CAPACITY: usize = 1600;
+
+static TRAFFIC_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+static TRAFFIC_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+static SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Enables the tap for this process, writing pairs into `dir`. First call
+/// wins; the `CODEX_LOG_PROVIDER_TRAFFIC` env var acts as a fallback for
+/// harnesses without a flag.
+pub fn init_provider_traffic_log(dir: PathBuf) {
+    let _ = TRAFFIC_DIR_OVERRIDE.set(dir);
+}
+
+fn traffic_dir() -> Option<&'static PathBuf> {
+    TRAFFIC_DIR
+        .get_or_init(|| {
+            let dir = TRAFFIC_DIR_OVERRIDE
+                .get()
+                .cloned()
+                .or_else(|| std::env::var_os(TRAFFIC_DIR_ENV_VAR).map(PathBuf::from))?;
+            if let Err(err) = std::fs::create_dir_all(&dir) {
+                warn!(
+                    "failed to create provider traffic log directory {}: {err}",
+                    dir.display()
+                );
+                return None;
+            }
+            Some(dir)
+        })
+        .as_ref()
+}
+
+fn write_redacted_json(file_name: &str, value: &serde_json::Value) {
+    let Some(dir) = traffic_dir() else {
+        return;
+    };
+    let json = match serde_json::to_string_pretty(value) {
+        Ok(json) => json,
+        Err(err) => {
+            warn!("failed to serialize provider traffic log entry: {err}");
+            return;
+        }
+    };
+    let json = codex_secrets::redact_secrets(json);
+    if let Err(err) = std::fs::write(dir.join(file_name), json) {
+        warn!("failed to write provider traffic log entry {file_name}: {err}");
+    }
+}
+
+/// Logs the model-facing side of a request (instructions, input items, and
+/// tool schemas) and returns the pair's sequence number, or `None` when the
+/// tap is disabled.
+pub(crate) fn log_request(prompt: &Prompt, model_info: &ModelInfo) -> Option<u64> {
+    traffic_dir()?;
+    let seq = SEQ.fetch_add(1, Ordering::Relaxed) + 1;
+    let input = prompt.get_formatted_input_for_request(model_info.use_responses_lite);
+    let tools = codex_tools::create_tools_json_for_responses_api(&prompt.tools)
+        .unwrap_or_else(|err| vec![json!({ "error": format!("unserializable tools: {err}") })]);
+    let request = json!({
+        "model": model_info.slug,
+        "instructions": prompt.base_instructions.text,
+        "input": input,
+        "tools": tools,
+    });
+    write_redacted_json(&format!("{seq:04}-request.json"), &request);
+    Some(seq)
+}
+
+/// Tees the stream's events into `NNNN-response.json` once the provider
+/// stream finishes. A no-op when the tap is disabled.
+pub(crate) fn wrap_for_response_log(seq: Option<u64>, inner: ResponseStream) -> ResponseStream {
+    let Some(seq) = seq else {
+        return inner;
+    };
+    let (tx_event, rx_event) =
+        mpsc::channel::<codex_protocol::error::Result<ResponseEvent>>(RESPONSE_CHANNEL_CAPACITY);
+    let consumer_dropped = inner.consumer_dropped.clone();
+    let mut inner = inner;
+    tokio::spawn(async move {
+        use futures::StreamExt;
+
+        let mut events: Vec<serde_json::Value> = Vec::new();
+        while let Some(event) = inner.next().await {
+            match &event {
+                Ok(event) => match serde_json::to_value(event) {
+                    Ok(value) => events.push(value),
+                    Err(err) => warn!("failed to serialize provider traffic event: {err}"),
+                },
+                Err(err) => {
+                    let message = take_bytes_at_char_boundary(&err.to_string(), 2048).to_string();
+                    events.push(json!({ "stream_error": message }));
+                }
+            }
+            if tx_event.send(event).await.is_err() {
+                return;
+            }
+        }
+        write_redacted_json(
+            &format!("{seq:04}-response.json"),
+            &serde_json::Value::Array(events),
+        );
+    });
+    ResponseStream {
+        rx_event,
+        consumer_dropped,
+    }
+}
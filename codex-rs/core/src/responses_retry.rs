@@ -8,6 +8,7 @@ use crate::session::turn_context::TurnContext;
 use crate::util::backoff;
 use codex_protocol::error::CodexErr;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::StreamRetryingEvent;
 use codex_protocol::protocol::WarningEvent;
 use tracing::warn;
 
@@ -56,6 +57,18 @@ pub(crate) async fn handle_retryable_response_stream_error(
         };
         log_retry(request, turn_context, &err, retry_count, max_retries, delay);
 
+        // Typed retry notification for front-ends, emitted even when the
+        // prose StreamError below is suppressed for the first quiet retry.
+        sess.send_event(
+            turn_context,
+            EventMsg::StreamRetrying(StreamRetryingEvent {
+                attempt: retry_count,
+                max_attempts: max_retries,
+                delay_ms: delay.as_millis() as u64,
+            }),
+        )
+        .await;
+
         // In release builds, hide the first websocket retry notification to reduce noisy
         // transient reconnect messages. In debug builds, keep full visibility for diagnosis.
         let report_error = retry_count > 1
@@ -43,6 +43,14 @@ impl codex_rollout::RolloutConfigView for Config {
     fn generate_memories(&self) -> bool {
         self.memories.generate_memories
     }
+
+    fn sessions_encryption_key(&self) -> Option<&str> {
+        self.sessions_encryption_key.as_deref()
+    }
+
+    fn redact_secrets(&self) -> bool {
+        self.redact_secrets
+    }
 }
 
 pub(crate) mod list {
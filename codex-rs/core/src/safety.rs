@@ -202,6 +202,231 @@ fn is_write_patch_constrained_to_writable_paths(
     true
 }
 
+/// Best-effort static extraction of filesystem write targets from a shell
+/// command: delete/move/copy/creation tools plus absolute-path redirects in
+/// `shell -c` scripts. Returns the targets that fall outside the writable
+/// roots so callers can block or request approval before execution,
+/// independent of OS-level sandbox enforcement.
+pub(crate) fn command_write_targets_outside_writable_roots(
+    command: &[String],
+    file_system_sandbox_policy: &FileSystemSandboxPolicy,
+    cwd: &PathUri,
+) -> Vec<PathBuf> {
+    if file_system_sandbox_policy.has_full_disk_write_access() {
+        return Vec::new();
+    }
+    let Ok(native_cwd) = cwd.to_abs_path() else {
+        return Vec::new();
+    };
+    let native_cwd = native_cwd.into_path_buf();
+
+    let mut targets: Vec<PathBuf> = Vec::new();
+    let segments = codex_shell_command::bash::parse_shell_lc_plain_commands(command)
+        .unwrap_or_else(|| vec![command.to_vec()]);
+    for segment in &segments {
+        for raw in write_targets_for_segment(segment) {
+            targets.push(resolve_against_cwd(raw, &native_cwd));
+        }
+    }
+    for raw in redirect_targets_in_script(command) {
+        targets.push(resolve_against_cwd(&raw, &native_cwd));
+    }
+
+    let mut outside: Vec<PathBuf> = Vec::new();
+    for target in targets {
+        let Some(normalized) = normalize_path(&target) else {
+            continue;
+        };
+        if is_discard_sink(&normalized) {
+            continue;
+        }
+        if !file_system_sandbox_policy.can_write_path_with_cwd(&normalized, &native_cwd)
+            && !outside.contains(&normalized)
+        {
+            outside.push(normalized);
+        }
+    }
+    outside
+}
+
+/// Device sinks that every shell one-liner writes to; never worth flagging.
+fn is_discard_sink(path: &Path) -> bool {
+    path.starts_with("/dev") || path.as_os_str().eq_ignore_ascii_case("nul")
+}
+
+/// Applies the static write-target pre-check to an exec approval decision:
+/// commands that would run without approval but write outside the writable
+/// roots are downgraded to an approval request (or rejected when the policy
+/// cannot ask). Only engages when no OS sandbox would contain the write —
+/// either because the decision bypasses sandboxing or the platform has none.
+pub(crate) fn enforce_write_precheck_on_exec_approval(
+    requirement: crate::tools::sandboxing::ExecApprovalRequirement,
+    command: &[String],
+    approval_policy: AskForApproval,
+    permission_profile: &PermissionProfile,
+    file_system_sandbox_policy: &FileSystemSandboxPolicy,
+    cwd: &PathUri,
+    windows_sandbox_level: WindowsSandboxLevel,
+) -> crate::tools::sandboxing::ExecApprovalRequirement {
+    use crate::tools::sandboxing::ExecApprovalRequirement;
+
+    let bypass_sandbox = match &requirement {
+        ExecApprovalRequirement::Skip { bypass_sandbox, .. } => *bypass_sandbox,
+        _ => return requirement,
+    };
+    // Disabled and External profiles intentionally run without a Codex
+    // filesystem sandbox; mirror the patch pre-check and leave them alone.
+    if matches!(
+        permission_profile,
+        PermissionProfile::Disabled | PermissionProfile::External { .. }
+    ) {
+        return requirement;
+    }
+    let sandbox_would_contain_write = !bypass_sandbox
+        && get_platform_sandbox(windows_sandbox_level != WindowsSandboxLevel::Disabled).is_some();
+    if sandbox_would_contain_write {
+        return requirement;
+    }
+
+    let outside =
+        command_write_targets_outside_writable_roots(command, file_system_sandbox_policy, cwd);
+    let Some(first) = outside.first() else {
+        return requirement;
+    };
+    let reason = format!(
+        "command writes outside the writable roots: {}",
+        first.display()
+    );
+    if matches!(approval_policy, AskForApproval::Never) {
+        ExecApprovalRequirement::Forbidden { reason }
+    } else {
+        ExecApprovalRequirement::NeedsApproval {
+            reason: Some(reason),
+            proposed_execpolicy_amendment: None,
+        }
+    }
+}
+
+pub(crate) fn resolve_against_cwd(raw: &str, cwd: &Path) -> PathBuf {
+    let path = Path::new(raw);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        cwd.join(path)
+    }
+}
+
+/// Normalizes a path by removing `.` and resolving `..` without touching the
+/// filesystem (works even if the file does not exist).
+pub(crate) fn normalize_path(path: &Path) -> Option<PathBuf> {
+    let mut out = PathBuf::new();
+    for comp in path.components() {
+        match comp {
+            Component::ParentDir => {
+                out.pop();
+            }
+            Component::CurDir => { /* skip */ }
+            other => out.push(other.as_os_str()),
+        }
+    }
+    Some(out)
+}
+
+/// Write targets of a single parsed command segment for well-known
+/// file-mutating tools; conservative (flags are skipped, unknown programs
+/// report nothing).
+pub(crate) fn write_targets_for_segment(segment: &[String]) -> Vec<&str> {
+    let mut words = segment.iter().map(String::as_str).peekable();
+    let mut program = match words.next() {
+        Some(program) => program,
+        None => return Vec::new(),
+    };
+    // Unwrap common wrappers so `sudo rm /etc/x` is still recognized.
+    while matches!(basename(program), "sudo" | "env" | "nohup" | "command") {
+        program = loop {
+            match words.next() {
+                Some(word) if word.starts_with('-') || word.contains('=') => continue,
+                Some(word) => break word,
+                None => return Vec::new(),
+            }
+        };
+    }
+
+    let args: Vec<&str> = words.collect();
+    let non_flag_args = || {
+        args.iter()
+            .copied()
+            .filter(|arg| !arg.starts_with('-'))
+            .collect::<Vec<&str>>()
+    };
+    match basename(program) {
+        "rm" | "rmdir" | "unlink" | "shred" | "touch" | "mkdir" | "truncate" | "tee" => {
+            non_flag_args()
+        }
+        // Destination is the final operand.
+        "mv" | "cp" | "install" | "ln" => non_flag_args().last().copied().into_iter().collect(),
+        // Owner/mode comes first; the remaining operands are targets.
+        "chmod" | "chown" | "chgrp" => non_flag_args().into_iter().skip(1).collect(),
+        "dd" => args
+            .iter()
+            .filter_map(|arg| arg.strip_prefix("of="))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Redirect targets (`> /abs/path`, `>>/abs/path`) inside a `shell -c`
+/// script. Only absolute paths are reported; relative redirects resolve
+/// under the cwd, which the writable-root check already covers via the
+/// returned path.
+fn redirect_targets_in_script(command: &[String]) -> Vec<String> {
+    let script = match command {
+        [shell, flag, script, ..]
+            if matches!(basename(shell), "bash" | "sh" | "zsh" | "dash")
+                && flag.starts_with('-')
+                && flag.contains('c') =>
+        {
+            script.as_str()
+        }
+        _ => return Vec::new(),
+    };
+
+    let mut targets = Vec::new();
+    let mut chars = script.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '>' {
+            continue;
+        }
+        // `>>` append redirect.
+        if chars.peek() == Some(&'>') {
+            chars.next();
+        }
+        // `>&2`-style descriptor duplication is not a file write.
+        if chars.peek() == Some(&'&') {
+            continue;
+        }
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+        let mut target = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || matches!(c, ';' | '|' | '&' | ')' | '<' | '>') {
+                break;
+            }
+            target.push(c);
+            chars.next();
+        }
+        if !target.is_empty() {
+            targets.push(target);
+        }
+    }
+    targets
+}
+
+fn basename(program: &str) -> &str {
+    program.rsplit(['/', '\\']).next().unwrap_or(program)
+}
+
 #[cfg(test)]
 #[path = "safety_tests.rs"]
 mod tests;
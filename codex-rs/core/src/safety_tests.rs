@@ -346,3 +346,120 @@ fn missing_project_dot_codex_config_requires_approval() {
         SafetyCheck::AskUser,
     );
 }
+
+#[test]
+fn write_precheck_flags_targets_outside_writable_roots() {
+    let tmp = TempDir::new().unwrap();
+    let cwd = tmp.path().abs();
+    let cwd_uri = PathUri::from_abs_path(&cwd);
+    let outside = cwd.parent().unwrap().join("outside.txt");
+
+    let policy = FileSystemSandboxPolicy::workspace_write(
+        &[],
+        /*exclude_tmpdir_env_var*/ true,
+        /*exclude_slash_tmp*/ true,
+    );
+
+    let vec_str = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    // Direct delete of an absolute path outside the workspace.
+    let outside_str = outside.as_path().to_string_lossy().to_string();
+    let hits = command_write_targets_outside_writable_roots(
+        &vec_str(&["rm", "-f", &outside_str]),
+        &policy,
+        &cwd_uri,
+    );
+    assert_eq!(hits, vec![outside.as_path().to_path_buf()]);
+
+    // Same command inside a `bash -lc` script.
+    let script = format!("echo ok && rm -f {outside_str}");
+    let hits = command_write_targets_outside_writable_roots(
+        &vec_str(&["bash", "-lc", &script]),
+        &policy,
+        &cwd_uri,
+    );
+    assert_eq!(hits, vec![outside.as_path().to_path_buf()]);
+
+    // Redirect to an absolute path outside the workspace.
+    let script = format!("echo pwned > {outside_str}");
+    let hits = command_write_targets_outside_writable_roots(
+        &vec_str(&["bash", "-lc", &script]),
+        &policy,
+        &cwd_uri,
+    );
+    assert_eq!(hits, vec![outside.as_path().to_path_buf()]);
+
+    // Writes inside the workspace are fine.
+    let hits = command_write_targets_outside_writable_roots(
+        &vec_str(&["rm", "-f", "inner.txt"]),
+        &policy,
+        &cwd_uri,
+    );
+    assert_eq!(hits, Vec::<PathBuf>::new());
+
+    // Reads and unknown programs report nothing.
+    let hits = command_write_targets_outside_writable_roots(
+        &vec_str(&["cat", &outside_str]),
+        &policy,
+        &cwd_uri,
+    );
+    assert_eq!(hits, Vec::<PathBuf>::new());
+}
+
+#[test]
+fn write_precheck_downgrades_skip_to_approval() {
+    use crate::tools::sandboxing::ExecApprovalRequirement;
+
+    let tmp = TempDir::new().unwrap();
+    let cwd = tmp.path().abs();
+    let cwd_uri = PathUri::from_abs_path(&cwd);
+    let outside = cwd.parent().unwrap().join("outside.txt");
+    let outside_str = outside.as_path().to_string_lossy().to_string();
+    let policy = FileSystemSandboxPolicy::workspace_write(
+        &[],
+        /*exclude_tmpdir_env_var*/ true,
+        /*exclude_slash_tmp*/ true,
+    );
+    let command: Vec<String> = ["rm", "-f", &outside_str]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let permission_profile = PermissionProfile::workspace_write_with(
+        &[],
+        NetworkSandboxPolicy::Restricted,
+        /*exclude_tmpdir_env_var*/ true,
+        /*exclude_slash_tmp*/ true,
+    );
+    let skip = ExecApprovalRequirement::Skip {
+        bypass_sandbox: true,
+        proposed_execpolicy_amendment: None,
+    };
+
+    let downgraded = enforce_write_precheck_on_exec_approval(
+        skip.clone(),
+        &command,
+        AskForApproval::OnRequest,
+        &permission_profile,
+        &policy,
+        &cwd_uri,
+        WindowsSandboxLevel::Disabled,
+    );
+    assert!(matches!(
+        downgraded,
+        ExecApprovalRequirement::NeedsApproval { .. }
+    ));
+
+    let forbidden = enforce_write_precheck_on_exec_approval(
+        skip,
+        &command,
+        AskForApproval::Never,
+        &permission_profile,
+        &policy,
+        &cwd_uri,
+        WindowsSandboxLevel::Disabled,
+    );
+    assert!(matches!(
+        forbidden,
+        ExecApprovalRequirement::Forbidden { .. }
+    ));
+}
@@ -141,6 +141,12 @@ fn save_config_resolved_fields(
         Some(config.include_collaboration_mode_instructions);
     lock_config.include_environment_context = Some(config.include_environment_context);
     lock_config.background_terminal_max_timeout = Some(config.background_terminal_max_timeout);
+    lock_config.normalize_pty_output = Some(config.normalize_pty_output);
+    lock_config.ascii_output = Some(config.ascii_output);
+    lock_config.colorblind_output = Some(config.colorblind_output);
+    lock_config.model_temperature = config.model_temperature;
+    lock_config.model_top_p = config.model_top_p;
+    lock_config.model_seed = config.model_seed;
 
     // Feature aliases and feature configs need to be written in their resolved
     // form; otherwise replay can drift when a legacy key maps to the same
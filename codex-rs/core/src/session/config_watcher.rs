@@ -0,0 +1,69 @@
+//! Optional hot-reload watcher for user config layers.
+//!
+//! When `config_watch = true`, the session watches the files backing its user
+//! config layers and re-runs the existing reload path when they change, so
+//! safe-to-change settings (trusted commands, MCP server enable flags, rate
+//! limits) apply mid-session without a restart. Each applied reload emits a
+//! [`EventMsg::ConfigReloaded`] event.
+
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use codex_file_watcher::DebouncedWatchReceiver;
+use codex_file_watcher::FileWatcher;
+use codex_file_watcher::WatchPath;
+use codex_protocol::protocol::ConfigReloadedEvent;
+use codex_protocol::protocol::Event;
+use codex_protocol::protocol::EventMsg;
+use tracing::warn;
+
+use crate::session::INITIAL_SUBMIT_ID;
+use crate::session::session::Session;
+
+/// Quiet period after the first change before reloading, so editors that
+/// write config files in several steps trigger a single reload.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+pub(crate) fn spawn_config_watcher(sess: &Arc<Session>) {
+    let session: Weak<Session> = Arc::downgrade(sess);
+    let sess_for_paths = Arc::clone(sess);
+    tokio::spawn(async move {
+        let watch_paths = sess_for_paths.user_config_layer_paths().await;
+        drop(sess_for_paths);
+        if watch_paths.is_empty() {
+            return;
+        }
+
+        let watcher = match FileWatcher::new() {
+            Ok(watcher) => Arc::new(watcher),
+            Err(err) => {
+                warn!("failed to create config watcher: {err}");
+                return;
+            }
+        };
+        let (subscriber, rx) = watcher.add_subscriber();
+        let _registration = subscriber.register_paths(
+            watch_paths
+                .into_iter()
+                .map(|path| WatchPath {
+                    path,
+                    recursive: false,
+                })
+                .collect(),
+        );
+        let mut rx = DebouncedWatchReceiver::new(rx, DEBOUNCE);
+
+        while let Some(event) = rx.recv().await {
+            let Some(sess) = session.upgrade() else {
+                break;
+            };
+            sess.reload_user_config_layer().await;
+            sess.send_event_raw(Event {
+                id: INITIAL_SUBMIT_ID.to_owned(),
+                msg: EventMsg::ConfigReloaded(ConfigReloadedEvent { paths: event.paths }),
+            })
+            .await;
+        }
+    });
+}
@@ -22,29 +22,40 @@ use crate::tasks::CompactTask;
 use crate::tasks::UserShellCommandMode;
 use crate::tasks::UserShellCommandTask;
 use crate::tasks::execute_user_shell_command;
+use codex_protocol::approvals::ExecPolicyAmendment;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::ApprovalLogEvent;
 use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::ConversationExportedEvent;
+use codex_protocol::protocol::EffectiveConfigEvent;
 use codex_protocol::protocol::ErrorEvent;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::FilesChangedEvent;
 use codex_protocol::protocol::GuardianAssessmentEvent;
 use codex_protocol::protocol::GuardianAssessmentStatus;
 use codex_protocol::protocol::InterAgentCommunication;
 use codex_protocol::protocol::McpServerRefreshConfig;
+use codex_protocol::protocol::McpStatusEvent;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::RealtimeConversationListVoicesResponseEvent;
 use codex_protocol::protocol::RealtimeVoicesList;
 use codex_protocol::protocol::ReviewDecision;
 use codex_protocol::protocol::ReviewRequest;
 use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::SessionInfoEvent;
+use codex_protocol::protocol::SessionStatusEvent;
+use codex_protocol::protocol::ThreadCheckpointEvent;
 use codex_protocol::protocol::ThreadMemoryMode;
 use codex_protocol::protocol::ThreadRolledBackEvent;
 use codex_protocol::protocol::ThreadSettingsAppliedEvent;
 use codex_protocol::protocol::ThreadSettingsOverrides;
 use codex_protocol::protocol::ThreadSettingsSnapshot;
+use codex_protocol::protocol::TrustCommandScope;
 use codex_protocol::protocol::TurnAbortReason;
+use codex_protocol::protocol::TurnRevertedEvent;
 use codex_protocol::protocol::WarningEvent;
 use codex_protocol::request_permissions::RequestPermissionsResponse;
 use codex_protocol::request_user_input::RequestUserInputResponse;
@@ -55,6 +66,7 @@ use codex_protocol::mcp::RequestId as ProtocolRequestId;
 use codex_rmcp_client::ElicitationAction;
 use codex_rmcp_client::ElicitationResponse;
 use serde_json::Value;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::debug;
 use tracing::info;
@@ -207,6 +219,9 @@ pub(super) async fn user_input_or_turn_inner(
     else {
         unreachable!();
     };
+    // Expand file/URL attachments into text items up front so history,
+    // telemetry, and serialization all see the resolved contents.
+    let items = crate::input_attachments::expand_attachment_inputs(items).await;
     let emit_thread_settings_applied = thread_settings != ThreadSettingsOverrides::default();
     let mut updates = if emit_thread_settings_applied {
         thread_settings_update(sess, thread_settings).await
@@ -449,6 +464,42 @@ pub async fn refresh_mcp_servers(sess: &Arc<Session>, refresh_config: McpServerR
     *guard = Some(refresh_config);
 }
 
+pub async fn mcp_status(sess: &Arc<Session>, sub_id: String) {
+    let servers = sess
+        .services
+        .latest_mcp_runtime()
+        .manager()
+        .server_health()
+        .await;
+    sess.send_event_raw(Event {
+        id: sub_id,
+        msg: EventMsg::McpStatus(McpStatusEvent { servers }),
+    })
+    .await;
+}
+
+pub async fn export_conversation(sess: &Arc<Session>, sub_id: String, destination: PathBuf) {
+    let result = async {
+        let rollout_path = sess
+            .current_rollout_path()
+            .await
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| "no rollout file for this conversation".to_string())?;
+        codex_rollout::export_session_archive(&rollout_path, &destination)
+            .await
+            .map_err(|err| err.to_string())
+    }
+    .await;
+    let msg = match result {
+        Ok(path) => EventMsg::ConversationExported(ConversationExportedEvent { path }),
+        Err(message) => EventMsg::Error(ErrorEvent {
+            message: format!("failed to export conversation: {message}"),
+            codex_error_info: None,
+        }),
+    };
+    sess.send_event_raw(Event { id: sub_id, msg }).await;
+}
+
 pub async fn reload_user_config(sess: &Arc<Session>) {
     sess.reload_user_config_layer().await;
 }
@@ -564,6 +615,436 @@ pub async fn thread_rollback(sess: &Arc<Session>, sub_id: String, num_turns: u32
     .await;
 }
 
+async fn current_user_turn_count(sess: &Arc<Session>) -> u32 {
+    let history = sess.clone_history().await;
+    let count = history
+        .raw_items()
+        .iter()
+        .filter(|item| is_user_turn_boundary(item))
+        .count();
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+/// Record a named checkpoint in the rollout so the conversation can later be
+/// forked back to this exact point with [`fork_from_checkpoint`].
+pub async fn create_checkpoint(sess: &Arc<Session>, sub_id: String, name: String) {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Error(ErrorEvent {
+                message: "checkpoint name must not be empty".to_string(),
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            }),
+        })
+        .await;
+        return;
+    }
+
+    let checkpoint_msg = EventMsg::ThreadCheckpoint(ThreadCheckpointEvent {
+        name,
+        user_turn_count: current_user_turn_count(sess).await,
+    });
+    sess.persist_rollout_items(&[RolloutItem::EventMsg(checkpoint_msg.clone())])
+        .await;
+    if let Err(err) = sess.flush_rollout().await {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Error(ErrorEvent {
+                message: format!("failed to persist checkpoint: {err}"),
+                codex_error_info: Some(CodexErrorInfo::Other),
+            }),
+        })
+        .await;
+        return;
+    }
+    sess.deliver_event_raw(Event {
+        id: sub_id,
+        msg: checkpoint_msg,
+    })
+    .await;
+}
+
+/// Drop every user turn recorded after the named checkpoint by delegating to
+/// the existing drop-last-N rollback path with the exact count.
+pub async fn fork_from_checkpoint(sess: &Arc<Session>, sub_id: String, name: String) {
+    let checkpoint_error = |message: String| {
+        EventMsg::Error(ErrorEvent {
+            message,
+            codex_error_info: Some(CodexErrorInfo::ThreadRollbackFailed),
+        })
+    };
+
+    let live_thread = match sess.live_thread_for_persistence("fork from checkpoint") {
+        Ok(live_thread) => live_thread,
+        Err(_) => {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: checkpoint_error(
+                    "forking from a checkpoint requires persisted thread history".to_string(),
+                ),
+            })
+            .await;
+            return;
+        }
+    };
+    if let Err(err) = live_thread.flush().await {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: checkpoint_error(format!(
+                "failed to flush thread persistence before checkpoint lookup: {err}"
+            )),
+        })
+        .await;
+        return;
+    }
+    let stored_history = match live_thread.load_history(/*include_archived*/ false).await {
+        Ok(history) => history,
+        Err(err) => {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: checkpoint_error(format!(
+                    "failed to load thread history for checkpoint lookup: {err}"
+                )),
+            })
+            .await;
+            return;
+        }
+    };
+
+    let checkpoint = stored_history
+        .items
+        .iter()
+        .rev()
+        .find_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::ThreadCheckpoint(checkpoint))
+                if checkpoint.name == name =>
+            {
+                Some(checkpoint.clone())
+            }
+            _ => None,
+        });
+    let Some(checkpoint) = checkpoint else {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: checkpoint_error(format!("no checkpoint named `{name}` in this conversation")),
+        })
+        .await;
+        return;
+    };
+
+    let current_turns = current_user_turn_count(sess).await;
+    if current_turns <= checkpoint.user_turn_count {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: checkpoint_error(format!(
+                "conversation is already at or before checkpoint `{name}`"
+            )),
+        })
+        .await;
+        return;
+    }
+
+    thread_rollback(sess, sub_id, current_turns - checkpoint.user_turn_count).await;
+}
+
+/// Restore the working tree to the git snapshot recorded before the most
+/// recent turn. Requires `git_snapshots = true` in config.
+pub async fn revert_last_turn(sess: &Arc<Session>, sub_id: String) {
+    let revert_error = |message: String| {
+        EventMsg::Error(ErrorEvent {
+            message,
+            codex_error_info: Some(CodexErrorInfo::Other),
+        })
+    };
+
+    let has_active_turn = { sess.active_turn.lock().await.is_some() };
+    if has_active_turn {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: revert_error("Cannot revert while a turn is in progress.".to_string()),
+        })
+        .await;
+        return;
+    }
+
+    match restore_git_snapshot_before_turns(sess, /*num_turns*/ 1).await {
+        Ok(Some(commit)) => {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::TurnReverted(TurnRevertedEvent { commit }),
+            })
+            .await;
+        }
+        Ok(None) => {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: revert_error(
+                    "no git snapshot recorded for this conversation; enable `git_snapshots` in config"
+                        .to_string(),
+                ),
+            })
+            .await;
+        }
+        Err(message) => {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: revert_error(message),
+            })
+            .await;
+        }
+    }
+}
+
+/// Restores the git snapshot recorded for the `num_turns`-th most recent user
+/// turn, i.e. the tree state those turns started from.
+///
+/// Returns the restored commit, `Ok(None)` when no snapshot was recorded for
+/// that turn, or a user-facing error message.
+async fn restore_git_snapshot_before_turns(
+    sess: &Arc<Session>,
+    num_turns: u32,
+) -> Result<Option<String>, String> {
+    if num_turns == 0 {
+        // Rejected by the rollback that follows; nothing to restore.
+        return Ok(None);
+    }
+    let live_thread = sess
+        .live_thread_for_persistence("restore git snapshot")
+        .map_err(|_| "restoring a git snapshot requires persisted thread history".to_string())?;
+    live_thread.flush().await.map_err(|err| {
+        format!("failed to flush thread persistence before snapshot lookup: {err}")
+    })?;
+    let stored_history = live_thread
+        .load_history(/*include_archived*/ false)
+        .await
+        .map_err(|err| format!("failed to load thread history for snapshot lookup: {err}"))?;
+
+    // A turn's snapshot is persisted just before the user message that starts
+    // it, so the snapshot for the `num_turns`-th most recent turn is the last
+    // GitSnapshot between the two preceding user-turn boundaries.
+    let items = &stored_history.items;
+    let boundaries: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, item)| match item {
+            RolloutItem::ResponseItem(item) if is_user_turn_boundary(item) => Some(idx),
+            _ => None,
+        })
+        .collect();
+    let snapshot = match boundaries.len().checked_sub(num_turns as usize) {
+        Some(target) => {
+            let window_start = target
+                .checked_sub(1)
+                .map(|prev| boundaries[prev] + 1)
+                .unwrap_or(0);
+            items[window_start..boundaries[target]]
+                .iter()
+                .rev()
+                .find_map(|item| match item {
+                    RolloutItem::EventMsg(EventMsg::GitSnapshot(snapshot)) => {
+                        Some(snapshot.clone())
+                    }
+                    _ => None,
+                })
+        }
+        // Undoing more turns than were recorded: fall back to the earliest
+        // snapshot, the oldest tree state this session knows about.
+        None => items.iter().find_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::GitSnapshot(snapshot)) => Some(snapshot.clone()),
+            _ => None,
+        }),
+    };
+    let Some(snapshot) = snapshot else {
+        return Ok(None);
+    };
+
+    let cwd = {
+        let state = sess.state.lock().await;
+        state.session_configuration.cwd().clone()
+    };
+    codex_git_utils::restore_turn_snapshot(cwd.as_path(), &snapshot.commit)
+        .await
+        .map_err(|err| format!("failed to restore git snapshot {}: {err}", snapshot.commit))?;
+    Ok(Some(snapshot.commit))
+}
+
+/// Undo the last `num_turns` user turns in place: best-effort git snapshot
+/// restore (skipped silently when snapshots are not recorded), then the same
+/// conversation rollback as `Op::ThreadRollback`.
+pub async fn undo_turns(sess: &Arc<Session>, sub_id: String, num_turns: u32) {
+    let has_active_turn = { sess.active_turn.lock().await.is_some() };
+    if has_active_turn {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Error(ErrorEvent {
+                message: "Cannot undo while a turn is in progress.".to_string(),
+                codex_error_info: Some(CodexErrorInfo::Other),
+            }),
+        })
+        .await;
+        return;
+    }
+    match restore_git_snapshot_before_turns(sess, num_turns).await {
+        Ok(Some(commit)) => {
+            sess.send_event_raw(Event {
+                id: sub_id.clone(),
+                msg: EventMsg::TurnReverted(TurnRevertedEvent { commit }),
+            })
+            .await;
+        }
+        // Snapshots disabled or never recorded: conversation rollback alone
+        // is still a useful undo.
+        Ok(None) => {}
+        Err(message) => {
+            sess.send_event_raw(Event {
+                id: sub_id.clone(),
+                msg: EventMsg::Warning(WarningEvent { message }),
+            })
+            .await;
+        }
+    }
+    thread_rollback(sess, sub_id, num_turns).await;
+}
+
+/// Trust a command prefix for this session or persistently via execpolicy.
+pub async fn trust_command(
+    sess: &Arc<Session>,
+    sub_id: String,
+    pattern: Vec<String>,
+    scope: TrustCommandScope,
+) {
+    if pattern.is_empty() {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Error(ErrorEvent {
+                message: "trusted command pattern must not be empty".to_string(),
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            }),
+        })
+        .await;
+        return;
+    }
+
+    let amendment = ExecPolicyAmendment::new(pattern);
+    let result = match scope {
+        TrustCommandScope::Session => {
+            sess.services
+                .exec_policy
+                .add_session_allow_prefix_rule(&amendment)
+                .await
+        }
+        TrustCommandScope::Persistent => {
+            let codex_home = {
+                let state = sess.state.lock().await;
+                state.session_configuration.codex_home().clone()
+            };
+            sess.services
+                .exec_policy
+                .append_amendment_and_update(codex_home.as_path(), &amendment)
+                .await
+        }
+    };
+    if let Err(err) = result {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Error(ErrorEvent {
+                message: format!("failed to trust command: {err}"),
+                codex_error_info: Some(CodexErrorInfo::Other),
+            }),
+        })
+        .await;
+    }
+}
+
+/// Tear down and re-spawn a single MCP server.
+pub async fn restart_mcp_server(sess: &Arc<Session>, sub_id: String, server: String) {
+    let restarted = sess
+        .services
+        .latest_mcp_runtime()
+        .manager()
+        .restart_server(&server);
+    if !restarted {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Error(ErrorEvent {
+                message: format!("unknown MCP server `{server}`"),
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            }),
+        })
+        .await;
+    }
+}
+
+/// Report session diagnostics as a structured event.
+/// Answers `Op::GetSessionInfo` with the session's identity; the same
+/// fields are emitted proactively in `SessionConfigured` at startup.
+pub async fn session_info(sess: &Arc<Session>, sub_id: String) {
+    let rollout_path = sess.current_rollout_path().await.ok().flatten();
+    let user_turn_count = u64::from(current_user_turn_count(sess).await);
+    sess.send_event_raw(Event {
+        id: sub_id,
+        msg: EventMsg::SessionInfo(SessionInfoEvent {
+            session_id: sess.session_id(),
+            thread_id: sess.thread_id,
+            rollout_path,
+            user_turn_count,
+        }),
+    })
+    .await;
+}
+
+pub async fn session_status(sess: &Arc<Session>, sub_id: String) {
+    let snapshot = {
+        let state = sess.state.lock().await;
+        state.session_configuration.thread_config_snapshot()
+    };
+    let token_usage = sess.total_token_usage().await;
+    let mcp_servers_connected = sess
+        .services
+        .latest_mcp_runtime()
+        .manager()
+        .started_server_count() as u64;
+    let active_exec_sessions = sess
+        .services
+        .unified_exec_manager
+        .list_processes()
+        .await
+        .len() as u64;
+    let rollout_path = sess.current_rollout_path().await.ok().flatten();
+
+    sess.send_event_raw(Event {
+        id: sub_id,
+        msg: EventMsg::SessionStatus(SessionStatusEvent {
+            model: snapshot.model,
+            model_provider_id: snapshot.model_provider_id,
+            approval_policy: snapshot.approval_policy,
+            active_permission_profile: snapshot.active_permission_profile,
+            token_usage,
+            mcp_servers_connected,
+            active_exec_sessions,
+            rollout_path,
+        }),
+    })
+    .await;
+}
+
+/// Send the contents of the approval audit log back to the client.
+pub async fn get_approval_log(sess: &Arc<Session>, sub_id: String) {
+    let codex_home = {
+        let state = sess.state.lock().await;
+        state.session_configuration.codex_home().clone()
+    };
+    let msg = match crate::approval_audit::read_approval_log(codex_home.as_path()).await {
+        Ok(entries) => EventMsg::ApprovalLog(ApprovalLogEvent { entries }),
+        Err(err) => EventMsg::Error(ErrorEvent {
+            message: format!("failed to read approval audit log: {err}"),
+            codex_error_info: Some(CodexErrorInfo::Other),
+        }),
+    };
+    sess.send_event_raw(Event { id: sub_id, msg }).await;
+}
+
 pub(super) async fn persist_thread_memory_mode_update(
     sess: &Arc<Session>,
     mode: ThreadMemoryMode,
@@ -631,6 +1112,15 @@ async fn emit_thread_stop_lifecycle(sess: &Session) {
 pub async fn shutdown(sess: &Arc<Session>, sub_id: String) -> bool {
     shutdown_session_runtime(sess).await;
     info!("Shutting down Codex instance");
+
+    let files = sess.take_files_changed().await;
+    if !files.is_empty() {
+        sess.send_event_raw(Event {
+            id: sub_id.clone(),
+            msg: EventMsg::FilesChanged(FilesChangedEvent { files }),
+        })
+        .await;
+    }
     let history = sess.clone_history().await;
     let turn_count = history
         .raw_items()
@@ -807,6 +1297,22 @@ pub(super) async fn submission_loop(
                     refresh_mcp_servers(&sess, config).await;
                     false
                 }
+                Op::McpStatus => {
+                    mcp_status(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::RestartMcpServer { server } => {
+                    restart_mcp_server(&sess, sub.id.clone(), server).await;
+                    false
+                }
+                Op::TrustCommand { pattern, scope } => {
+                    trust_command(&sess, sub.id.clone(), pattern, scope).await;
+                    false
+                }
+                Op::ExportConversation { path } => {
+                    export_conversation(&sess, sub.id.clone(), path).await;
+                    false
+                }
                 Op::ReloadUserConfig => {
                     reload_user_config(&sess).await;
                     false
@@ -819,6 +1325,56 @@ pub(super) async fn submission_loop(
                     thread_rollback(&sess, sub.id.clone(), num_turns).await;
                     false
                 }
+                Op::CreateCheckpoint { name } => {
+                    create_checkpoint(&sess, sub.id.clone(), name).await;
+                    false
+                }
+                Op::ForkFromCheckpoint { name } => {
+                    fork_from_checkpoint(&sess, sub.id.clone(), name).await;
+                    false
+                }
+                Op::RevertLastTurn => {
+                    revert_last_turn(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::UndoTurns { num_turns } => {
+                    undo_turns(&sess, sub.id.clone(), num_turns).await;
+                    false
+                }
+                Op::SetParallelConfig {
+                    enabled,
+                    max_concurrent_calls,
+                    min_delay_ms,
+                } => {
+                    sess.services.parallel_limiter.update(
+                        enabled,
+                        max_concurrent_calls,
+                        min_delay_ms,
+                    );
+                    false
+                }
+                Op::GetApprovalLog => {
+                    get_approval_log(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::Status => {
+                    session_status(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::GetSessionInfo => {
+                    session_info(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::GetEffectiveConfig => {
+                    sess.send_event_raw(Event {
+                        id: sub.id.clone(),
+                        msg: EventMsg::EffectiveConfig(EffectiveConfigEvent {
+                            config: crate::config::effective_config_json(&config),
+                        }),
+                    })
+                    .await;
+                    false
+                }
                 Op::SetThreadMemoryMode { mode } => {
                     set_thread_memory_mode(&sess, sub.id.clone(), mode).await;
                     false
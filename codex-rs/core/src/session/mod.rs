@@ -108,9 +108,12 @@ use codex_protocol::models::SandboxEnforcement;
 use codex_protocol::models::format_allow_prefixes;
 use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelPreset;
+use codex_protocol::parse_command::ParsedCommand;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::NetworkSandboxPolicy;
 use codex_protocol::protocol::AdditionalContextEntry;
+use codex_protocol::protocol::ChangedFileKind;
+use codex_protocol::protocol::ChangedFileSummary;
 use codex_protocol::protocol::FileChange;
 use codex_protocol::protocol::HasLegacyEvent;
 use codex_protocol::protocol::InterAgentCommunication;
@@ -205,6 +208,7 @@ use codex_protocol::exec_output::StreamOutput;
 
 mod code_mode_warning;
 mod config_lock;
+mod config_watcher;
 pub(crate) mod context_window;
 mod handlers;
 mod inject;
@@ -1663,6 +1667,35 @@ impl Session {
         }
     }
 
+    /// File paths backing the user config layers, used by manual reloads and
+    /// the optional config hot-reload watcher.
+    pub(crate) async fn user_config_layer_paths(&self) -> Vec<PathBuf> {
+        let state = self.state.lock().await;
+        let config = &state.session_configuration.original_config_do_not_use;
+        let user_config_paths = config
+            .config_layer_stack
+            .get_user_layers(
+                ConfigLayerStackOrdering::LowestPrecedenceFirst,
+                /*include_disabled*/ true,
+            )
+            .into_iter()
+            .filter_map(|layer| match &layer.name {
+                ConfigLayerSource::User { file, .. } => Some(file.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if user_config_paths.is_empty() {
+            vec![
+                state
+                    .session_configuration
+                    .codex_home
+                    .join(CONFIG_TOML_FILE),
+            ]
+        } else {
+            user_config_paths
+        }
+    }
+
     pub(crate) async fn reload_user_config_layer(&self) {
         // Refresh layer-backed runtime state for an existing session, including enabled plugin,
         // skill, and hook state. Derived config fields such as feature gates and legacy notify
@@ -1670,32 +1703,7 @@ impl Session {
         //
         // Prefer `refresh_runtime_config()` when the host can already provide a materialized
         // config snapshot. This file-based path exists for legacy local reload flows.
-        let config_toml_paths = {
-            let state = self.state.lock().await;
-            let config = &state.session_configuration.original_config_do_not_use;
-            let user_config_paths = config
-                .config_layer_stack
-                .get_user_layers(
-                    ConfigLayerStackOrdering::LowestPrecedenceFirst,
-                    /*include_disabled*/ true,
-                )
-                .into_iter()
-                .filter_map(|layer| match &layer.name {
-                    ConfigLayerSource::User { file, .. } => Some(file.clone()),
-                    _ => None,
-                })
-                .collect::<Vec<_>>();
-            if user_config_paths.is_empty() {
-                vec![
-                    state
-                        .session_configuration
-                        .codex_home
-                        .join(CONFIG_TOML_FILE),
-                ]
-            } else {
-                user_config_paths
-            }
-        };
+        let config_toml_paths = self.user_config_layer_paths().await;
 
         let mut reloaded_user_configs = Vec::with_capacity(config_toml_paths.len());
         for config_toml_path in config_toml_paths {
@@ -1971,12 +1979,69 @@ impl Session {
             let rollout_items = vec![RolloutItem::EventMsg(event.msg.clone())];
             self.persist_rollout_items(&rollout_items).await;
         }
+        if let EventMsg::PatchApplyEnd(patch_apply_end) = &event.msg
+            && patch_apply_end.success
+        {
+            self.record_files_changed(&patch_apply_end.changes).await;
+        }
         self.services
             .rollout_thread_trace
             .record_protocol_event(&event.msg);
         self.deliver_event_raw(event).await;
     }
 
+    /// Fold a successful patch's changes into the session-level changed-files
+    /// summary reported at shutdown.
+    async fn record_files_changed(&self, changes: &HashMap<PathBuf, FileChange>) {
+        let mut files_changed = self.services.files_changed.lock().await;
+        for (path, change) in changes {
+            let (kind, lines_added, lines_removed) = match change {
+                FileChange::Add { content } => {
+                    (ChangedFileKind::Added, content.lines().count() as u64, 0)
+                }
+                FileChange::Delete { content } => {
+                    (ChangedFileKind::Deleted, 0, content.lines().count() as u64)
+                }
+                FileChange::Update { unified_diff, .. } => {
+                    let lines_added = unified_diff
+                        .lines()
+                        .filter(|line| line.starts_with('+') && !line.starts_with("+++"))
+                        .count() as u64;
+                    let lines_removed = unified_diff
+                        .lines()
+                        .filter(|line| line.starts_with('-') && !line.starts_with("---"))
+                        .count() as u64;
+                    (ChangedFileKind::Modified, lines_added, lines_removed)
+                }
+            };
+            files_changed
+                .entry(path.clone())
+                .and_modify(|summary| {
+                    // A file added or deleted earlier and touched again stays
+                    // in its original category; counts accumulate.
+                    summary.lines_added += lines_added;
+                    summary.lines_removed += lines_removed;
+                    if kind == ChangedFileKind::Deleted {
+                        summary.kind = ChangedFileKind::Deleted;
+                    }
+                })
+                .or_insert_with(|| ChangedFileSummary {
+                    path: path.clone(),
+                    kind,
+                    lines_added,
+                    lines_removed,
+                });
+        }
+    }
+
+    /// Drain the accumulated changed-files summary, sorted by path.
+    pub(crate) async fn take_files_changed(&self) -> Vec<ChangedFileSummary> {
+        let mut files_changed = self.services.files_changed.lock().await;
+        let mut files: Vec<ChangedFileSummary> = files_changed.drain().map(|(_, v)| v).collect();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files
+    }
+
     async fn deliver_event_raw(&self, event: Event) {
         // Record the last known agent status.
         if let Some(status) = agent_status_from_event(&event.msg) {
@@ -2226,6 +2291,7 @@ impl Session {
                 additional_permissions.as_ref(),
             )
         });
+        let explanation = explain_parsed_commands(&parsed_cmd);
         let event = EventMsg::ExecApprovalRequest(ExecApprovalRequestEvent {
             call_id,
             approval_id,
@@ -2241,6 +2307,7 @@ impl Session {
             additional_permissions,
             available_decisions: Some(available_decisions),
             parsed_cmd,
+            explanation,
         });
         self.send_event(turn_context, event).await;
         rx_approve.await.unwrap_or(ReviewDecision::Abort)
@@ -4083,3 +4150,44 @@ mod elicitation_holders_tests;
 
 #[cfg(test)]
 pub(crate) mod tests;
+
+/// One-line heuristic explanation of what a command will do, derived from the
+/// parsed command actions, so approval prompts can show intent without a
+/// model call.
+fn explain_parsed_commands(parsed: &[ParsedCommand]) -> Option<String> {
+    if parsed.is_empty() {
+        return None;
+    }
+    let mut parts: Vec<String> = Vec::new();
+    for command in parsed {
+        let part = match command {
+            ParsedCommand::Read { name, range, .. } => match range {
+                Some((start, end)) if *end == u32::MAX => format!("reads {name}:{start}-"),
+                Some((start, end)) => format!("reads {name}:{start}-{end}"),
+                None => format!("reads {name}"),
+            },
+            ParsedCommand::ListFiles { path, .. } => match path {
+                Some(path) => format!("lists files in {path}"),
+                None => "lists files".to_string(),
+            },
+            ParsedCommand::Search { query, path, .. } => match (query, path) {
+                (Some(query), Some(path)) => format!("searches for `{query}` in {path}"),
+                (Some(query), None) => format!("searches for `{query}`"),
+                _ => "searches the workspace".to_string(),
+            },
+            ParsedCommand::Unknown { cmd } => {
+                format!("runs `{cmd}` (effects unknown; may modify files or state)")
+            }
+        };
+        if !parts.contains(&part) {
+            parts.push(part);
+        }
+    }
+    let suffix = if parts.len() > 3 {
+        format!("; and {} more", parts.len() - 3)
+    } else {
+        String::new()
+    };
+    parts.truncate(3);
+    Some(format!("{}{suffix}", parts.join("; ")))
+}
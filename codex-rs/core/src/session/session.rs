@@ -1060,6 +1060,11 @@ impl Session {
                 mcp_startup_cancellation_token: Mutex::new(CancellationToken::new()),
                 unified_exec_manager: UnifiedExecProcessManager::new(
                     config.background_terminal_max_timeout,
+                    config.normalize_pty_output,
+                ),
+                web_fetch_cache: Default::default(),
+                parallel_limiter: std::sync::Arc::new(
+                    crate::tools::parallel_limiter::ParallelLimiter::from_config(&config.parallel),
                 ),
                 elicitations: crate::elicitation::ElicitationService::new(),
                 shell_zsh_path: config.zsh_path.clone(),
@@ -1074,6 +1079,7 @@ impl Session {
                 session_telemetry,
                 models_manager: Arc::clone(&models_manager),
                 tool_approvals: Mutex::new(ApprovalStore::default()),
+                files_changed: Mutex::new(HashMap::new()),
                 guardian_rejections: Mutex::new(HashMap::new()),
                 guardian_rejection_circuit_breaker: Mutex::new(Default::default()),
                 runtime_handle: tokio::runtime::Handle::current(),
@@ -1112,6 +1118,11 @@ impl Session {
                     session_configuration.session_source.clone(),
                     session_configuration.originator.clone(),
                     config.model_verbosity,
+                    codex_api::SamplingControls {
+                        temperature: config.model_temperature,
+                        top_p: config.model_top_p,
+                        seed: config.model_seed,
+                    },
                     config.features.enabled(Feature::EnableRequestCompression),
                     config.features.enabled(Feature::RuntimeMetrics),
                     Self::build_model_client_beta_features_header(config.as_ref()),
@@ -509,6 +509,7 @@ fn test_model_client_session() -> crate::client::ModelClientSession {
         codex_protocol::protocol::SessionSource::Exec,
         "test_originator".to_string(),
         /*model_verbosity*/ None,
+        codex_api::SamplingControls::default(),
         /*enable_request_compression*/ false,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
@@ -5467,6 +5468,11 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
         mcp_startup_cancellation_token: Mutex::new(CancellationToken::new()),
         unified_exec_manager: UnifiedExecProcessManager::new(
             config.background_terminal_max_timeout,
+            config.normalize_pty_output,
+        ),
+        web_fetch_cache: Default::default(),
+        parallel_limiter: std::sync::Arc::new(
+            crate::tools::parallel_limiter::ParallelLimiter::from_config(&config.parallel),
         ),
         elicitations: crate::elicitation::ElicitationService::new(),
         shell_zsh_path: None,
@@ -5524,6 +5530,11 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
             session_configuration.session_source.clone(),
             session_configuration.originator.clone(),
             config.model_verbosity,
+            codex_api::SamplingControls {
+                temperature: config.model_temperature,
+                top_p: config.model_top_p,
+                seed: config.model_seed,
+            },
             config.features.enabled(Feature::EnableRequestCompression),
             config.features.enabled(Feature::RuntimeMetrics),
             Session::build_model_client_beta_features_header(config.as_ref()),
@@ -7598,6 +7609,11 @@ where
         mcp_startup_cancellation_token: Mutex::new(CancellationToken::new()),
         unified_exec_manager: UnifiedExecProcessManager::new(
             config.background_terminal_max_timeout,
+            config.normalize_pty_output,
+        ),
+        web_fetch_cache: Default::default(),
+        parallel_limiter: std::sync::Arc::new(
+            crate::tools::parallel_limiter::ParallelLimiter::from_config(&config.parallel),
         ),
         elicitations: crate::elicitation::ElicitationService::new(),
         shell_zsh_path: None,
@@ -7655,6 +7671,11 @@ where
             session_configuration.session_source.clone(),
             session_configuration.originator.clone(),
             config.model_verbosity,
+            codex_api::SamplingControls {
+                temperature: config.model_temperature,
+                top_p: config.model_top_p,
+                seed: config.model_seed,
+            },
             config.features.enabled(Feature::EnableRequestCompression),
             config.features.enabled(Feature::RuntimeMetrics),
             Session::build_model_client_beta_features_header(config.as_ref()),
@@ -7934,6 +7955,7 @@ async fn built_tools_uses_the_step_mcp_runtime() -> anyhow::Result<()> {
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
@@ -1544,6 +1544,15 @@ pub(super) fn realtime_text_for_event(msg: &EventMsg) -> Option<(String, Option<
         | EventMsg::SafetyBuffering(_)
         | EventMsg::ContextCompacted(_)
         | EventMsg::ThreadRolledBack(_)
+        | EventMsg::ThreadCheckpoint(_)
+        | EventMsg::GitSnapshot(_)
+        | EventMsg::TurnReverted(_)
+        | EventMsg::ApprovalLog(_)
+        | EventMsg::FilesChanged(_)
+        | EventMsg::EffectiveConfig(_)
+        | EventMsg::ConfigReloaded(_)
+        | EventMsg::SessionStatus(_)
+        | EventMsg::SessionInfo(_)
         | EventMsg::TurnStarted(_)
         | EventMsg::ThreadSettingsApplied(_)
         | EventMsg::TurnComplete(_)
@@ -1556,6 +1565,8 @@ pub(super) fn realtime_text_for_event(msg: &EventMsg) -> Option<(String, Option<
         | EventMsg::ThreadGoalUpdated(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpStatus(_)
+        | EventMsg::ConversationExported(_)
         | EventMsg::McpToolCallBegin(_)
         | EventMsg::McpToolCallEnd(_)
         | EventMsg::WebSearchBegin(_)
@@ -1580,6 +1591,7 @@ pub(super) fn realtime_text_for_event(msg: &EventMsg) -> Option<(String, Option<
         | EventMsg::ApplyPatchApprovalRequest(_)
         | EventMsg::DeprecationNotice(_)
         | EventMsg::StreamError(_)
+        | EventMsg::StreamRetrying(_)
         | EventMsg::TurnDiff(_)
         | EventMsg::RealtimeConversationListVoicesResponse(_)
         | EventMsg::PlanUpdate(_)
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+
+use codex_protocol::protocol::ChangedFileSummary;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
@@ -56,6 +58,10 @@ pub(crate) struct SessionServices {
     pub(crate) mcp_projection_lock: Mutex<()>,
     pub(crate) mcp_startup_cancellation_token: Mutex<CancellationToken>,
     pub(crate) unified_exec_manager: UnifiedExecProcessManager,
+    /// Session-scoped cache for the `fetch_url` tool (url -> markdown).
+    pub(crate) web_fetch_cache: Mutex<std::collections::HashMap<String, String>>,
+    /// Limiter applied to parallel tool-call dispatch.
+    pub(crate) parallel_limiter: std::sync::Arc<crate::tools::parallel_limiter::ParallelLimiter>,
     pub(crate) elicitations: ElicitationService,
     #[cfg_attr(not(unix), allow(dead_code))]
     pub(crate) shell_zsh_path: Option<PathBuf>,
@@ -71,6 +77,9 @@ pub(crate) struct SessionServices {
     pub(crate) models_manager: SharedModelsManager,
     pub(crate) session_telemetry: SessionTelemetry,
     pub(crate) tool_approvals: Mutex<ApprovalStore>,
+    /// Accumulated per-file change summaries from successful patch applies,
+    /// reported as a `FilesChanged` event at shutdown.
+    pub(crate) files_changed: Mutex<HashMap<PathBuf, ChangedFileSummary>>,
     pub(crate) guardian_rejections: Mutex<HashMap<String, GuardianRejection>>,
     pub(crate) guardian_rejection_circuit_breaker: Mutex<GuardianRejectionCircuitBreaker>,
     pub(crate) runtime_handle: Handle,
@@ -8,6 +8,7 @@ use crate::session::turn_context::TurnContext;
 use crate::session_startup_prewarm::SessionStartupPrewarmResolution;
 use crate::state::TaskKind;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::GitSnapshotEvent;
 use codex_protocol::protocol::TurnStartedEvent;
 use tracing::Instrument;
 use tracing::trace_span;
@@ -55,6 +56,29 @@ impl SessionTask for RegularTask {
                 collaboration_mode_kind: ctx.collaboration_mode.mode,
             });
             sess.send_event(ctx.as_ref(), event).await;
+            if ctx.config.git_snapshots
+                && let Some(snapshot_cwd) = ctx
+                    .environments
+                    .primary()
+                    .map(|environment| environment.cwd().clone())
+            {
+                match codex_git_utils::create_turn_snapshot(snapshot_cwd.as_path(), &ctx.sub_id)
+                    .await
+                {
+                    Ok(Some(commit)) => {
+                        // `send_event` persists the snapshot record per rollout policy.
+                        let snapshot_msg = EventMsg::GitSnapshot(GitSnapshotEvent {
+                            turn_id: ctx.sub_id.clone(),
+                            commit,
+                        });
+                        sess.send_event(ctx.as_ref(), snapshot_msg).await;
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        tracing::warn!("failed to create git snapshot for turn: {err}");
+                    }
+                }
+            }
             sess.set_server_reasoning_included(/*included*/ false).await;
             sess.consume_startup_prewarm_for_regular_turn(&cancellation_token)
                 .await
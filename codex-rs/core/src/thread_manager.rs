@@ -81,6 +81,7 @@ use futures::StreamExt;
 use futures::stream::FuturesUnordered;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -172,6 +173,27 @@ impl From<usize> for ForkSnapshot {
     }
 }
 
+/// Activity state reported by [`ThreadManager::list_threads`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadActivityState {
+    /// The session loop is alive and a turn is executing.
+    TurnActive,
+    /// The session loop is alive with no turn in flight.
+    Idle,
+    /// The session loop has terminated but the thread is still tracked.
+    Closed,
+}
+
+/// Summary of one loaded thread for hosts managing many concurrent sessions.
+#[derive(Debug, Clone)]
+pub struct ThreadSummary {
+    pub thread_id: ThreadId,
+    pub rollout_path: Option<PathBuf>,
+    pub state: ThreadActivityState,
+    /// Time since the thread was loaded into this manager.
+    pub age: Duration,
+}
+
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct ThreadShutdownReport {
     pub completed: Vec<ThreadId>,
@@ -585,6 +607,37 @@ impl ThreadManager {
         self.state.list_thread_ids().await
     }
 
+    /// Lists all loaded (non-internal) threads with their rollout location,
+    /// activity state, and age, oldest first.
+    pub async fn list_threads(&self) -> Vec<ThreadSummary> {
+        let threads = {
+            let threads = self.state.threads.read().await;
+            threads
+                .iter()
+                .filter(|(_, thread)| !thread.session_source.is_internal())
+                .map(|(thread_id, thread)| (*thread_id, Arc::clone(thread)))
+                .collect::<Vec<_>>()
+        };
+        let mut summaries = Vec::with_capacity(threads.len());
+        for (thread_id, thread) in threads {
+            let state = if !thread.is_running() {
+                ThreadActivityState::Closed
+            } else if thread.has_active_turn().await {
+                ThreadActivityState::TurnActive
+            } else {
+                ThreadActivityState::Idle
+            };
+            summaries.push(ThreadSummary {
+                thread_id,
+                rollout_path: thread.rollout_path(),
+                state,
+                age: thread.age(),
+            });
+        }
+        summaries.sort_by(|a, b| b.age.cmp(&a.age));
+        summaries
+    }
+
     pub fn subscribe_thread_created(&self) -> broadcast::Receiver<ThreadId> {
         self.state.thread_created_tx.subscribe()
     }
@@ -781,6 +834,20 @@ impl ThreadManager {
             .await
     }
 
+    /// Import a `.codexsession` archive into the local sessions directory.
+    /// Returns the imported rollout path, which can be handed to
+    /// [`Self::resume_thread_from_rollout`] to continue the session.
+    pub async fn import_thread_archive(
+        &self,
+        config: &Config,
+        archive_path: &Path,
+    ) -> CodexResult<PathBuf> {
+        let rollout_path =
+            codex_rollout::import_session_archive(archive_path, config.codex_home.as_path())
+                .await?;
+        Ok(rollout_path)
+    }
+
     pub async fn resume_thread_from_rollout(
         &self,
         config: Config,
@@ -919,6 +986,15 @@ impl ThreadManager {
         self.state.threads.write().await.remove(thread_id)
     }
 
+    /// Shuts down one thread's agent loop, waiting for the rollout to flush,
+    /// and stops tracking it. Returns `ThreadNotFound` for unknown ids.
+    pub async fn close_thread(&self, thread_id: ThreadId) -> CodexResult<()> {
+        let thread = self.state.get_thread(thread_id).await?;
+        thread.shutdown_and_wait().await?;
+        self.state.threads.write().await.remove(&thread_id);
+        Ok(())
+    }
+
     /// Tries to shut down all tracked threads concurrently within the provided timeout.
     /// Threads that complete shutdown are removed from the manager; incomplete shutdowns
     /// remain tracked so callers can retry or inspect them later.
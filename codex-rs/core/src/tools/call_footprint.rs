@@ -0,0 +1,238 @@
+//! Dependency-aware admission for tool-call batches.
+//!
+//! Calls that cannot run fully parallel are scheduled by their inferred file
+//! footprint instead of a single global write lock: reads of a path take a
+//! shared per-path lock and writes take it exclusively, so a write to a file
+//! orders after in-flight reads of it while calls with disjoint footprints
+//! run concurrently. Calls whose footprint cannot be inferred fall back to
+//! full serialization, preserving today's behavior.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use codex_protocol::parse_command::ParsedCommand;
+use tokio::sync::OwnedRwLockReadGuard;
+use tokio::sync::OwnedRwLockWriteGuard;
+use tokio::sync::RwLock;
+
+use crate::tools::context::ToolPayload;
+use crate::tools::router::ToolCall;
+
+/// Whether a path participates in a call as a read or a write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Access {
+    Read,
+    Write,
+}
+
+/// Inferred file footprint of a shell-style call. Paths are resolved against
+/// the call's effective working directory and normalized, so different
+/// spellings of the same file share one lock.
+#[derive(Debug, Default)]
+pub(crate) struct CallFootprint {
+    /// Normalized path -> strongest access (writes dominate reads).
+    accesses: BTreeMap<PathBuf, Access>,
+}
+
+impl CallFootprint {
+    /// Whether every access in the footprint is a read.
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.accesses.values().all(|access| *access == Access::Read)
+    }
+
+    fn record(&mut self, path: PathBuf, access: Access) {
+        self.accesses
+            .entry(path)
+            .and_modify(|existing| {
+                if access == Access::Write {
+                    *existing = Access::Write;
+                }
+            })
+            .or_insert(access);
+    }
+}
+
+/// Infers the footprint of a shell-style call from its arguments, resolving
+/// paths against `turn_cwd` (or the call's own `workdir`/`cwd` argument).
+/// Returns `None` when the command cannot be confidently parsed, which
+/// callers treat as "serialize fully".
+pub(crate) fn call_footprint(call: &ToolCall, turn_cwd: &Path) -> Option<CallFootprint> {
+    if call.tool_name != codex_tools::ToolName::plain("shell")
+        && call.tool_name != codex_tools::ToolName::plain("shell_command")
+        && call.tool_name != codex_tools::ToolName::plain("exec_command")
+    {
+        return None;
+    }
+    let ToolPayload::Function { arguments } = &call.payload else {
+        return None;
+    };
+    let arguments: serde_json::Value = serde_json::from_str(arguments).ok()?;
+    let tokens: Vec<String> = match (
+        arguments
+            .get("command")
+            .or_else(|| arguments.get("cmd"))
+            .and_then(serde_json::Value::as_str),
+        arguments
+            .get("command")
+            .and_then(serde_json::Value::as_array),
+    ) {
+        (Some(command), _) => shlex::split(command)?,
+        (None, Some(tokens)) => tokens
+            .iter()
+            .map(|token| token.as_str().map(str::to_string))
+            .collect::<Option<Vec<_>>>()?,
+        (None, None) => return None,
+    };
+    if tokens.is_empty() {
+        return None;
+    }
+    let cwd = match arguments
+        .get("workdir")
+        .or_else(|| arguments.get("cwd"))
+        .and_then(serde_json::Value::as_str)
+    {
+        Some(workdir) => crate::safety::resolve_against_cwd(workdir, turn_cwd),
+        None => turn_cwd.to_path_buf(),
+    };
+
+    let segments = codex_shell_command::bash::parse_shell_lc_plain_commands(&tokens)
+        .unwrap_or_else(|| vec![tokens.clone()]);
+    let mut footprint = CallFootprint::default();
+    for segment in &segments {
+        let writes = crate::safety::write_targets_for_segment(segment);
+        if !writes.is_empty() {
+            // Recognized mutating tool; its targets are the footprint.
+            for write in writes {
+                footprint.record(lock_key(write, &cwd), Access::Write);
+            }
+            continue;
+        }
+        for parsed in codex_shell_command::parse_command::parse_command(segment) {
+            match parsed {
+                ParsedCommand::Read { path, .. } => {
+                    let path = path.display().to_string();
+                    footprint.record(lock_key(&path, &cwd), Access::Read);
+                }
+                ParsedCommand::ListFiles { .. } | ParsedCommand::Search { .. } => {}
+                // An unparsed segment could touch anything.
+                ParsedCommand::Unknown { .. } => return None,
+                _ => {}
+            }
+        }
+    }
+    Some(footprint)
+}
+
+/// Resolves a raw path argument against the call's working directory and
+/// normalizes it so `./src/lib.rs` and `src/lib.rs` key the same lock.
+fn lock_key(raw: &str, cwd: &Path) -> PathBuf {
+    let resolved = crate::safety::resolve_against_cwd(raw, cwd);
+    crate::safety::normalize_path(&resolved).unwrap_or(resolved)
+}
+
+/// Held guards for one admitted call; dropping releases all path locks.
+pub(crate) struct FootprintGuards {
+    _guards: Vec<PathGuard>,
+}
+
+enum PathGuard {
+    Read(OwnedRwLockReadGuard<()>),
+    Write(OwnedRwLockWriteGuard<()>),
+}
+
+/// Per-turn registry of path locks used for dependency-aware admission.
+#[derive(Default)]
+pub(crate) struct PathLocks {
+    locks: Mutex<HashMap<PathBuf, Arc<RwLock<()>>>>,
+}
+
+impl PathLocks {
+    /// Acquires the footprint's path locks in sorted order (consistent
+    /// ordering across tasks prevents deadlock): shared for reads,
+    /// exclusive for writes.
+    pub(crate) async fn acquire(&self, footprint: &CallFootprint) -> FootprintGuards {
+        let mut guards = Vec::with_capacity(footprint.accesses.len());
+        for (path, access) in &footprint.accesses {
+            let lock = {
+                let Ok(mut locks) = self.locks.lock() else {
+                    continue;
+                };
+                Arc::clone(locks.entry(path.clone()).or_default())
+            };
+            let guard = match access {
+                Access::Read => PathGuard::Read(lock.read_owned().await),
+                Access::Write => PathGuard::Write(lock.write_owned().await),
+            };
+            guards.push(guard);
+        }
+        FootprintGuards { _guards: guards }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::context::ToolPayload;
+    use codex_tools::ToolName;
+
+    fn shell_call(arguments: &str) -> ToolCall {
+        ToolCall {
+            tool_name: ToolName::plain("shell_command"),
+            call_id: "call".to_string(),
+            payload: ToolPayload::Function {
+                arguments: arguments.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn read_and_write_footprints_are_classified() {
+        let cwd = Path::new("/repo");
+        let read = call_footprint(&shell_call(r#"{"command":"cat src/lib.rs"}"#), cwd)
+            .expect("read footprint");
+        assert_eq!(
+            read.accesses.get(Path::new("/repo/src/lib.rs")).copied(),
+            Some(Access::Read)
+        );
+
+        let write = call_footprint(&shell_call(r#"{"command":"rm -f src/lib.rs"}"#), cwd)
+            .expect("write footprint");
+        assert_eq!(
+            write.accesses.get(Path::new("/repo/src/lib.rs")).copied(),
+            Some(Access::Write)
+        );
+    }
+
+    #[test]
+    fn path_spellings_normalize_to_one_lock_key() {
+        let cwd = Path::new("/repo");
+        let dotted = call_footprint(&shell_call(r#"{"command":"cat ./src/lib.rs"}"#), cwd)
+            .expect("read footprint");
+        assert_eq!(
+            dotted.accesses.get(Path::new("/repo/src/lib.rs")).copied(),
+            Some(Access::Read)
+        );
+
+        let workdir = call_footprint(
+            &shell_call(r#"{"command":"cat lib.rs","workdir":"/repo/src"}"#),
+            cwd,
+        )
+        .expect("read footprint");
+        assert_eq!(
+            workdir.accesses.get(Path::new("/repo/src/lib.rs")).copied(),
+            Some(Access::Read)
+        );
+    }
+
+    #[test]
+    fn unknown_commands_have_no_footprint() {
+        assert!(
+            call_footprint(&shell_call(r#"{"command":"cargo build"}"#), Path::new("/repo"))
+                .is_none()
+        );
+    }
+}
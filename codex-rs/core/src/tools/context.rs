@@ -320,6 +320,12 @@ pub struct ExecCommandToolOutput {
     pub exit_code: Option<i32>,
     pub original_token_count: Option<usize>,
     pub hook_command: Option<String>,
+    /// Stderr captured separately from stdout; only populated when the call
+    /// requested `separate_stderr` in non-PTY mode.
+    pub stderr: Option<String>,
+    /// Set when the per-command `timeout_ms` expired and the process was
+    /// killed; the output carries whatever partial output was collected.
+    pub timed_out_after_ms: Option<u64>,
 }
 
 impl ToolOutput for ExecCommandToolOutput {
@@ -379,6 +385,10 @@ impl ToolOutput for ExecCommandToolOutput {
             #[serde(skip_serializing_if = "Option::is_none")]
             original_token_count: Option<usize>,
             output: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            stderr: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            timed_out_after_ms: Option<u64>,
         }
 
         let result = UnifiedExecCodeModeResult {
@@ -391,6 +401,8 @@ impl ToolOutput for ExecCommandToolOutput {
                 Some(max_tokens) => self.truncated_output(max_tokens),
                 None => String::from_utf8_lossy(&self.raw_output).to_string(),
             },
+            stderr: self.stderr.clone(),
+            timed_out_after_ms: self.timed_out_after_ms,
         };
 
         serde_json::to_value(result).unwrap_or_else(|err| {
@@ -423,6 +435,12 @@ impl ExecCommandToolOutput {
             sections.push(format!("Process exited with code {exit_code}"));
         }
 
+        if let Some(timeout_ms) = self.timed_out_after_ms {
+            sections.push(format!(
+                "Process timed out after {timeout_ms} ms; partial output attached"
+            ));
+        }
+
         if let Some(process_id) = &self.process_id {
             sections.push(format!("Process running with session ID {process_id}"));
         }
@@ -434,6 +452,14 @@ impl ExecCommandToolOutput {
         sections.push("Output:".to_string());
         sections.push(self.truncated_output(self.model_output_max_tokens()));
 
+        if let Some(stderr) = &self.stderr {
+            sections.push("Stderr:".to_string());
+            sections.push(formatted_truncate_text(
+                stderr,
+                TruncationPolicy::Tokens(self.model_output_max_tokens()),
+            ));
+        }
+
         sections.join("\n")
     }
 }
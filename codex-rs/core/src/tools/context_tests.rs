@@ -435,6 +435,8 @@ fn exec_command_tool_output_formats_truncated_response() {
         exit_code: Some(0),
         original_token_count: Some(10),
         hook_command: None,
+        stderr: None,
+        timed_out_after_ms: None,
     }
     .to_response_item("call-42", &payload);
 
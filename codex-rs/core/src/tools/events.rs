@@ -351,7 +351,21 @@ impl ToolEmitter {
         output: &ExecToolCallOutput,
         ctx: ToolEventCtx<'_>,
     ) -> String {
-        super::format_exec_output_for_model(output, ctx.turn.model_info.truncation_policy.into())
+        let truncation_policy = ctx.turn.model_info.truncation_policy.into();
+        // Optionally convert HTML/troff documentation read by the agent to
+        // plain text/markdown before it reaches the model context.
+        if ctx.turn.config.render_read_documents
+            && let (Self::Shell { parsed_cmd, .. } | Self::UnifiedExec { parsed_cmd, .. }) = self
+            && crate::tools::read_rendering::is_read_only_command(parsed_cmd)
+            && let Some(rendered) =
+                crate::tools::read_rendering::render_read_document(&output.aggregated_output.text)
+        {
+            let mut rendered_output = output.clone();
+            rendered_output.aggregated_output =
+                codex_protocol::exec_output::StreamOutput::new(rendered);
+            return super::format_exec_output_for_model(&rendered_output, truncation_policy);
+        }
+        super::format_exec_output_for_model(output, truncation_policy)
     }
 
     pub async fn finish(
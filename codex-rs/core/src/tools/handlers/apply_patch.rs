@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -45,6 +47,9 @@ use codex_protocol::models::AdditionalPermissionProfile;
 use codex_protocol::models::FileSystemPermissions;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::FileChange;
+use codex_protocol::protocol::PatchApplyBeginEvent;
+use codex_protocol::protocol::PatchApplyEndEvent;
+use codex_protocol::protocol::PatchApplyStatus;
 use codex_protocol::protocol::PatchApplyUpdatedEvent;
 use codex_sandboxing::policy_transforms::effective_file_system_sandbox_policy;
 use codex_sandboxing::policy_transforms::merge_permission_profiles;
@@ -396,6 +401,41 @@ impl ApplyPatchHandler {
         .await
         {
             codex_apply_patch::MaybeApplyPatchVerified::Body(changes) => {
+                write_patch_output_if_configured(&turn, &call_id, &changes).await;
+                // In dry-run mode, record the verified patch as a proposal
+                // without touching the filesystem.
+                if turn.config.dry_run {
+                    let proposed = convert_apply_patch_to_protocol(&changes);
+                    session
+                        .send_event(
+                            turn.as_ref(),
+                            EventMsg::PatchApplyBegin(PatchApplyBeginEvent {
+                                call_id: call_id.clone(),
+                                turn_id: turn.sub_id.clone(),
+                                auto_approved: true,
+                                changes: proposed.clone(),
+                            }),
+                        )
+                        .await;
+                    session
+                        .send_event(
+                            turn.as_ref(),
+                            EventMsg::PatchApplyEnd(PatchApplyEndEvent {
+                                call_id: call_id.clone(),
+                                turn_id: turn.sub_id.clone(),
+                                stdout: "dry run: patch verified but not applied".to_string(),
+                                stderr: String::new(),
+                                success: false,
+                                changes: proposed,
+                                status: PatchApplyStatus::Declined,
+                            }),
+                        )
+                        .await;
+                    return Ok(boxed_tool_output(ApplyPatchToolOutput::from_text(
+                        "Dry run: patch verified and recorded as a proposal, but not applied."
+                            .to_string(),
+                    )));
+                }
                 let (file_paths, effective_additional_permissions, file_system_sandbox_policy) =
                     effective_patch_permissions(
                         session.as_ref(),
@@ -662,6 +702,104 @@ fn require_environment_id(
     }
 }
 
+/// Monotonic index for `--patch-out` diff files within this process.
+static NEXT_PATCH_FILE_INDEX: AtomicU64 = AtomicU64::new(1);
+
+/// When `patch_output_dir` is configured, serialize the proposed patch as a
+/// numbered unified diff file so "codex proposes, CI applies" workflows can
+/// collect every change even when apply is enabled.
+async fn write_patch_output_if_configured(
+    turn: &TurnContext,
+    call_id: &str,
+    action: &codex_apply_patch::ApplyPatchAction,
+) {
+    let Some(dir) = turn.config.patch_output_dir.as_ref() else {
+        return;
+    };
+    let changes = convert_apply_patch_to_protocol(action);
+    let relative_root = turn
+        .environments
+        .primary()
+        .map(|environment| environment.cwd().as_path().to_path_buf());
+    let index = NEXT_PATCH_FILE_INDEX.fetch_add(1, Ordering::Relaxed);
+    let turn_id = &turn.sub_id;
+    let file_name = format!("{index:04}-{turn_id}-{call_id}.diff");
+    let contents = render_changes_as_unified_diff(&changes, relative_root.as_deref());
+    let path = dir.join(file_name);
+    let result = async {
+        tokio::fs::create_dir_all(dir).await?;
+        tokio::fs::write(&path, contents).await
+    }
+    .await;
+    if let Err(err) = result {
+        tracing::warn!("failed to write patch output to {}: {err}", path.display());
+    }
+}
+
+fn render_changes_as_unified_diff(
+    changes: &HashMap<PathBuf, FileChange>,
+    relative_root: Option<&Path>,
+) -> String {
+    let mut entries: Vec<_> = changes.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut out = String::new();
+    for (path, change) in entries {
+        // Diffs apply cleanly in CI only with repo-relative paths.
+        let path = relative_root
+            .and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path);
+        let display = path.display();
+        match change {
+            FileChange::Add { content } => {
+                let line_count = content.lines().count();
+                out.push_str(&format!(
+                    "diff --git a/{display} b/{display}\nnew file mode 100644\n--- /dev/null\n+++ b/{display}\n@@ -0,0 +1,{line_count} @@\n"
+                ));
+                for line in content.lines() {
+                    out.push('+');
+                    out.push_str(line);
+                    out.push('\n');
+                }
+            }
+            FileChange::Delete { content } => {
+                let line_count = content.lines().count();
+                out.push_str(&format!(
+                    "diff --git a/{display} b/{display}\ndeleted file mode 100644\n--- a/{display}\n+++ /dev/null\n@@ -1,{line_count} +0,0 @@\n"
+                ));
+                for line in content.lines() {
+                    out.push('-');
+                    out.push_str(line);
+                    out.push('\n');
+                }
+            }
+            FileChange::Update {
+                unified_diff,
+                move_path,
+            } => {
+                let new_display = move_path.as_ref().map_or_else(
+                    || display.to_string(),
+                    |move_path| {
+                        relative_root
+                            .and_then(|root| move_path.strip_prefix(root).ok())
+                            .unwrap_or(move_path)
+                            .display()
+                            .to_string()
+                    },
+                );
+                out.push_str(&format!(
+                    "diff --git a/{display} b/{new_display}\n--- a/{display}\n+++ b/{new_display}\n"
+                ));
+                out.push_str(unified_diff);
+                if !unified_diff.ends_with('\n') {
+                    out.push('\n');
+                }
+            }
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 #[path = "apply_patch_tests.rs"]
 mod tests;
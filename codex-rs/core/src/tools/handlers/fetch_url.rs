@@ -0,0 +1,438 @@
+//! Built-in `fetch_url` function tool.
+//!
+//! Downloads a page, strips boilerplate, converts the HTML to markdown with
+//! a length cap, and caches results per session — more reliable than having
+//! the model drive `curl` through the safe-command heuristics. Gated by the
+//! `fetch_url_tool` config flag and the sandbox network policy, and honors
+//! `robots.txt` Disallow rules for the wildcard user agent.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use codex_http_client::build_reqwest_client_with_custom_ca;
+use codex_protocol::models::PermissionProfile;
+use codex_tools::JsonSchema;
+use codex_tools::ResponsesApiTool;
+use codex_tools::ToolName;
+use codex_tools::ToolSpec;
+use codex_utils_string::take_bytes_at_char_boundary;
+use serde::Deserialize;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolPayload;
+use crate::tools::context::boxed_tool_output;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::CoreToolRuntime;
+use crate::tools::registry::ToolExecutor;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+/// Cap on the markdown returned from a single fetch.
+const MAX_FETCH_BYTES: usize = 128 * 1024;
+/// Cap on cached pages per session so long sessions stay bounded.
+const MAX_CACHED_PAGES: usize = 64;
+
+#[derive(Deserialize)]
+struct FetchUrlArgs {
+    url: String,
+}
+
+pub struct FetchUrlHandler;
+
+impl ToolExecutor<ToolInvocation> for FetchUrlHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain("fetch_url")
+    }
+
+    fn spec(&self) -> ToolSpec {
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "url".to_string(),
+            JsonSchema::string(Some("http(s) URL of the page to fetch.".to_string())),
+        );
+        ToolSpec::Function(ResponsesApiTool {
+            name: "fetch_url".to_string(),
+            description: "Fetch a web page and return its content converted to markdown. \
+Results are cached for the session and capped in length."
+                .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                properties,
+                Some(vec!["url".to_string()]),
+                Some(false.into()),
+            ),
+            output_schema: None,
+        })
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            let ToolPayload::Function { arguments } = &invocation.payload else {
+                return Err(FunctionCallError::RespondToModel(
+                    "fetch_url handler received unsupported payload".to_string(),
+                ));
+            };
+            let args: FetchUrlArgs = parse_arguments(arguments)?;
+            let url = args.url.trim().to_string();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(FunctionCallError::RespondToModel(
+                    "fetch_url only supports http(s) URLs".to_string(),
+                ));
+            }
+
+            ensure_network_allowed(&invocation)?;
+
+            if let Some(cached) = invocation
+                .session
+                .services
+                .web_fetch_cache
+                .lock()
+                .await
+                .get(&url)
+                .cloned()
+            {
+                return Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                    cached,
+                    Some(true),
+                )));
+            }
+
+            let client = build_reqwest_client_with_custom_ca(
+                reqwest::Client::builder()
+                    .timeout(FETCH_TIMEOUT)
+                    .redirect(reqwest::redirect::Policy::limited(5)),
+            )
+            .map_err(|err| {
+                FunctionCallError::RespondToModel(format!("failed to build HTTP client: {err}"))
+            })?;
+
+            ensure_allowed_by_robots(&client, &url).await?;
+
+            let response = client.get(&url).send().await.map_err(|err| {
+                FunctionCallError::RespondToModel(format!("failed to fetch {url}: {err}"))
+            })?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(FunctionCallError::RespondToModel(format!(
+                    "failed to fetch {url}: HTTP {status}"
+                )));
+            }
+            let content_type = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or("")
+                .to_string();
+            let body = response.text().await.map_err(|err| {
+                FunctionCallError::RespondToModel(format!("failed to read body of {url}: {err}"))
+            })?;
+
+            let markdown = if content_type.contains("html") || looks_like_html(&body) {
+                html_to_markdown(&body)
+            } else {
+                body
+            };
+            let markdown = take_bytes_at_char_boundary(&markdown, MAX_FETCH_BYTES).to_string();
+
+            let mut cache = invocation.session.services.web_fetch_cache.lock().await;
+            if cache.len() >= MAX_CACHED_PAGES {
+                cache.clear();
+            }
+            cache.insert(url, markdown.clone());
+            drop(cache);
+
+            Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                markdown,
+                Some(true),
+            )))
+        })
+    }
+}
+
+impl CoreToolRuntime for FetchUrlHandler {}
+
+/// Rejects the call when the sandbox network policy restricts outbound
+/// network access: the tool runs in the host process, so it must not become
+/// a bypass for a network-restricted profile.
+pub(crate) fn ensure_network_allowed(invocation: &ToolInvocation) -> Result<(), FunctionCallError> {
+    let network_enabled = match invocation.turn.permission_profile() {
+        PermissionProfile::Managed { network, .. } | PermissionProfile::External { network } => {
+            network.is_enabled()
+        }
+        PermissionProfile::Disabled => true,
+    };
+    if network_enabled {
+        Ok(())
+    } else {
+        Err(FunctionCallError::RespondToModel(
+            "fetch_url is unavailable: the sandbox network policy restricts network access"
+                .to_string(),
+        ))
+    }
+}
+
+/// Honors `robots.txt` Disallow rules for the `*` user agent; fetch failures
+/// for robots.txt itself are treated as allow, matching common crawler
+/// behavior.
+async fn ensure_allowed_by_robots(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<(), FunctionCallError> {
+    let Ok(parsed) = reqwest::Url::parse(url) else {
+        return Err(FunctionCallError::RespondToModel(format!(
+            "invalid url: {url}"
+        )));
+    };
+    let Some(host) = parsed.host_str() else {
+        return Err(FunctionCallError::RespondToModel(format!(
+            "invalid url: {url}"
+        )));
+    };
+    let robots_url = format!(
+        "{}://{host}{}/robots.txt",
+        parsed.scheme(),
+        parsed
+            .port()
+            .map(|port| format!(":{port}"))
+            .unwrap_or_default()
+    );
+    let Ok(response) = client.get(&robots_url).send().await else {
+        return Ok(());
+    };
+    if !response.status().is_success() {
+        return Ok(());
+    }
+    let Ok(body) = response.text().await else {
+        return Ok(());
+    };
+    if robots_disallows(&body, parsed.path()) {
+        return Err(FunctionCallError::RespondToModel(format!(
+            "fetch_url declined: {robots_url} disallows fetching {}",
+            parsed.path()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns whether the `*` user-agent group disallows `path`.
+fn robots_disallows(robots: &str, path: &str) -> bool {
+    // RFC 9309 groups: one or more consecutive User-agent lines followed by
+    // rules; the group applies when *any* of its user-agent lines is `*`,
+    // and only the next user-agent line (not a blank line) starts a new one.
+    let mut applies = false;
+    let mut in_group_header = false;
+    for line in robots.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "user-agent" => {
+                if !in_group_header {
+                    applies = false;
+                    in_group_header = true;
+                }
+                applies |= value == "*";
+            }
+            "disallow" => {
+                in_group_header = false;
+                if applies && !value.is_empty() && path.starts_with(value) {
+                    return true;
+                }
+            }
+            _ => in_group_header = false,
+        }
+    }
+    false
+}
+
+fn looks_like_html(body: &str) -> bool {
+    let head = body.trim_start();
+    head.starts_with("<!DOCTYPE") || head.starts_with("<!doctype") || head.starts_with("<html")
+}
+
+/// Minimal boilerplate-stripping HTML to markdown conversion: drops
+/// script/style/head/nav/footer subtrees, maps headings, paragraphs, list
+/// items, links, and emphasis, and collapses the rest to text.
+pub(crate) fn html_to_markdown(html: &str) -> String {
+    let mut out = String::with_capacity(html.len() / 2);
+    let mut rest = html;
+    let mut skip_depth = 0usize;
+    let mut link_href: Option<String> = None;
+
+    while let Some(start) = rest.find('<') {
+        let text = &rest[..start];
+        if skip_depth == 0 {
+            push_text(&mut out, text);
+        }
+        rest = &rest[start..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let tag_name = tag
+            .trim_start_matches('/')
+            .split([' ', '\t', '\n', '/'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        let closing = tag.starts_with('/');
+        let self_closing = tag.ends_with('/');
+
+        match tag_name.as_str() {
+            "script" | "style" | "head" | "nav" | "footer" | "noscript" | "svg" => {
+                if closing {
+                    skip_depth = skip_depth.saturating_sub(1);
+                } else if !self_closing {
+                    skip_depth += 1;
+                }
+            }
+            _ if skip_depth > 0 => {}
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                if closing {
+                    out.push('\n');
+                } else {
+                    let level = tag_name[1..].parse::<usize>().unwrap_or(1);
+                    out.push_str("\n\n");
+                    out.push_str(&"#".repeat(level));
+                    out.push(' ');
+                }
+            }
+            "p" | "div" | "section" | "article" | "tr" => {
+                if !closing {
+                    out.push_str("\n\n");
+                }
+            }
+            "br" => out.push('\n'),
+            "li" => {
+                if !closing {
+                    out.push_str("\n- ");
+                }
+            }
+            "a" => {
+                if closing {
+                    if let Some(href) = link_href.take() {
+                        out.push_str(&format!("]({href})"));
+                    }
+                } else if let Some(href) = attribute_value(tag, "href") {
+                    link_href = Some(href);
+                    out.push('[');
+                }
+            }
+            "strong" | "b" => out.push_str("**"),
+            "em" | "i" => out.push('*'),
+            "code" => out.push('`'),
+            "pre" => out.push_str("\n```\n"),
+            _ => {}
+        }
+    }
+    if skip_depth == 0 {
+        push_text(&mut out, rest);
+    }
+
+    // Collapse runs of blank lines left by dropped markup.
+    let mut collapsed = String::with_capacity(out.len());
+    let mut blank_run = 0usize;
+    for line in out.lines() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        collapsed.push_str(line);
+        collapsed.push('\n');
+    }
+    collapsed.trim().to_string()
+}
+
+fn attribute_value(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    // Require a leading separator so `href=` does not match `data-href=`.
+    let idx = lower.find(&format!(" {name}="))? + 1;
+    let value = &tag[idx + name.len() + 1..];
+    let (quote, value) = match value.chars().next()? {
+        quote @ ('"' | '\'') => (Some(quote), &value[1..]),
+        _ => (None, value),
+    };
+    let end = match quote {
+        Some(quote) => value.find(quote)?,
+        None => value.find([' ', '\t', '\n', '>']).unwrap_or(value.len()),
+    };
+    Some(value[..end].to_string())
+}
+
+fn push_text(out: &mut String, text: &str) {
+    let decoded = text
+        .replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ");
+    let mut last_was_space = out.ends_with([' ', '\n']);
+    for ch in decoded.chars() {
+        if ch == '\n' || ch == '\r' || ch == '\t' || ch == ' ' {
+            if !last_was_space {
+                out.push(' ');
+                last_was_space = true;
+            }
+        } else {
+            out.push(ch);
+            last_was_space = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn converts_basic_html_to_markdown() {
+        let html = "<html><head><title>x</title><style>p{}</style></head>\
+<body><h1>Title</h1><p>Hello <strong>world</strong>.</p>\
+<ul><li>one</li><li><a href=\"https://example.com\">link</a></li></ul>\
+<script>ignored()</script></body></html>";
+        let markdown = html_to_markdown(html);
+        assert_eq!(
+            markdown,
+            "# Title\n\nHello **world**.\n- one\n- [link](https://example.com)"
+        );
+    }
+
+    #[test]
+    fn robots_wildcard_disallow_applies() {
+        let robots =
+            "User-agent: googlebot\nDisallow: /private\n\nUser-agent: *\nDisallow: /admin\n";
+        assert!(robots_disallows(robots, "/admin/settings"));
+        assert!(!robots_disallows(robots, "/public"));
+        assert!(!robots_disallows(robots, "/private"));
+    }
+
+    #[test]
+    fn robots_group_applies_when_any_user_agent_is_wildcard() {
+        // `*` is not the last user-agent line of its group but the group's
+        // rules still apply to us.
+        let robots = "User-agent: *\nUser-agent: googlebot\nDisallow: /admin\n";
+        assert!(robots_disallows(robots, "/admin"));
+
+        // A later named-only group must not inherit the wildcard.
+        let robots =
+            "User-agent: *\nDisallow: /admin\nUser-agent: googlebot\nDisallow: /private\n";
+        assert!(robots_disallows(robots, "/admin"));
+        assert!(!robots_disallows(robots, "/private"));
+    }
+}
@@ -0,0 +1,311 @@
+//! Built-in `read_file`, `write_file`, and `list_dir` function tools.
+//!
+//! Native file IO as an alternative to shelling out to `cat`/`sed -n`:
+//! line-range reads with byte caps and lossless UTF-8 handling, writes and
+//! listings routed through the turn environment's filesystem with the
+//! sandbox context enforced, gated by the `file_io_tools` config flag.
+
+use std::collections::BTreeMap;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolPayload;
+use crate::tools::context::boxed_tool_output;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::handlers::resolve_tool_environment;
+use crate::tools::registry::CoreToolRuntime;
+use crate::tools::registry::ToolExecutor;
+use codex_tools::JsonSchema;
+use codex_tools::ResponsesApiTool;
+use codex_tools::ToolName;
+use codex_tools::ToolSpec;
+use codex_utils_path_uri::PathUri;
+use serde::Deserialize;
+
+/// Cap on bytes returned from a single `read_file` call.
+const MAX_READ_BYTES: usize = 256 * 1024;
+/// Cap on entries returned from a single `list_dir` call.
+const MAX_DIR_ENTRIES: usize = 500;
+
+async fn resolve_path(
+    invocation: &ToolInvocation,
+    path: &str,
+) -> Result<
+    (
+        std::sync::Arc<dyn codex_file_system::ExecutorFileSystem>,
+        PathUri,
+        codex_file_system::FileSystemSandboxContext,
+    ),
+    FunctionCallError,
+> {
+    let Some(turn_environment) =
+        resolve_tool_environment(&invocation.step_context.environments, None)?
+    else {
+        return Err(FunctionCallError::RespondToModel(
+            "file IO tools are unavailable in this session".to_string(),
+        ));
+    };
+    let fs = turn_environment.environment.get_filesystem();
+    let sandbox = invocation.turn.file_system_sandbox_context(
+        /*additional_permissions*/ None,
+        turn_environment.cwd_uri(),
+    );
+    let path_uri = if std::path::Path::new(path).is_absolute() {
+        PathUri::from_host_native_path(path)
+            .map_err(|err| FunctionCallError::RespondToModel(format!("invalid path: {err}")))?
+    } else {
+        turn_environment
+            .cwd_uri()
+            .join(path)
+            .map_err(|err| FunctionCallError::RespondToModel(format!("invalid path: {err}")))?
+    };
+    Ok((fs, path_uri, sandbox))
+}
+
+fn map_fs_error(operation: &str, err: std::io::Error) -> FunctionCallError {
+    FunctionCallError::RespondToModel(format!("{operation} failed: {err}"))
+}
+
+// ---------------------------------------------------------------------------
+// read_file
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct ReadFileArgs {
+    path: String,
+    /// 1-based first line to include.
+    #[serde(default)]
+    offset: Option<usize>,
+    /// Maximum number of lines to return.
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+pub struct ReadFileHandler;
+
+impl ToolExecutor<ToolInvocation> for ReadFileHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain("read_file")
+    }
+
+    fn spec(&self) -> ToolSpec {
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "path".to_string(),
+            JsonSchema::string(Some("File path, absolute or relative to cwd.".to_string())),
+        );
+        properties.insert(
+            "offset".to_string(),
+            JsonSchema::integer(Some("1-based first line to include.".to_string())),
+        );
+        properties.insert(
+            "limit".to_string(),
+            JsonSchema::integer(Some("Maximum number of lines to return.".to_string())),
+        );
+        ToolSpec::Function(ResponsesApiTool {
+            name: "read_file".to_string(),
+            description: "Read a UTF-8 text file, optionally a line range. Output is capped; \
+use offset/limit to page through large files."
+                .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                properties,
+                Some(vec!["path".to_string()]),
+                Some(false.into()),
+            ),
+            output_schema: None,
+        })
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            let ToolPayload::Function { arguments } = &invocation.payload else {
+                return Err(FunctionCallError::RespondToModel(
+                    "read_file handler received unsupported payload".to_string(),
+                ));
+            };
+            let args: ReadFileArgs = parse_arguments(arguments)?;
+            let (fs, path_uri, sandbox) = resolve_path(&invocation, &args.path).await?;
+            let text = fs
+                .read_file_text(&path_uri, Some(&sandbox))
+                .await
+                .map_err(|err| map_fs_error("read_file", err))?;
+
+            let start = args.offset.unwrap_or(1).max(1) - 1;
+            let mut selected: Vec<&str> = text
+                .lines()
+                .skip(start)
+                .take(args.limit.unwrap_or(usize::MAX))
+                .collect();
+            let mut truncated_lines = false;
+            let mut bytes = 0usize;
+            let mut keep = 0usize;
+            for line in &selected {
+                bytes += line.len() + 1;
+                if bytes > MAX_READ_BYTES {
+                    truncated_lines = true;
+                    break;
+                }
+                keep += 1;
+            }
+            selected.truncate(keep);
+            let mut output = selected.join("\n");
+            if truncated_lines {
+                output.push_str("\n(truncated; use offset/limit to read more)");
+            }
+            Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                output,
+                Some(true),
+            )))
+        })
+    }
+}
+
+impl CoreToolRuntime for ReadFileHandler {}
+
+// ---------------------------------------------------------------------------
+// write_file
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct WriteFileArgs {
+    path: String,
+    contents: String,
+}
+
+pub struct WriteFileHandler;
+
+impl ToolExecutor<ToolInvocation> for WriteFileHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain("write_file")
+    }
+
+    fn spec(&self) -> ToolSpec {
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "path".to_string(),
+            JsonSchema::string(Some("File path, absolute or relative to cwd.".to_string())),
+        );
+        properties.insert(
+            "contents".to_string(),
+            JsonSchema::string(Some("Full UTF-8 contents to write.".to_string())),
+        );
+        ToolSpec::Function(ResponsesApiTool {
+            name: "write_file".to_string(),
+            description: "Write a UTF-8 text file (replacing any existing contents), subject to \
+the sandbox policy. Prefer apply_patch for edits that should be reviewable."
+                .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                properties,
+                Some(vec!["path".to_string(), "contents".to_string()]),
+                Some(false.into()),
+            ),
+            output_schema: None,
+        })
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            let ToolPayload::Function { arguments } = &invocation.payload else {
+                return Err(FunctionCallError::RespondToModel(
+                    "write_file handler received unsupported payload".to_string(),
+                ));
+            };
+            let args: WriteFileArgs = parse_arguments(arguments)?;
+            let (fs, path_uri, sandbox) = resolve_path(&invocation, &args.path).await?;
+            let byte_count = args.contents.len();
+            fs.write_file(&path_uri, args.contents.into_bytes(), Some(&sandbox))
+                .await
+                .map_err(|err| map_fs_error("write_file", err))?;
+            Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                format!("wrote {byte_count} bytes"),
+                Some(true),
+            )))
+        })
+    }
+}
+
+impl CoreToolRuntime for WriteFileHandler {}
+
+// ---------------------------------------------------------------------------
+// list_dir
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct ListDirArgs {
+    path: String,
+}
+
+pub struct ListDirHandler;
+
+impl ToolExecutor<ToolInvocation> for ListDirHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain("list_dir")
+    }
+
+    fn spec(&self) -> ToolSpec {
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "path".to_string(),
+            JsonSchema::string(Some(
+                "Directory path, absolute or relative to cwd.".to_string(),
+            )),
+        );
+        ToolSpec::Function(ResponsesApiTool {
+            name: "list_dir".to_string(),
+            description: "List a directory's entries (directories suffixed with `/`), capped at \
+500 entries."
+                .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                properties,
+                Some(vec!["path".to_string()]),
+                Some(false.into()),
+            ),
+            output_schema: None,
+        })
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            let ToolPayload::Function { arguments } = &invocation.payload else {
+                return Err(FunctionCallError::RespondToModel(
+                    "list_dir handler received unsupported payload".to_string(),
+                ));
+            };
+            let args: ListDirArgs = parse_arguments(arguments)?;
+            let (fs, path_uri, sandbox) = resolve_path(&invocation, &args.path).await?;
+            let mut entries = fs
+                .read_directory(&path_uri, Some(&sandbox))
+                .await
+                .map_err(|err| map_fs_error("list_dir", err))?;
+            entries.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+            let truncated = entries.len() > MAX_DIR_ENTRIES;
+            entries.truncate(MAX_DIR_ENTRIES);
+            let mut lines: Vec<String> = entries
+                .into_iter()
+                .map(|entry| {
+                    if entry.is_directory {
+                        format!("{}/", entry.file_name)
+                    } else {
+                        entry.file_name
+                    }
+                })
+                .collect();
+            if truncated {
+                lines.push(format!("(truncated to {MAX_DIR_ENTRIES} entries)"));
+            }
+            Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                lines.join("\n"),
+                Some(true),
+            )))
+        })
+    }
+}
+
+impl CoreToolRuntime for ListDirHandler {}
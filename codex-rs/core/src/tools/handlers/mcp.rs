@@ -153,6 +153,15 @@ impl McpHandler {
         )
         .await;
 
+        let mut result = result;
+        persist_image_blocks(
+            &mut result.result,
+            &turn.config.codex_home,
+            &session.thread_id.to_string(),
+            &call_id,
+        )
+        .await;
+
         Ok(boxed_tool_output(McpToolOutput {
             result: result.result,
             tool_input: result.tool_input,
@@ -230,6 +239,74 @@ impl CoreToolRuntime for McpHandler {
     }
 }
 
+/// Persists image content blocks from a tool result under the session's
+/// generated-images directory and appends a text block naming each saved
+/// path, so screenshots survive the tool-result path on disk and UIs render
+/// a placeholder with the location. Forwarding to vision-capable models
+/// happens downstream via the content-item conversion, which already turns
+/// image blocks into image inputs.
+async fn persist_image_blocks(
+    result: &mut CallToolResult,
+    codex_home: &codex_utils_absolute_path::AbsolutePathBuf,
+    session_id: &str,
+    call_id: &str,
+) {
+    use base64::Engine as _;
+
+    let mut saved_paths: Vec<String> = Vec::new();
+    for (index, content) in result.content.iter().enumerate() {
+        let Some(object) = content.as_object() else {
+            continue;
+        };
+        if object.get("type").and_then(Value::as_str) != Some("image") {
+            continue;
+        }
+        let Some(data) = object.get("data").and_then(Value::as_str) else {
+            continue;
+        };
+        let mime_type = object
+            .get("mimeType")
+            .or_else(|| object.get("mime_type"))
+            .and_then(Value::as_str)
+            .unwrap_or("image/png");
+        let extension = match mime_type {
+            "image/jpeg" | "image/jpg" => "jpg",
+            "image/gif" => "gif",
+            "image/webp" => "webp",
+            _ => "png",
+        };
+        let bytes = match base64::engine::general_purpose::STANDARD.decode(data) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                tracing::warn!("skipping unpersistable image block from tool result: {err}");
+                continue;
+            }
+        };
+        let artifact_path = crate::stream_events_utils::image_generation_artifact_path(
+            codex_home, session_id, call_id,
+        );
+        let path = artifact_path
+            .as_path()
+            .with_extension(format!("{index}.{extension}"));
+        if let Some(parent) = path.parent()
+            && let Err(err) = tokio::fs::create_dir_all(parent).await
+        {
+            tracing::warn!("failed to create image artifact dir: {err}");
+            continue;
+        }
+        match tokio::fs::write(&path, &bytes).await {
+            Ok(()) => saved_paths.push(path.display().to_string()),
+            Err(err) => tracing::warn!("failed to persist tool image block: {err}"),
+        }
+    }
+
+    for path in saved_paths {
+        result.content.push(
+            serde_json::json!({ "type": "text", "text": format!("[image saved to {path}]") }),
+        );
+    }
+}
+
 fn create_tool_spec(tool_info: &ToolInfo) -> Result<ToolSpec, serde_json::Error> {
     let tool_name = tool_info.canonical_tool_name();
     let tool = mcp_tool_to_responses_api_tool(&tool_name, &tool_info.tool)?;
@@ -5,6 +5,8 @@ pub(crate) mod apply_patch_spec;
 mod current_time;
 mod dynamic;
 pub(crate) mod extension_tools;
+pub(crate) mod fetch_url;
+mod file_io;
 mod get_context_remaining;
 pub(crate) mod get_context_remaining_spec;
 mod list_available_plugins_to_install;
@@ -25,6 +27,7 @@ mod request_plugin_install;
 pub(crate) mod request_plugin_install_spec;
 mod request_user_input;
 pub(crate) mod request_user_input_spec;
+mod search_workspace;
 mod shell;
 pub(crate) mod shell_spec;
 mod sleep;
@@ -36,6 +39,7 @@ pub(crate) mod unified_exec;
 mod view_image;
 pub(crate) mod view_image_spec;
 mod wait_for_environment;
+mod web_search_provider;
 
 use codex_sandboxing::policy_transforms::intersect_permission_profiles;
 use codex_sandboxing::policy_transforms::merge_permission_profiles;
@@ -59,6 +63,10 @@ use codex_protocol::models::AdditionalPermissionProfile;
 use codex_protocol::protocol::AskForApproval;
 pub use current_time::CurrentTimeHandler;
 pub use dynamic::DynamicToolHandler;
+pub use fetch_url::FetchUrlHandler;
+pub use file_io::ListDirHandler;
+pub use file_io::ReadFileHandler;
+pub use file_io::WriteFileHandler;
 pub use get_context_remaining::GetContextRemainingHandler;
 pub use list_available_plugins_to_install::ListAvailablePluginsToInstallHandler;
 pub use mcp::McpHandler;
@@ -70,6 +78,7 @@ pub use plan::PlanHandler;
 pub use request_permissions::RequestPermissionsHandler;
 pub use request_plugin_install::RequestPluginInstallHandler;
 pub use request_user_input::RequestUserInputHandler;
+pub use search_workspace::SearchWorkspaceHandler;
 pub use shell::ShellCommandHandler;
 pub(crate) use shell::ShellCommandHandlerOptions;
 pub use sleep::SleepHandler;
@@ -77,9 +86,11 @@ pub use test_sync::TestSyncHandler;
 pub(crate) use tool_search::ToolSearchHandlerCache;
 pub use unified_exec::ExecCommandHandler;
 pub(crate) use unified_exec::ExecCommandHandlerOptions;
+pub use unified_exec::ListExecSessionsHandler;
 pub use unified_exec::WriteStdinHandler;
 pub use view_image::ViewImageHandler;
 pub(crate) use wait_for_environment::WaitForEnvironmentHandler;
+pub use web_search_provider::WebSearchProviderHandler;
 
 pub(crate) fn parse_arguments<T>(arguments: &str) -> Result<T, FunctionCallError>
 where
@@ -0,0 +1,275 @@
+//! Built-in `search_workspace` tool.
+//!
+//! Gives the model a deterministic, rate-limit-free alternative to shelling
+//! out to `rg`/`find`: fuzzy file-name search backed by `codex-file-search`
+//! (which honors ignore rules) and a capped substring content search over the
+//! same ignore-filtered walk.
+
+use std::collections::BTreeMap;
+use std::num::NonZero;
+use std::path::PathBuf;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolOutput;
+use crate::tools::context::ToolPayload;
+use crate::tools::context::boxed_tool_output;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::CoreToolRuntime;
+use crate::tools::registry::ToolExecutor;
+use codex_protocol::models::ResponseInputItem;
+use codex_tools::JsonSchema;
+use codex_tools::ResponsesApiTool;
+use codex_tools::ToolName;
+use codex_tools::ToolSpec;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use serde_json::json;
+
+const TOOL_NAME: &str = "search_workspace";
+const MAX_RESULTS: usize = 50;
+/// Files larger than this are skipped during content search.
+const MAX_CONTENT_FILE_BYTES: u64 = 1024 * 1024;
+/// Upper bound on files visited by a content search.
+const MAX_CONTENT_FILES_SCANNED: usize = 20_000;
+
+#[derive(Debug, Deserialize)]
+struct SearchWorkspaceArgs {
+    /// Query text: a fuzzy file-name pattern or a content substring.
+    query: String,
+    /// `files` (default) searches file names; `content` searches file text.
+    #[serde(default)]
+    mode: SearchMode,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum SearchMode {
+    #[default]
+    Files,
+    Content,
+}
+
+#[derive(Debug, Serialize)]
+struct SearchWorkspaceMatch {
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preview: Option<String>,
+}
+
+struct SearchWorkspaceOutput {
+    matches: Vec<SearchWorkspaceMatch>,
+    truncated: bool,
+}
+
+impl SearchWorkspaceOutput {
+    fn render(&self) -> String {
+        if self.matches.is_empty() {
+            return "no matches".to_string();
+        }
+        let mut lines: Vec<String> = self
+            .matches
+            .iter()
+            .map(|entry| match (&entry.line, &entry.preview) {
+                (Some(line), Some(preview)) => format!("{}:{line}: {preview}", entry.path),
+                _ => entry.path.clone(),
+            })
+            .collect();
+        if self.truncated {
+            lines.push(format!("(truncated to {MAX_RESULTS} matches)"));
+        }
+        lines.join("\n")
+    }
+}
+
+impl ToolOutput for SearchWorkspaceOutput {
+    fn log_preview(&self) -> String {
+        format!("{} matches", self.matches.len())
+    }
+
+    fn success_for_logging(&self) -> bool {
+        true
+    }
+
+    fn to_response_item(&self, call_id: &str, payload: &ToolPayload) -> ResponseInputItem {
+        FunctionToolOutput::from_text(self.render(), Some(true)).to_response_item(call_id, payload)
+    }
+
+    fn code_mode_result(&self, _payload: &ToolPayload) -> JsonValue {
+        json!({
+            "matches": self.matches,
+            "truncated": self.truncated,
+        })
+    }
+}
+
+pub struct SearchWorkspaceHandler;
+
+impl ToolExecutor<ToolInvocation> for SearchWorkspaceHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain(TOOL_NAME)
+    }
+
+    fn spec(&self) -> ToolSpec {
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "query".to_string(),
+            JsonSchema::string(Some(
+                "Fuzzy file-name pattern (mode=files) or content substring (mode=content)."
+                    .to_string(),
+            )),
+        );
+        properties.insert(
+            "mode".to_string(),
+            JsonSchema::string_enum(
+                vec!["files".into(), "content".into()],
+                Some(
+                    "`files` (default) to search file names, `content` to search file text."
+                        .to_string(),
+                ),
+            ),
+        );
+        ToolSpec::Function(ResponsesApiTool {
+            name: TOOL_NAME.to_string(),
+            description:
+                "Search the workspace without shelling out: fuzzy file-name search or capped \
+substring content search, honoring ignore rules. Results are deterministic."
+                    .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                properties,
+                Some(vec!["query".to_string()]),
+                Some(false.into()),
+            ),
+            output_schema: None,
+        })
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            let ToolPayload::Function { arguments } = &invocation.payload else {
+                return Err(FunctionCallError::RespondToModel(format!(
+                    "{TOOL_NAME} handler received unsupported payload"
+                )));
+            };
+            let args: SearchWorkspaceArgs = parse_arguments(arguments)?;
+            if args.query.trim().is_empty() {
+                return Err(FunctionCallError::RespondToModel(
+                    "query must not be empty".to_string(),
+                ));
+            }
+            let Some(root) = invocation
+                .step_context
+                .environments
+                .primary()
+                .map(|environment| environment.cwd().as_path().to_path_buf())
+            else {
+                return Err(FunctionCallError::RespondToModel(
+                    "no workspace root available for this turn".to_string(),
+                ));
+            };
+
+            let output = tokio::task::spawn_blocking(move || match args.mode {
+                SearchMode::Files => search_file_names(&args.query, root),
+                SearchMode::Content => Ok(search_content(&args.query, &root)),
+            })
+            .await
+            .map_err(|err| FunctionCallError::Fatal(format!("search task panicked: {err}")))?
+            .map_err(|err| {
+                FunctionCallError::RespondToModel(format!("workspace search failed: {err}"))
+            })?;
+
+            Ok(boxed_tool_output(output))
+        })
+    }
+}
+
+impl CoreToolRuntime for SearchWorkspaceHandler {}
+
+fn search_file_names(query: &str, root: PathBuf) -> anyhow::Result<SearchWorkspaceOutput> {
+    let limit = NonZero::new(MAX_RESULTS).unwrap_or(NonZero::<usize>::MIN);
+    let threads = NonZero::new(2).unwrap_or(NonZero::<usize>::MIN);
+    let results = codex_file_search::run(
+        query,
+        vec![root],
+        codex_file_search::FileSearchOptions {
+            limit,
+            threads,
+            ..Default::default()
+        },
+        /*cancel_flag*/ None,
+    )?;
+    let truncated = results.total_match_count > results.matches.len();
+    Ok(SearchWorkspaceOutput {
+        matches: results
+            .matches
+            .into_iter()
+            .map(|file_match| SearchWorkspaceMatch {
+                path: file_match.path.display().to_string(),
+                line: None,
+                preview: None,
+            })
+            .collect(),
+        truncated,
+    })
+}
+
+fn search_content(query: &str, root: &std::path::Path) -> SearchWorkspaceOutput {
+    let mut matches = Vec::new();
+    let mut truncated = false;
+    let mut scanned = 0usize;
+    let walker = ignore::WalkBuilder::new(root).build();
+    for entry in walker {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_some_and(|kind| kind.is_file()) {
+            continue;
+        }
+        scanned += 1;
+        if scanned > MAX_CONTENT_FILES_SCANNED {
+            truncated = true;
+            break;
+        }
+        if entry
+            .metadata()
+            .map(|metadata| metadata.len() > MAX_CONTENT_FILE_BYTES)
+            .unwrap_or(true)
+        {
+            continue;
+        }
+        let Ok(contents) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        for (index, line) in contents.lines().enumerate() {
+            if line.contains(query) {
+                let display_path = entry
+                    .path()
+                    .strip_prefix(root)
+                    .unwrap_or(entry.path())
+                    .display()
+                    .to_string();
+                matches.push(SearchWorkspaceMatch {
+                    path: display_path,
+                    line: Some(index + 1),
+                    preview: Some(line.trim().chars().take(200).collect()),
+                });
+                if matches.len() >= MAX_RESULTS {
+                    truncated = true;
+                    break;
+                }
+            }
+        }
+        if matches.len() >= MAX_RESULTS {
+            break;
+        }
+    }
+    // Walk order is platform-dependent; sort for deterministic output.
+    matches.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+    SearchWorkspaceOutput { matches, truncated }
+}
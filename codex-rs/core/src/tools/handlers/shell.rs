@@ -24,8 +24,11 @@ use crate::tools::runtimes::shell::ShellRequest;
 use crate::tools::runtimes::shell::ShellRuntime;
 use crate::tools::runtimes::shell::ShellRuntimeBackend;
 use crate::tools::sandboxing::ToolCtx;
+use codex_protocol::exec_output::ExecToolCallOutput;
+use codex_protocol::exec_output::StreamOutput;
 use codex_protocol::models::AdditionalPermissionProfile;
 use codex_protocol::protocol::ExecCommandSource;
+use codex_shell_command::is_safe_command::is_known_safe_command;
 use codex_tools::ToolName;
 use codex_utils_path_uri::PathUri;
 
@@ -165,6 +168,35 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
     );
     emitter.begin(event_ctx).await;
 
+    // In dry-run mode, echo non-read-only commands instead of executing them.
+    if turn.config.dry_run && !is_known_safe_command(&exec_params.command) {
+        let note = format!(
+            "dry run: would run `{}` (not executed)",
+            exec_params.command.join(" ")
+        );
+        let output = ExecToolCallOutput {
+            stdout: StreamOutput::new(note.clone()),
+            aggregated_output: StreamOutput::new(note),
+            ..Default::default()
+        };
+        let event_ctx = ToolEventCtx::new(
+            session.as_ref(),
+            turn.as_ref(),
+            &call_id,
+            /*turn_diff_tracker*/ None,
+        );
+        let content = emitter
+            .finish(event_ctx, Ok(output), /*applied_patch_delta*/ None)
+            .await?;
+        return Ok(FunctionToolOutput {
+            body: vec![
+                codex_protocol::models::FunctionCallOutputContentItem::InputText { text: content },
+            ],
+            success: Some(true),
+            post_tool_use_response: None,
+        });
+    }
+
     let exec_approval_requirement = session
         .services
         .exec_policy
@@ -181,6 +213,16 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
             prefix_rule,
         })
         .await;
+    let cwd_uri: codex_utils_path_uri::PathUri = exec_params.cwd.clone().into();
+    let exec_approval_requirement = crate::safety::enforce_write_precheck_on_exec_approval(
+        exec_approval_requirement,
+        &exec_params.command,
+        turn.approval_policy.value(),
+        &turn.permission_profile(),
+        &turn.file_system_sandbox_policy(),
+        &cwd_uri,
+        turn.windows_sandbox_level,
+    );
 
     let req = ShellRequest {
         command: exec_params.command.clone(),
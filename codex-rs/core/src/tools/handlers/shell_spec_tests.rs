@@ -55,12 +55,25 @@ fn exec_command_tool_matches_expected_spec() {
                         .to_string(),
                 )),
         ),
+        (
+            "separate_stderr".to_string(),
+            JsonSchema::boolean(Some(
+                    "True captures stderr separately and returns it in its own section; requires tty: false. Defaults to false."
+                        .to_string(),
+                )),
+        ),
         (
             "yield_time_ms".to_string(),
             JsonSchema::number(Some(
                     "Wait before yielding output. Defaults to 10000 ms; effective range is 250-30000 ms.".to_string(),
                 )),
         ),
+        (
+            "timeout_ms".to_string(),
+            JsonSchema::number(Some(
+                    "Overall command timeout. On expiry the process is terminated gracefully, then killed, and partial output is returned. Unset means no timeout.".to_string(),
+                )),
+        ),
         (
             "max_output_tokens".to_string(),
             JsonSchema::number(Some(
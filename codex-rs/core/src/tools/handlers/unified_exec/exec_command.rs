@@ -213,7 +213,9 @@ impl ExecCommandHandler {
 
         let ExecCommandArgs {
             tty,
+            separate_stderr,
             yield_time_ms,
+            timeout_ms,
             max_output_tokens,
             sandbox_permissions,
             additional_permissions,
@@ -307,9 +309,19 @@ impl ExecCommandHandler {
                 exit_code: None,
                 original_token_count: None,
                 hook_command: None,
+                stderr: None,
+                timed_out_after_ms: None,
             }));
         }
 
+        if separate_stderr && tty {
+            manager.release_process_id(process_id).await;
+            return Err(FunctionCallError::RespondToModel(
+                "separate_stderr requires tty: false; PTY output is inherently interleaved"
+                    .to_string(),
+            ));
+        }
+
         emit_unified_exec_tty_metric(&turn.session_telemetry, tty);
         match manager
             .exec_command(
@@ -319,6 +331,7 @@ impl ExecCommandHandler {
                     hook_command: hook_command.clone(),
                     process_id,
                     yield_time_ms,
+                    timeout_ms,
                     max_output_tokens,
                     cwd: cwd_uri,
                     sandbox_cwd: native_environment_cwd.into(),
@@ -326,6 +339,7 @@ impl ExecCommandHandler {
                     shell_mode,
                     network: context.turn.network.clone(),
                     tty,
+                    separate_stderr,
                     sandbox_permissions: effective_additional_permissions.sandbox_permissions,
                     additional_permissions: normalized_additional_permissions,
                     additional_permissions_preapproved: effective_additional_permissions
@@ -354,6 +368,8 @@ impl ExecCommandHandler {
                     exit_code: Some(output.exit_code),
                     original_token_count: Some(original_token_count),
                     hook_command: Some(hook_command),
+                    stderr: None,
+                    timed_out_after_ms: None,
                 }))
             }
             Err(err) => Err(FunctionCallError::RespondToModel(format!(
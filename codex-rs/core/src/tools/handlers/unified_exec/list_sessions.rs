@@ -0,0 +1,82 @@
+//! `list_exec_sessions` function tool.
+//!
+//! Lets the model enumerate the live background terminal sessions it has
+//! started (e.g. a dev server from an earlier turn) so it can poll their
+//! output or send input through `write_stdin` without guessing process ids.
+
+use std::collections::BTreeMap;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolPayload;
+use crate::tools::context::boxed_tool_output;
+use crate::tools::registry::CoreToolRuntime;
+use crate::tools::registry::ToolExecutor;
+use codex_tools::JsonSchema;
+use codex_tools::ResponsesApiTool;
+use codex_tools::ToolName;
+use codex_tools::ToolSpec;
+
+pub struct ListExecSessionsHandler;
+
+impl ToolExecutor<ToolInvocation> for ListExecSessionsHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain("list_exec_sessions")
+    }
+
+    fn spec(&self) -> ToolSpec {
+        ToolSpec::Function(ResponsesApiTool {
+            name: "list_exec_sessions".to_string(),
+            description: "List live background terminal sessions (process id, command, cwd). \
+Use write_stdin with a process id to poll output or send input."
+                .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                BTreeMap::new(),
+                /*required*/ None,
+                /*additional_properties*/ Some(false.into()),
+            ),
+            output_schema: None,
+        })
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            if !matches!(invocation.payload, ToolPayload::Function { .. }) {
+                return Err(FunctionCallError::RespondToModel(
+                    "list_exec_sessions handler received unsupported payload".to_string(),
+                ));
+            }
+            let processes = invocation
+                .session
+                .services
+                .unified_exec_manager
+                .list_processes()
+                .await;
+            let output = if processes.is_empty() {
+                "no background sessions".to_string()
+            } else {
+                processes
+                    .into_iter()
+                    .map(|process| {
+                        format!(
+                            "#{}: {} (cwd: {})",
+                            process.process_id,
+                            process.command,
+                            process.cwd.inferred_native_path_string(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                output,
+                Some(true),
+            )))
+        })
+    }
+}
+
+impl CoreToolRuntime for ListExecSessionsHandler {}
@@ -0,0 +1,298 @@
+//! Local `web_search` function tool with pluggable provider backends.
+//!
+//! Queries a configured SearxNG instance or the Brave/Bing APIs and returns
+//! structured results (title, url, snippet) as JSON, giving the agent a
+//! sanctioned way to search the web. Registered only when
+//! `[web_search_provider]` is configured, so profiles opt in explicitly; the
+//! sandbox network-policy gate matches `fetch_url`.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use codex_config::types::WebSearchBackend;
+use codex_config::types::WebSearchProviderConfig;
+use codex_http_client::build_reqwest_client_with_custom_ca;
+use codex_tools::JsonSchema;
+use codex_tools::ResponsesApiTool;
+use codex_tools::ToolName;
+use codex_tools::ToolSpec;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolPayload;
+use crate::tools::context::boxed_tool_output;
+use crate::tools::handlers::fetch_url::ensure_network_allowed;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::CoreToolRuntime;
+use crate::tools::registry::ToolExecutor;
+
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(20);
+const DEFAULT_MAX_RESULTS: usize = 5;
+
+#[derive(Deserialize)]
+struct WebSearchArgs {
+    query: String,
+}
+
+/// One structured search hit returned to the model.
+#[derive(Serialize)]
+struct SearchResult {
+    title: String,
+    url: String,
+    snippet: String,
+}
+
+pub struct WebSearchProviderHandler {
+    provider: WebSearchProviderConfig,
+}
+
+impl WebSearchProviderHandler {
+    pub(crate) fn new(provider: WebSearchProviderConfig) -> Self {
+        Self { provider }
+    }
+}
+
+impl ToolExecutor<ToolInvocation> for WebSearchProviderHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain("web_search")
+    }
+
+    fn spec(&self) -> ToolSpec {
+        let mut properties = BTreeMap::new();
+        properties.insert(
+            "query".to_string(),
+            JsonSchema::string(Some("Search query.".to_string())),
+        );
+        ToolSpec::Function(ResponsesApiTool {
+            name: "web_search".to_string(),
+            description: "Search the web via the configured provider; returns a JSON array \
+of { title, url, snippet } results."
+                .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                properties,
+                Some(vec!["query".to_string()]),
+                Some(false.into()),
+            ),
+            output_schema: None,
+        })
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            let ToolPayload::Function { arguments } = &invocation.payload else {
+                return Err(FunctionCallError::RespondToModel(
+                    "web_search handler received unsupported payload".to_string(),
+                ));
+            };
+            let args: WebSearchArgs = parse_arguments(arguments)?;
+            let query = args.query.trim();
+            if query.is_empty() {
+                return Err(FunctionCallError::RespondToModel(
+                    "query cannot be empty".to_string(),
+                ));
+            }
+
+            ensure_network_allowed(&invocation)?;
+
+            let client = build_reqwest_client_with_custom_ca(
+                reqwest::Client::builder().timeout(SEARCH_TIMEOUT),
+            )
+            .map_err(|err| {
+                FunctionCallError::RespondToModel(format!("failed to build HTTP client: {err}"))
+            })?;
+
+            let max_results = self.provider.max_results.unwrap_or(DEFAULT_MAX_RESULTS);
+            let mut results = match self.provider.backend {
+                WebSearchBackend::Searxng => search_searxng(&client, &self.provider, query).await,
+                WebSearchBackend::Brave => search_brave(&client, &self.provider, query).await,
+                WebSearchBackend::Bing => search_bing(&client, &self.provider, query).await,
+            }?;
+            results.truncate(max_results);
+
+            let body = serde_json::to_string_pretty(&results).map_err(|err| {
+                FunctionCallError::RespondToModel(format!("failed to serialize results: {err}"))
+            })?;
+            Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                body,
+                Some(true),
+            )))
+        })
+    }
+}
+
+impl CoreToolRuntime for WebSearchProviderHandler {}
+
+fn provider_url<'a>(
+    provider: &'a WebSearchProviderConfig,
+    backend: &str,
+) -> Result<&'a str, FunctionCallError> {
+    provider
+        .url
+        .as_deref()
+        .map(|url| url.trim_end_matches('/'))
+        .ok_or_else(|| {
+            FunctionCallError::RespondToModel(format!(
+                "web_search_provider.url is required for the {backend} backend"
+            ))
+        })
+}
+
+fn provider_api_key<'a>(
+    provider: &'a WebSearchProviderConfig,
+    backend: &str,
+) -> Result<&'a str, FunctionCallError> {
+    provider.api_key.as_deref().ok_or_else(|| {
+        FunctionCallError::RespondToModel(format!(
+            "web_search_provider.api_key is required for the {backend} backend"
+        ))
+    })
+}
+
+async fn fetch_json(
+    request: reqwest::RequestBuilder,
+) -> Result<serde_json::Value, FunctionCallError> {
+    let response = request.send().await.map_err(|err| {
+        FunctionCallError::RespondToModel(format!("web search request failed: {err}"))
+    })?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(FunctionCallError::RespondToModel(format!(
+            "web search request failed: HTTP {status}"
+        )));
+    }
+    response.json().await.map_err(|err| {
+        FunctionCallError::RespondToModel(format!("web search response was not JSON: {err}"))
+    })
+}
+
+async fn search_searxng(
+    client: &reqwest::Client,
+    provider: &WebSearchProviderConfig,
+    query: &str,
+) -> Result<Vec<SearchResult>, FunctionCallError> {
+    let base = provider_url(provider, "searxng")?;
+    let json = fetch_json(
+        client
+            .get(format!("{base}/search"))
+            .query(&[("q", query), ("format", "json")]),
+    )
+    .await?;
+    Ok(results_from_array(
+        json.get("results").and_then(serde_json::Value::as_array),
+        "title",
+        "url",
+        "content",
+    ))
+}
+
+async fn search_brave(
+    client: &reqwest::Client,
+    provider: &WebSearchProviderConfig,
+    query: &str,
+) -> Result<Vec<SearchResult>, FunctionCallError> {
+    let api_key = provider_api_key(provider, "brave")?;
+    let json = fetch_json(
+        client
+            .get("https://api.search.brave.com/res/v1/web/search")
+            .query(&[("q", query)])
+            .header("X-Subscription-Token", api_key)
+            .header(reqwest::header::ACCEPT, "application/json"),
+    )
+    .await?;
+    Ok(results_from_array(
+        json.get("web")
+            .and_then(|web| web.get("results"))
+            .and_then(serde_json::Value::as_array),
+        "title",
+        "url",
+        "description",
+    ))
+}
+
+async fn search_bing(
+    client: &reqwest::Client,
+    provider: &WebSearchProviderConfig,
+    query: &str,
+) -> Result<Vec<SearchResult>, FunctionCallError> {
+    let api_key = provider_api_key(provider, "bing")?;
+    let json = fetch_json(
+        client
+            .get("https://api.bing.microsoft.com/v7.0/search")
+            .query(&[("q", query)])
+            .header("Ocp-Apim-Subscription-Key", api_key),
+    )
+    .await?;
+    Ok(results_from_array(
+        json.get("webPages")
+            .and_then(|pages| pages.get("value"))
+            .and_then(serde_json::Value::as_array),
+        "name",
+        "url",
+        "snippet",
+    ))
+}
+
+fn results_from_array(
+    array: Option<&Vec<serde_json::Value>>,
+    title_key: &str,
+    url_key: &str,
+    snippet_key: &str,
+) -> Vec<SearchResult> {
+    let Some(array) = array else {
+        return Vec::new();
+    };
+    array
+        .iter()
+        .filter_map(|entry| {
+            let string = |key: &str| {
+                entry
+                    .get(key)
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let url = string(url_key);
+            if url.is_empty() {
+                return None;
+            }
+            Some(SearchResult {
+                title: string(title_key),
+                url,
+                snippet: string(snippet_key),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn maps_searxng_results() {
+        let json: serde_json::Value = serde_json::json!({
+            "results": [
+                { "title": "One", "url": "https://a.example", "content": "first" },
+                { "title": "Two", "content": "missing url" },
+                { "title": "Three", "url": "https://b.example", "content": "third" },
+            ]
+        });
+        let results = results_from_array(
+            json.get("results").and_then(serde_json::Value::as_array),
+            "title",
+            "url",
+            "content",
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "One");
+        assert_eq!(results[0].url, "https://a.example");
+        assert_eq!(results[0].snippet, "first");
+        assert_eq!(results[1].url, "https://b.example");
+    }
+}
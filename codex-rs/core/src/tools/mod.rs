@@ -1,3 +1,4 @@
+pub(crate) mod call_footprint;
 #[cfg(not(target_os = "android"))]
 pub(crate) mod code_mode;
 #[cfg(target_os = "android")]
@@ -12,6 +13,8 @@ pub(crate) mod lifecycle;
 pub(crate) mod network_approval;
 pub(crate) mod orchestrator;
 pub(crate) mod parallel;
+pub(crate) mod parallel_limiter;
+pub(crate) mod read_rendering;
 pub(crate) mod registry;
 pub(crate) mod router;
 pub(crate) mod runtimes;
@@ -27,8 +30,8 @@ use codex_protocol::exec_output::ExecToolCallOutput;
 use codex_protocol::openai_models::ToolMode;
 use codex_tools::ToolName;
 use codex_utils_output_truncation::TruncationPolicy;
-use codex_utils_output_truncation::formatted_truncate_text;
-use codex_utils_output_truncation::truncate_text;
+use codex_utils_output_truncation::formatted_truncate_exec_text;
+use codex_utils_output_truncation::truncate_exec_text;
 pub use router::ToolRouter;
 
 // Telemetry preview limits: keep log events smaller than model budgets.
@@ -99,7 +102,7 @@ pub fn format_exec_output_for_model(
 
     let total_lines = content.lines().count();
 
-    let formatted_output = truncate_text(&content, truncation_policy);
+    let formatted_output = truncate_exec_text(&content, truncation_policy);
 
     let mut sections = Vec::new();
 
@@ -122,7 +125,7 @@ pub fn format_exec_output_str(
     let content = build_content_with_timeout(exec_output);
 
     // Truncate for model consumption before serialization.
-    formatted_truncate_text(&content, truncation_policy)
+    formatted_truncate_exec_text(&content, truncation_policy)
 }
 
 /// Extracts exec output content and prepends a timeout message if the command timed out.
@@ -6,6 +6,9 @@ simple sequence for any ToolRuntime: approval → select sandbox → attempt →
 retry with an escalated sandbox strategy on denial (no re‑approval thanks to
 caching).
 */
+use crate::approval_audit::ApprovalAuditEntry;
+use crate::approval_audit::ApprovalAuditSource;
+use crate::approval_audit::record_approval;
 use crate::guardian::guardian_rejection_message;
 use crate::guardian::guardian_timeout_message;
 use crate::guardian::new_guardian_review_id;
@@ -155,6 +158,49 @@ impl ToolOrchestrator {
         let otel_tn = flat_tool_name(&tool_ctx.tool_name).into_owned();
         let otel_ci = &tool_ctx.call_id;
         let strict_auto_review = tool_ctx.session.strict_auto_review_enabled_for_turn().await;
+        let audit_approval = |decision: serde_json::Value,
+                              source: ApprovalAuditSource,
+                              reason: Option<String>,
+                              subject: serde_json::Value,
+                              sandbox_policy: serde_json::Value| {
+            let decision_label = decision
+                .as_str()
+                .map(str::to_string)
+                .or_else(|| {
+                    decision
+                        .as_object()
+                        .and_then(|map| map.keys().next().cloned())
+                })
+                .unwrap_or_else(|| "unknown".to_string());
+            let source_label = match source {
+                ApprovalAuditSource::Config => "config",
+                ApprovalAuditSource::User => "user",
+                ApprovalAuditSource::Policy => "policy",
+            };
+            otel.counter(
+                codex_otel::APPROVAL_DECISION_METRIC,
+                1,
+                &[
+                    ("decision", decision_label.as_str()),
+                    ("source", source_label),
+                    ("tool", &otel_tn),
+                ],
+            );
+            record_approval(
+                turn_ctx.config.codex_home.as_path(),
+                ApprovalAuditEntry {
+                    timestamp_ms: crate::turn_timing::now_unix_timestamp_ms(),
+                    tool_name: flat_tool_name(&tool_ctx.tool_name).into_owned(),
+                    call_id: tool_ctx.call_id.clone(),
+                    subject,
+                    decision,
+                    source,
+                    approval_policy: serde_json::to_value(approval_policy).unwrap_or_default(),
+                    sandbox_policy,
+                    reason,
+                },
+            );
+        };
         let use_guardian = routes_approval_to_guardian(turn_ctx) || strict_auto_review;
 
         // 1) Approval
@@ -187,6 +233,13 @@ impl ToolOrchestrator {
                         &otel,
                     )
                     .await?;
+                    audit_approval(
+                        serde_json::to_value(&decision).unwrap_or_default(),
+                        ApprovalAuditSource::User,
+                        None,
+                        serde_json::to_value(tool.approval_keys(req)).unwrap_or_default(),
+                        serde_json::to_value(&file_system_sandbox_policy).unwrap_or_default(),
+                    );
                     Self::reject_if_not_approved(tool_ctx, guardian_review_id.as_deref(), decision)
                         .await?;
                     already_approved = true;
@@ -197,9 +250,23 @@ impl ToolOrchestrator {
                         &ReviewDecision::Approved,
                         ToolDecisionSource::Config,
                     );
+                    audit_approval(
+                        serde_json::to_value(ReviewDecision::Approved).unwrap_or_default(),
+                        ApprovalAuditSource::Config,
+                        None,
+                        serde_json::to_value(tool.approval_keys(req)).unwrap_or_default(),
+                        serde_json::to_value(&file_system_sandbox_policy).unwrap_or_default(),
+                    );
                 }
             }
             ExecApprovalRequirement::Forbidden { reason } => {
+                audit_approval(
+                    serde_json::json!("denied"),
+                    ApprovalAuditSource::Policy,
+                    Some(reason.clone()),
+                    serde_json::to_value(tool.approval_keys(req)).unwrap_or_default(),
+                    serde_json::to_value(&file_system_sandbox_policy).unwrap_or_default(),
+                );
                 return Err(ToolError::Rejected(reason.clone()));
             }
             ExecApprovalRequirement::NeedsApproval { reason, .. } => {
@@ -222,6 +289,13 @@ impl ToolOrchestrator {
                     &otel,
                 )
                 .await?;
+                audit_approval(
+                    serde_json::to_value(&decision).unwrap_or_default(),
+                    ApprovalAuditSource::User,
+                    reason.clone(),
+                    serde_json::to_value(tool.approval_keys(req)).unwrap_or_default(),
+                    serde_json::to_value(&file_system_sandbox_policy).unwrap_or_default(),
+                );
 
                 Self::reject_if_not_approved(tool_ctx, guardian_review_id.as_deref(), decision)
                     .await?;
@@ -27,7 +27,9 @@ use crate::tools::router::ToolCall;
 use crate::tools::router::ToolCallSource;
 use crate::tools::router::ToolRouter;
 use codex_protocol::error::CodexErr;
+use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::ResponseInputItem;
+use std::collections::HashMap;
 
 struct ToolCallTimingGuard {
     started_at: Instant,
@@ -38,6 +40,26 @@ struct ToolCallTimingGuard {
     tool_name: codex_tools::ToolName,
 }
 
+/// Turn-scoped tool-call scheduling state shared by every sampling step of a
+/// turn: the per-path locks backing dependency-aware admission, and the
+/// memoization cache for identical read-only calls. `ToolCallRuntime` itself
+/// is rebuilt per sampling request, so this state lives on the `TurnContext`.
+#[derive(Default)]
+pub(crate) struct TurnToolCallState {
+    /// Per-path locks backing dependency-aware admission of non-parallel calls.
+    path_locks: crate::tools::call_footprint::PathLocks,
+    /// Memoized results of identical read-only tool calls this turn.
+    result_cache: tokio::sync::Mutex<ResultCache>,
+}
+
+#[derive(Default)]
+struct ResultCache {
+    entries: HashMap<String, ResponseInputItem>,
+    /// Bumped when a mutating call clears the entries; an in-flight read
+    /// dispatched before the bump must not be cached afterwards.
+    generation: u64,
+}
+
 #[derive(Clone)]
 pub(crate) struct ToolCallRuntime {
     router: Arc<ToolRouter>,
@@ -78,11 +100,58 @@ impl ToolCallRuntime {
         cancellation_token: CancellationToken,
     ) -> impl std::future::Future<Output = Result<ResponseInputItem, CodexErr>> {
         let error_call = call.clone();
+        let state = Arc::clone(&self.step_context.turn.tool_call_state);
+        let cache_key = read_only_cache_key(&call, &turn_cwd(&self.step_context.turn));
+        let call_id = call.call_id.clone();
         let future =
             self.handle_tool_call_with_source(call, ToolCallSource::Direct, cancellation_token);
         async move {
+            // Models frequently re-read the same file within a turn; serve
+            // identical read-only calls from the turn-scoped cache instead of
+            // re-executing, marking the payload as a cache hit.
+            let generation = match &cache_key {
+                Some(key) => {
+                    let cache = state.result_cache.lock().await;
+                    if let Some(cached) = cache.entries.get(key) {
+                        return Ok(cached_response(cached.clone(), &call_id));
+                    }
+                    cache.generation
+                }
+                // A potentially mutating call invalidates cached reads both
+                // here at dispatch (so batch-mates stop serving pre-write
+                // content) and again below once the mutation has completed.
+                None => {
+                    let mut cache = state.result_cache.lock().await;
+                    cache.entries.clear();
+                    cache.generation += 1;
+                    cache.generation
+                }
+            };
             match future.await {
-                Ok(response) => Ok(response.into_response()),
+                Ok(response) => {
+                    let item = response.into_response();
+                    match cache_key {
+                        Some(key) if is_cacheable_success(&item) => {
+                            let mut cache = state.result_cache.lock().await;
+                            // Skip the insert when a mutating call cleared the
+                            // cache while this read was in flight; the result
+                            // may predate the mutation.
+                            if cache.generation == generation {
+                                cache.entries.insert(key, item.clone());
+                            }
+                        }
+                        Some(_) => {}
+                        // Any potentially mutating call invalidates cached
+                        // reads so a write-then-re-read never serves stale
+                        // content.
+                        None => {
+                            let mut cache = state.result_cache.lock().await;
+                            cache.entries.clear();
+                            cache.generation += 1;
+                        }
+                    }
+                    Ok(item)
+                }
                 Err(FunctionCallError::Fatal(message)) => Err(CodexErr::Fatal(message)),
                 Err(other) => Ok(Self::failure_response(error_call, other)),
             }
@@ -97,7 +166,25 @@ impl ToolCallRuntime {
         source: ToolCallSource,
         cancellation_token: CancellationToken,
     ) -> impl std::future::Future<Output = Result<AnyToolResult, FunctionCallError>> {
-        let supports_parallel = self.router.tool_supports_parallel(&call);
+        let limiter = std::sync::Arc::clone(&self.session.services.parallel_limiter);
+        let parallel_enabled = limiter.enabled();
+        let supports_parallel = self.router.tool_supports_parallel(&call) && parallel_enabled;
+        // Dependency-aware scheduling: calls whose file footprint can be
+        // inferred from their arguments coexist with parallel calls under
+        // per-path locks (reads shared, writes exclusive) instead of the
+        // global write lock, so independent calls run concurrently and a
+        // write orders after in-flight reads of the same path. With parallel
+        // dispatch disabled every call stays fully serialized.
+        let footprint = parallel_enabled
+            .then(|| {
+                crate::tools::call_footprint::call_footprint(
+                    &call,
+                    &turn_cwd(&self.step_context.turn),
+                )
+            })
+            .flatten();
+        let dependency_scheduled = !supports_parallel && footprint.is_some();
+        let tool_call_state = Arc::clone(&self.step_context.turn.tool_call_state);
         let router = Arc::clone(&self.router);
         let session = Arc::clone(&self.session);
         let step_context = Arc::clone(&self.step_context);
@@ -130,11 +217,22 @@ impl ToolCallRuntime {
 
         let mut dispatch_handle: AbortOnDropHandle<Result<AnyToolResult, FunctionCallError>> =
             AbortOnDropHandle::new(tokio::spawn(async move {
-                let _guard = if supports_parallel {
+                let _guard = if supports_parallel || dependency_scheduled {
                     Either::Left(lock.read().await)
                 } else {
                     Either::Right(lock.write().await)
                 };
+                let _path_guards = match &footprint {
+                    Some(footprint) => Some(tool_call_state.path_locks.acquire(footprint).await),
+                    None => None,
+                };
+                // Configured concurrency cap and spacing applies to every
+                // call that may run concurrently with others.
+                let _permit = if supports_parallel || dependency_scheduled {
+                    limiter.admit().await
+                } else {
+                    None
+                };
                 // Admission through the parallel-execution gate marks the end
                 // of dispatch waiting and the start of handler execution.
                 if let Some(execution_started_at) = execution_started_at {
@@ -341,6 +439,69 @@ impl Drop for ToolCallTimingGuard {
     }
 }
 
+/// Effective working directory of the turn, used to normalize footprint
+/// paths: the primary environment's cwd, falling back to the turn cwd.
+fn turn_cwd(turn: &crate::session::turn_context::TurnContext) -> std::path::PathBuf {
+    turn.environments
+        .primary()
+        .map(|environment| environment.cwd().as_path().to_path_buf())
+        .unwrap_or_else(|| {
+            #[allow(deprecated)]
+            turn.cwd.as_path().to_path_buf()
+        })
+}
+
+/// Cache key for a read-only call: tool name plus canonical JSON arguments.
+/// Returns `None` for calls that may observe or mutate state.
+fn read_only_cache_key(call: &ToolCall, turn_cwd: &std::path::Path) -> Option<String> {
+    let read_only = match call.tool_name.name.as_str() {
+        "read_file" | "list_dir" | "search_workspace" | "fetch_url" => {
+            call.tool_name.namespace.is_none()
+        }
+        "shell" | "shell_command" | "exec_command" => {
+            crate::tools::call_footprint::call_footprint(call, turn_cwd)
+                .is_some_and(|footprint| footprint.is_read_only())
+        }
+        _ => false,
+    };
+    if !read_only {
+        return None;
+    }
+    let ToolPayload::Function { arguments } = &call.payload else {
+        return None;
+    };
+    // Round-trip through Value so formatting differences do not defeat the
+    // cache (serde_json maps serialize with sorted keys).
+    let canonical = serde_json::from_str::<serde_json::Value>(arguments)
+        .ok()
+        .and_then(|value| serde_json::to_string(&value).ok())?;
+    Some(format!("{}\n{canonical}", call.tool_name))
+}
+
+/// Only successful function outputs are worth memoizing.
+fn is_cacheable_success(item: &ResponseInputItem) -> bool {
+    matches!(
+        item,
+        ResponseInputItem::FunctionCallOutput { output, .. }
+            if output.success != Some(false)
+    )
+}
+
+/// Rewrites a cached response for a new call id and marks it as a cache hit.
+fn cached_response(mut item: ResponseInputItem, call_id: &str) -> ResponseInputItem {
+    if let ResponseInputItem::FunctionCallOutput {
+        call_id: id,
+        output,
+    } = &mut item
+    {
+        *id = call_id.to_string();
+        if let FunctionCallOutputBody::Text(text) = &mut output.body {
+            text.push_str("\n[cached: identical read-only call earlier this turn]");
+        }
+    }
+    item
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,97 @@
+//! Session-scoped limiter for parallel tool-call dispatch.
+//!
+//! Applies the `[parallel]` config — enabled flag, concurrency cap, and a
+//! minimum spacing between dispatches — and supports live adjustment via
+//! `Op::SetParallelConfig`.
+
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+use tokio::time::Instant;
+
+use crate::config::ParallelDispatchConfig;
+
+pub(crate) struct ParallelLimiter {
+    state: RwLock<LimiterState>,
+    /// Instant of the most recent parallel dispatch, for spacing.
+    last_dispatch: Mutex<Option<Instant>>,
+}
+
+#[derive(Clone)]
+struct LimiterState {
+    enabled: bool,
+    min_delay: Duration,
+    semaphore: Option<Arc<Semaphore>>,
+}
+
+impl ParallelLimiter {
+    pub(crate) fn from_config(config: &ParallelDispatchConfig) -> Self {
+        Self {
+            state: RwLock::new(LimiterState {
+                enabled: config.enabled,
+                min_delay: Duration::from_millis(config.min_delay_ms),
+                semaphore: config
+                    .max_concurrent_calls
+                    .map(|calls| Arc::new(Semaphore::new(calls))),
+            }),
+            last_dispatch: Mutex::new(None),
+        }
+    }
+
+    /// Whether parallel dispatch is enabled at all.
+    pub(crate) fn enabled(&self) -> bool {
+        self.state.read().map(|state| state.enabled).unwrap_or(true)
+    }
+
+    /// Waits for admission of one parallel dispatch: enforces the minimum
+    /// spacing, then takes a concurrency permit when a cap is configured.
+    pub(crate) async fn admit(&self) -> Option<OwnedSemaphorePermit> {
+        let (min_delay, semaphore) = {
+            let Ok(state) = self.state.read() else {
+                return None;
+            };
+            (state.min_delay, state.semaphore.clone())
+        };
+        if !min_delay.is_zero() {
+            let mut last = self.last_dispatch.lock().await;
+            if let Some(last_dispatch) = *last {
+                let ready_at = last_dispatch + min_delay;
+                tokio::time::sleep_until(ready_at).await;
+            }
+            *last = Some(Instant::now());
+        }
+        match semaphore {
+            Some(semaphore) => semaphore.acquire_owned().await.ok(),
+            None => None,
+        }
+    }
+
+    /// Applies a live adjustment; `None` fields keep their current value and
+    /// a cap of `0` removes the limit.
+    pub(crate) fn update(
+        &self,
+        enabled: Option<bool>,
+        max_concurrent_calls: Option<u64>,
+        min_delay_ms: Option<u64>,
+    ) {
+        let Ok(mut state) = self.state.write() else {
+            return;
+        };
+        if let Some(enabled) = enabled {
+            state.enabled = enabled;
+        }
+        if let Some(max_concurrent_calls) = max_concurrent_calls {
+            state.semaphore = usize::try_from(max_concurrent_calls)
+                .ok()
+                .filter(|&calls| calls > 0)
+                .map(|calls| Arc::new(Semaphore::new(calls)));
+        }
+        if let Some(min_delay_ms) = min_delay_ms {
+            state.min_delay = Duration::from_millis(min_delay_ms);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Optional post-processing of Read-classified command output.
+//!
+//! When the agent cats documentation files, raw HTML or troff man-page
+//! source wastes a large share of the token budget on markup. With
+//! `render_read_documents = true`, output from commands classified as reads
+//! is converted to plain text/markdown before it reaches the model context.
+
+use codex_protocol::parse_command::ParsedCommand;
+
+use crate::tools::handlers::fetch_url::html_to_markdown;
+
+/// Returns whether every parsed segment is a read-style command, so the
+/// conversion never rewrites output the model may need verbatim (build logs,
+/// JSON from arbitrary tools, ...).
+pub(crate) fn is_read_only_command(parsed: &[ParsedCommand]) -> bool {
+    !parsed.is_empty()
+        && parsed
+            .iter()
+            .all(|command| matches!(command, ParsedCommand::Read { .. }))
+}
+
+/// Converts HTML or troff man-page source to plain text/markdown; returns
+/// `None` when the content is neither, leaving the output untouched.
+pub(crate) fn render_read_document(content: &str) -> Option<String> {
+    let head = content.trim_start();
+    if head.starts_with("<!DOCTYPE") || head.starts_with("<!doctype") || head.starts_with("<html") {
+        return Some(html_to_markdown(content));
+    }
+    if looks_like_troff(head) {
+        return Some(troff_to_text(content));
+    }
+    None
+}
+
+fn looks_like_troff(head: &str) -> bool {
+    head.lines()
+        .find(|line| !line.starts_with(".\\\"") && !line.trim().is_empty())
+        .is_some_and(|line| {
+            line.starts_with(".TH ") || line.starts_with(".Dd ") || line.starts_with(".SH ")
+        })
+}
+
+/// Minimal troff/man stripping: keeps section headings and running text,
+/// drops macro lines, and removes font/escape sequences.
+fn troff_to_text(source: &str) -> String {
+    let mut out = String::with_capacity(source.len() / 2);
+    for line in source.lines() {
+        if line.starts_with(".\\\"") {
+            continue; // comment
+        }
+        if let Some(rest) = line
+            .strip_prefix(".SH")
+            .or_else(|| line.strip_prefix(".Sh"))
+        {
+            out.push_str("\n# ");
+            out.push_str(&strip_troff_escapes(rest.trim().trim_matches('"')));
+            out.push('\n');
+            continue;
+        }
+        if let Some(rest) = line
+            .strip_prefix(".SS")
+            .or_else(|| line.strip_prefix(".Ss"))
+        {
+            out.push_str("\n## ");
+            out.push_str(&strip_troff_escapes(rest.trim().trim_matches('"')));
+            out.push('\n');
+            continue;
+        }
+        if let Some(rest) = line
+            .strip_prefix(".TP")
+            .or_else(|| line.strip_prefix(".IP"))
+        {
+            let rest = rest.trim().trim_matches('"');
+            if !rest.is_empty() {
+                out.push_str("\n- ");
+                out.push_str(&strip_troff_escapes(rest));
+                out.push('\n');
+            } else {
+                out.push('\n');
+            }
+            continue;
+        }
+        if let Some(rest) = line
+            .strip_prefix(".B ")
+            .or_else(|| line.strip_prefix(".I "))
+            .or_else(|| line.strip_prefix(".BR "))
+            .or_else(|| line.strip_prefix(".IR "))
+            .or_else(|| line.strip_prefix(".BI "))
+        {
+            out.push_str(&strip_troff_escapes(rest.trim()));
+            out.push('\n');
+            continue;
+        }
+        if line.starts_with('.') || line.starts_with('\'') {
+            // Other macro lines (.TH, .PP, .br, .nf, ...) carry layout, not
+            // content; paragraph macros become blank lines.
+            if line.starts_with(".PP") || line.starts_with(".P") || line.starts_with(".LP") {
+                out.push('\n');
+            }
+            continue;
+        }
+        out.push_str(&strip_troff_escapes(line));
+        out.push('\n');
+    }
+
+    let mut collapsed = String::with_capacity(out.len());
+    let mut blank_run = 0usize;
+    for line in out.lines() {
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        collapsed.push_str(line.trim_end());
+        collapsed.push('\n');
+    }
+    collapsed.trim().to_string()
+}
+
+/// Removes `\fB`/`\fI`/`\fR`/`\f(XX` font switches and common escapes.
+fn strip_troff_escapes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('f') => match chars.next() {
+                Some('(') => {
+                    chars.next();
+                    chars.next();
+                }
+                Some('[') => {
+                    for ch in chars.by_ref() {
+                        if ch == ']' {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Some('-') => out.push('-'),
+            Some('&') | Some('%') => {}
+            Some('e') => out.push('\\'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn troff_man_page_renders_to_text() {
+        let man = ".\\\" comment\n.TH LS 1 \"March 2024\"\n.SH NAME\nls \\- list directory contents\n.SH DESCRIPTION\n.PP\nList information about the \\fBFILEs\\fR.\n.TP\n.B \\-a\ndo not ignore hidden entries\n";
+        let text = troff_to_text(man);
+        assert_eq!(
+            text,
+            "# NAME\nls - list directory contents\n\n# DESCRIPTION\n\nList information about the FILEs.\n\n-a\ndo not ignore hidden entries"
+        );
+    }
+
+    #[test]
+    fn non_document_output_is_untouched() {
+        assert_eq!(render_read_document("plain build log output"), None);
+        assert_eq!(render_read_document("{\"json\": true}"), None);
+    }
+}
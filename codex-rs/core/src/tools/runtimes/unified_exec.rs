@@ -73,6 +73,7 @@ pub struct UnifiedExecRequest {
     pub explicit_env_overrides: HashMap<String, String>,
     pub network: Option<NetworkProxy>,
     pub tty: bool,
+    pub separate_stderr: bool,
     pub sandbox_permissions: SandboxPermissions,
     pub additional_permissions: Option<AdditionalPermissionProfile>,
     #[cfg(unix)]
@@ -433,6 +434,7 @@ impl<'a> ToolRuntime<UnifiedExecRequest, UnifiedExecProcess> for UnifiedExecRunt
                             req.process_id,
                             &prepared.exec_request,
                             req.tty,
+                            req.separate_stderr,
                             prepared.spawn_lifecycle,
                             req.turn_environment.environment.as_ref(),
                         )
@@ -478,6 +480,7 @@ impl<'a> ToolRuntime<UnifiedExecRequest, UnifiedExecProcess> for UnifiedExecRunt
                 /*environment_id*/ Some(&req.turn_environment.environment_id),
                 req.exec_server_env_config.clone(),
                 req.tty,
+                req.separate_stderr,
                 Box::new(NoopSpawnLifecycle),
                 req.turn_environment.environment.as_ref(),
             )
@@ -572,6 +575,7 @@ mod tests {
             explicit_env_overrides: HashMap::new(),
             network: None,
             tty: false,
+            separate_stderr: false,
             sandbox_permissions: SandboxPermissions::UseDefault,
             additional_permissions: None,
             #[cfg(unix)]
@@ -674,6 +678,7 @@ mod tests {
             explicit_env_overrides: HashMap::new(),
             network: None,
             tty: false,
+            separate_stderr: false,
             sandbox_permissions,
             additional_permissions: None,
             #[cfg(unix)]
@@ -9,23 +9,30 @@ use crate::tools::handlers::CodeModeWaitHandler;
 use crate::tools::handlers::DynamicToolHandler;
 use crate::tools::handlers::ExecCommandHandler;
 use crate::tools::handlers::ExecCommandHandlerOptions;
+use crate::tools::handlers::FetchUrlHandler;
 use crate::tools::handlers::GetContextRemainingHandler;
 use crate::tools::handlers::ListAvailablePluginsToInstallHandler;
+use crate::tools::handlers::ListDirHandler;
+use crate::tools::handlers::ListExecSessionsHandler;
 use crate::tools::handlers::ListMcpResourceTemplatesHandler;
 use crate::tools::handlers::ListMcpResourcesHandler;
 use crate::tools::handlers::McpHandler;
 use crate::tools::handlers::NewContextWindowHandler;
 use crate::tools::handlers::PlanHandler;
+use crate::tools::handlers::ReadFileHandler;
 use crate::tools::handlers::ReadMcpResourceHandler;
 use crate::tools::handlers::RequestPermissionsHandler;
 use crate::tools::handlers::RequestPluginInstallHandler;
 use crate::tools::handlers::RequestUserInputHandler;
+use crate::tools::handlers::SearchWorkspaceHandler;
 use crate::tools::handlers::ShellCommandHandler;
 use crate::tools::handlers::ShellCommandHandlerOptions;
 use crate::tools::handlers::SleepHandler;
 use crate::tools::handlers::TestSyncHandler;
 use crate::tools::handlers::ToolSearchHandlerCache;
 use crate::tools::handlers::ViewImageHandler;
+use crate::tools::handlers::WebSearchProviderHandler;
+use crate::tools::handlers::WriteFileHandler;
 use crate::tools::handlers::WriteStdinHandler;
 use crate::tools::handlers::agent_jobs::ReportAgentJobResultHandler;
 use crate::tools::handlers::agent_jobs::SpawnAgentsOnCsvHandler;
@@ -548,6 +555,7 @@ fn add_tool_sources(context: &CoreToolPlanContext<'_>, planned_tools: &mut Plann
                 include_shell_parameter: unified_exec_should_include_shell_parameter(turn_context),
             }));
             planned_tools.add(WriteStdinHandler);
+            planned_tools.add(ListExecSessionsHandler);
             planned_tools.add(ViewImageHandler::new(ViewImageToolOptions {
                 can_request_original_image_detail: can_request_original_image_detail(
                     &turn_context.model_info,
@@ -605,6 +613,7 @@ fn add_shell_tools(context: &CoreToolPlanContext<'_>, planned_tools: &mut Planne
                 include_shell_parameter: unified_exec_should_include_shell_parameter(turn_context),
             }));
             planned_tools.add(WriteStdinHandler);
+            planned_tools.add(ListExecSessionsHandler);
 
             // Keep the legacy shell tool registered while unified exec is
             // model-visible.
@@ -663,6 +672,29 @@ fn add_core_utility_tools(context: &CoreToolPlanContext<'_>, planned_tools: &mut
         planned_tools.add(GetContextRemainingHandler);
     }
 
+    if turn_context.config.search_workspace_tool {
+        planned_tools.add(SearchWorkspaceHandler);
+    }
+
+    if turn_context.config.fetch_url_tool {
+        planned_tools.add(FetchUrlHandler);
+    }
+
+    // The hosted web_search tool owns the name when the provider supports it
+    // and the mode is not disabled; the local backend fills the gap otherwise.
+    if let Some(web_search_provider) = turn_context.config.web_search_provider.clone()
+        && (turn_context.config.web_search_mode.value() == WebSearchMode::Disabled
+            || !turn_context.provider.capabilities().web_search)
+    {
+        planned_tools.add(WebSearchProviderHandler::new(web_search_provider));
+    }
+
+    if turn_context.config.file_io_tools {
+        planned_tools.add(ReadFileHandler);
+        planned_tools.add(WriteFileHandler);
+        planned_tools.add(ListDirHandler);
+    }
+
     planned_tools.add(SleepHandler);
 
     if tool_suggest_enabled(turn_context)
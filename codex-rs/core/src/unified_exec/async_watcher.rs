@@ -16,6 +16,7 @@ use crate::tools::events::ToolEventCtx;
 use crate::tools::events::ToolEventFailure;
 use crate::tools::events::ToolEventStage;
 use crate::unified_exec::head_tail_buffer::HeadTailBuffer;
+use crate::unified_exec::normalize_pty_output;
 use codex_protocol::exec_output::ExecToolCallOutput;
 use codex_protocol::exec_output::StreamOutput;
 use codex_protocol::protocol::EventMsg;
@@ -204,7 +205,10 @@ pub(crate) async fn emit_exec_end_for_unified_exec(
     exit_code: i32,
     duration: Duration,
 ) {
-    let aggregated_output = resolve_aggregated_output(&transcript, fallback_output).await;
+    let mut aggregated_output = resolve_aggregated_output(&transcript, fallback_output).await;
+    if turn_ref.config.normalize_pty_output {
+        aggregated_output = normalize_pty_output(&aggregated_output);
+    }
     let output = ExecToolCallOutput {
         exit_code,
         stdout: StreamOutput::new(aggregated_output.clone()),
@@ -249,11 +253,14 @@ pub(crate) async fn emit_failed_exec_end_for_unified_exec(
     message: String,
     duration: Duration,
 ) {
-    let stdout = if fallback_output.is_empty() {
+    let mut stdout = if fallback_output.is_empty() {
         resolve_aggregated_output(&transcript, fallback_output).await
     } else {
         fallback_output
     };
+    if turn_ref.config.normalize_pty_output {
+        stdout = normalize_pty_output(&stdout);
+    }
     let aggregated_output = if stdout.is_empty() {
         message.clone()
     } else {
@@ -112,6 +112,7 @@ async fn exec_command_with_tty(
                 process_id,
                 &request,
                 tty,
+                /*separate_stderr*/ false,
                 Box::new(NoopSpawnLifecycle),
                 turn.environments
                     .primary()
@@ -198,6 +199,8 @@ async fn exec_command_with_tty(
         exit_code,
         original_token_count: Some(approx_token_count(&text)),
         hook_command: Some(cmd.to_string()),
+        stderr: None,
+        timed_out_after_ms: None,
     })
 }
 
@@ -812,6 +815,7 @@ async fn completed_pipe_commands_preserve_exit_code() -> anyhow::Result<()> {
             /*process_id*/ 1234,
             &request,
             /*tty*/ false,
+            /*separate_stderr*/ false,
             Box::new(NoopSpawnLifecycle),
             &environment,
         )
@@ -832,6 +836,110 @@ async fn completed_pipe_commands_preserve_exit_code() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn timeout_watchdog_kills_hung_command_and_marks_timed_out() -> anyhow::Result<()> {
+    let (_, turn) = make_session_and_context().await;
+    #[allow(deprecated)]
+    let cwd = turn.cwd.clone();
+    let request = test_exec_request(
+        &turn,
+        vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "sleep 30".to_string(),
+        ],
+        cwd,
+        shell_env(),
+    );
+
+    let environment = codex_exec_server::Environment::default_for_tests();
+    let process = Arc::new(
+        UnifiedExecProcessManager::default()
+            .open_session_with_prepared_exec_env(
+                /*process_id*/ 1234,
+                &request,
+                /*tty*/ false,
+                /*separate_stderr*/ false,
+                Box::new(NoopSpawnLifecycle),
+                &environment,
+            )
+            .await?,
+    );
+
+    UnifiedExecProcessManager::spawn_timeout_watchdog(Arc::downgrade(&process), 200);
+
+    let exit_signal = process.cancellation_token();
+    assert!(
+        tokio::time::timeout(Duration::from_secs(5), exit_signal.cancelled())
+            .await
+            .is_ok(),
+        "timed-out process was not killed"
+    );
+    assert_eq!(process.timed_out_after_ms(), Some(200));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn separate_stderr_keeps_stderr_out_of_combined_output() -> anyhow::Result<()> {
+    let (_, turn) = make_session_and_context().await;
+    #[allow(deprecated)]
+    let cwd = turn.cwd.clone();
+    let request = test_exec_request(
+        &turn,
+        vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "echo to-stdout; echo to-stderr 1>&2".to_string(),
+        ],
+        cwd,
+        shell_env(),
+    );
+
+    let environment = codex_exec_server::Environment::default_for_tests();
+    let process = UnifiedExecProcessManager::default()
+        .open_session_with_prepared_exec_env(
+            /*process_id*/ 1234,
+            &request,
+            /*tty*/ false,
+            /*separate_stderr*/ true,
+            Box::new(NoopSpawnLifecycle),
+            &environment,
+        )
+        .await?;
+
+    if !process.has_exited() {
+        let exit_signal = process.cancellation_token();
+        assert!(
+            tokio::time::timeout(Duration::from_secs(2), exit_signal.cancelled())
+                .await
+                .is_ok(),
+            "process did not report exit within timeout"
+        );
+    }
+    // Give the output and stderr drain tasks a moment to flush.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let crate::unified_exec::process::OutputHandles { output_buffer, .. } =
+        process.output_handles();
+    let combined = String::from_utf8_lossy(&output_buffer.lock().await.to_bytes()).to_string();
+    assert!(combined.contains("to-stdout"), "stdout missing: {combined}");
+    assert!(
+        !combined.contains("to-stderr"),
+        "stderr leaked into combined output: {combined}"
+    );
+
+    let stderr = process
+        .drain_separate_stderr()
+        .await
+        .expect("stderr capture enabled");
+    let stderr = String::from_utf8_lossy(&stderr).to_string();
+    assert!(
+        stderr.contains("to-stderr"),
+        "stderr not captured: {stderr}"
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn unified_exec_uses_remote_exec_server_when_configured() -> anyhow::Result<()> {
     skip_if_sandbox!(Ok(()));
@@ -852,6 +960,7 @@ async fn unified_exec_uses_remote_exec_server_when_configured() -> anyhow::Resul
             /*process_id*/ 1234,
             &request,
             /*tty*/ true,
+            /*separate_stderr*/ false,
             Box::new(NoopSpawnLifecycle),
             remote_test_env.environment(),
         )
@@ -907,6 +1016,7 @@ async fn remote_exec_server_rejects_inherited_fd_launches() -> anyhow::Result<()
             /*process_id*/ 1234,
             &request,
             /*tty*/ true,
+            /*separate_stderr*/ false,
             Box::new(TestSpawnLifecycle {
                 inherited_fds: vec![42],
             }),
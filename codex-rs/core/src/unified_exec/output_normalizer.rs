@@ -0,0 +1,90 @@
+//! Post-processing for collected PTY output.
+//!
+//! PTYs emit color codes, cursor-movement sequences, and carriage-return
+//! progress updates that bloat token usage and confuse models. The normalizer
+//! strips ANSI escape sequences, resolves carriage-return overwrites
+//! (progress bars keep only their final state), and normalizes CRLF line
+//! endings, leaving plain text for truncation and model consumption. It runs
+//! before output is truncated and before events are emitted; disable it with
+//! `normalize_pty_output = false` in config.
+
+/// Normalizes collected PTY output into plain text.
+pub(crate) fn normalize_pty_output(text: &str) -> String {
+    if !text.contains('\u{1b}') && !text.contains('\r') {
+        return text.to_string();
+    }
+    let stripped = strip_ansi_sequences(text);
+    collapse_carriage_returns(&stripped)
+}
+
+/// Removes ANSI escape sequences (CSI, OSC, and two-character escapes) while
+/// leaving all other content untouched.
+fn strip_ansi_sequences(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            out.push(c);
+            continue;
+        }
+        match chars.peek() {
+            // CSI: ESC [ parameter/intermediate bytes, then a final byte @..~.
+            Some('[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: ESC ] ... terminated by BEL or ST (ESC \).
+            Some(']') => {
+                chars.next();
+                let mut prev_esc = false;
+                for c in chars.by_ref() {
+                    if c == '\u{7}' || (prev_esc && c == '\\') {
+                        break;
+                    }
+                    prev_esc = c == '\u{1b}';
+                }
+            }
+            // Charset designation (ESC ( B, ESC ) 0, ...): final char follows.
+            Some('(' | ')') => {
+                chars.next();
+                chars.next();
+            }
+            // Two-character escapes (ESC c, ESC =, ESC 7, ...).
+            Some(_) => {
+                chars.next();
+            }
+            None => {}
+        }
+    }
+    out
+}
+
+/// Resolves carriage-return overwrites within each line: CRLF becomes LF, and
+/// for bare CRs (progress bars rewriting themselves in place) only the text
+/// after the last CR is kept.
+fn collapse_carriage_returns(text: &str) -> String {
+    if !text.contains('\r') {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (body, newline) = match line.strip_suffix('\n') {
+            Some(body) => (body, "\n"),
+            None => (line, ""),
+        };
+        let body = body.strip_suffix('\r').unwrap_or(body);
+        let body = body.rsplit('\r').next().unwrap_or(body);
+        out.push_str(body);
+        out.push_str(newline);
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "output_normalizer_tests.rs"]
+mod tests;
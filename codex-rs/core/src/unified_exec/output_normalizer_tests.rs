@@ -0,0 +1,53 @@
+use super::normalize_pty_output;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn passes_plain_text_through_unchanged() {
+    assert_eq!(normalize_pty_output("hello\nworld\n"), "hello\nworld\n");
+}
+
+#[test]
+fn strips_color_codes() {
+    assert_eq!(
+        normalize_pty_output("\u{1b}[31merror\u{1b}[0m: something failed\n"),
+        "error: something failed\n"
+    );
+}
+
+#[test]
+fn strips_osc_title_sequences() {
+    assert_eq!(
+        normalize_pty_output("\u{1b}]0;window title\u{7}prompt$ "),
+        "prompt$ "
+    );
+}
+
+#[test]
+fn normalizes_crlf_line_endings() {
+    assert_eq!(
+        normalize_pty_output("line one\r\nline two\r\n"),
+        "line one\nline two\n"
+    );
+}
+
+#[test]
+fn collapses_carriage_return_progress_bars() {
+    assert_eq!(
+        normalize_pty_output("Downloading 10%\rDownloading 55%\rDownloading 100%\ndone\n"),
+        "Downloading 100%\ndone\n"
+    );
+}
+
+#[test]
+fn keeps_text_after_unterminated_escape() {
+    assert_eq!(normalize_pty_output("partial\u{1b}"), "partial");
+}
+
+#[test]
+fn strips_cursor_movement_sequences() {
+    assert_eq!(
+        normalize_pty_output("\u{1b}[2K\u{1b}[1Gredrawn line\n"),
+        "redrawn line\n"
+    );
+}
@@ -86,6 +86,9 @@ pub(crate) struct UnifiedExecProcess {
     state_rx: watch::Receiver<ProcessState>,
     output_task: Option<JoinHandle<()>>,
     sandbox_type: SandboxType,
+    /// Populated when the process was opened with separate stderr capture:
+    /// stderr chunks are routed here instead of the combined output stream.
+    stderr_buffer: Option<OutputBuffer>,
     _spawn_lifecycle: Option<SpawnLifecycleHandle>,
 }
 
@@ -127,6 +130,7 @@ impl UnifiedExecProcess {
             state_rx,
             output_task: None,
             sandbox_type,
+            stderr_buffer: None,
             _spawn_lifecycle: spawn_lifecycle,
         }
     }
@@ -170,6 +174,14 @@ impl UnifiedExecProcess {
         self.output_tx.subscribe()
     }
 
+    /// Drains stderr collected since the last call. Returns `None` unless the
+    /// process was opened with separate stderr capture.
+    pub(super) async fn drain_separate_stderr(&self) -> Option<Vec<u8>> {
+        let buffer = self.stderr_buffer.as_ref()?;
+        let chunks = buffer.lock().await.drain_chunks();
+        Some(chunks.concat())
+    }
+
     pub(super) fn cancellation_token(&self) -> CancellationToken {
         self.cancellation_token.clone()
     }
@@ -233,6 +245,33 @@ impl UnifiedExecProcess {
         Ok(())
     }
 
+    /// Politely asks the process to exit (SIGTERM for local process
+    /// groups) so TERM-aware programs can clean up; callers escalate to
+    /// [`Self::terminate`] when the process does not exit in time.
+    pub(super) async fn terminate_gracefully(&self) -> Result<(), UnifiedExecError> {
+        match &self.process_handle {
+            ProcessHandle::Local(process_handle) => process_handle
+                .signal(PtyProcessSignal::Terminate)
+                .map_err(|err| UnifiedExecError::process_failed(err.to_string())),
+            ProcessHandle::ExecServer(_) => Err(UnifiedExecError::process_failed(
+                "graceful terminate is not supported for exec-server processes".to_string(),
+            )),
+        }
+    }
+
+    /// Records that the per-command timeout expired; the first mark wins.
+    pub(super) fn mark_timed_out(&self, timeout_ms: u64) {
+        let mut state = self.state_rx.borrow().clone();
+        if state.timed_out_after_ms.is_none() {
+            state.timed_out_after_ms = Some(timeout_ms);
+            let _ = self.state_tx.send_replace(state);
+        }
+    }
+
+    pub(super) fn timed_out_after_ms(&self) -> Option<u64> {
+        self.state_rx.borrow().timed_out_after_ms
+    }
+
     pub(super) async fn interrupt(&self) -> Result<(), UnifiedExecError> {
         match &self.process_handle {
             ProcessHandle::Local(process_handle) => process_handle
@@ -318,19 +357,37 @@ impl UnifiedExecProcess {
         spawned: SpawnedPty,
         sandbox_type: SandboxType,
         spawn_lifecycle: SpawnLifecycleHandle,
+        separate_stderr: bool,
     ) -> Result<Self, UnifiedExecError> {
         let SpawnedPty {
             session: process_handle,
             stdout_rx,
-            stderr_rx,
+            mut stderr_rx,
             mut exit_rx,
         } = spawned;
+        let mut stderr_buffer: Option<OutputBuffer> = None;
+        if separate_stderr {
+            // Route stderr to its own buffer and hand the combiner an
+            // already-closed receiver so only stdout reaches the combined
+            // output stream.
+            let buffer: OutputBuffer = Arc::new(Mutex::new(HeadTailBuffer::default()));
+            let sink = Arc::clone(&buffer);
+            tokio::spawn(async move {
+                while let Some(chunk) = stderr_rx.recv().await {
+                    sink.lock().await.push_chunk(chunk);
+                }
+            });
+            stderr_buffer = Some(buffer);
+            let (_, closed_rx) = tokio::sync::mpsc::channel(1);
+            stderr_rx = closed_rx;
+        }
         let output_rx = codex_utils_pty::combine_output_receivers(stdout_rx, stderr_rx);
         let mut managed = Self::new(
             ProcessHandle::Local(Box::new(process_handle)),
             sandbox_type,
             Some(spawn_lifecycle),
         );
+        managed.stderr_buffer = stderr_buffer;
         managed.output_task = Some(Self::spawn_local_output_task(
             output_rx,
             Arc::clone(&managed.output_buffer),
@@ -3,6 +3,7 @@ use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use tokio::sync::Notify;
@@ -41,6 +42,7 @@ use crate::unified_exec::MIN_EMPTY_YIELD_TIME_MS;
 use crate::unified_exec::MIN_YIELD_TIME_MS;
 use crate::unified_exec::ProcessEntry;
 use crate::unified_exec::ProcessStore;
+use crate::unified_exec::TIMEOUT_TERMINATION_GRACE_PERIOD;
 use crate::unified_exec::UnifiedExecContext;
 use crate::unified_exec::UnifiedExecError;
 use crate::unified_exec::UnifiedExecProcessManager;
@@ -52,6 +54,7 @@ use crate::unified_exec::async_watcher::start_streaming_output;
 use crate::unified_exec::clamp_yield_time;
 use crate::unified_exec::generate_chunk_id;
 use crate::unified_exec::head_tail_buffer::HeadTailBuffer;
+use crate::unified_exec::normalize_pty_output;
 use crate::unified_exec::process::OutputBuffer;
 use crate::unified_exec::process::OutputHandles;
 use crate::unified_exec::process::SpawnLifecycleHandle;
@@ -368,6 +371,63 @@ fn terminate_process_on_network_denial(
 }
 
 impl UnifiedExecProcessManager {
+    /// Applies the configured PTY output post-processing (ANSI stripping,
+    /// carriage-return collapsing, CRLF normalization) to collected bytes
+    /// before they reach truncation, events, or the model.
+    fn post_process_collected(&self, collected: Vec<u8>) -> Vec<u8> {
+        if !self.normalize_output {
+            return collected;
+        }
+        normalize_pty_output(&String::from_utf8_lossy(&collected)).into_bytes()
+    }
+
+    /// Enforces a per-command timeout: on expiry the process is asked to
+    /// terminate gracefully (SIGTERM to the process group), given a short
+    /// grace window, then hard-killed, and the process state is marked timed
+    /// out so responses report the partial output instead of hanging through
+    /// repeated yield windows.
+    pub(super) fn spawn_timeout_watchdog(process: Weak<UnifiedExecProcess>, timeout_ms: u64) {
+        let exited = {
+            let Some(process) = process.upgrade() else {
+                return;
+            };
+            process.cancellation_token()
+        };
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_millis(timeout_ms)) => {}
+                _ = exited.cancelled() => return,
+            }
+            let Some(process) = process.upgrade() else {
+                return;
+            };
+            if process.has_exited() {
+                return;
+            }
+            process.mark_timed_out(timeout_ms);
+            if process.terminate_gracefully().await.is_ok() {
+                let _ = tokio::time::timeout(TIMEOUT_TERMINATION_GRACE_PERIOD, exited.cancelled())
+                    .await;
+            }
+            if !process.has_exited() {
+                process.terminate();
+            }
+        });
+    }
+
+    /// Drains separately captured stderr for a tool response, applying the
+    /// same post-processing as the main output stream. Returns `None` when
+    /// the process was not opened with separate stderr capture or nothing
+    /// new arrived.
+    async fn drain_stderr_for_response(&self, process: &UnifiedExecProcess) -> Option<String> {
+        let bytes = process.drain_separate_stderr().await?;
+        if bytes.is_empty() {
+            return None;
+        }
+        let bytes = self.post_process_collected(bytes);
+        Some(String::from_utf8_lossy(&bytes).to_string())
+    }
+
     pub(crate) async fn allocate_process_id(&self) -> i32 {
         loop {
             let mut store = self.process_store.lock().await;
@@ -448,6 +508,9 @@ impl UnifiedExecProcessManager {
         emitter.emit(event_ctx, ToolEventStage::Begin).await;
 
         start_streaming_output(&process, context, Arc::clone(&transcript));
+        if let Some(timeout_ms) = request.timeout_ms {
+            Self::spawn_timeout_watchdog(Arc::downgrade(&process), timeout_ms);
+        }
         let start = Instant::now();
         // Persist live sessions before the initial yield wait so interrupting the
         // turn cannot drop the last Arc and terminate the background process.
@@ -499,6 +562,7 @@ impl UnifiedExecProcessManager {
         .await;
         let wall_time = Instant::now().saturating_duration_since(start);
 
+        let collected = self.post_process_collected(collected);
         let text = String::from_utf8_lossy(&collected).to_string();
         let chunk_id = generate_chunk_id();
         if deferred_network_approval
@@ -617,6 +681,7 @@ impl UnifiedExecProcessManager {
         };
 
         let original_token_count = approx_token_count(&text);
+        let stderr = self.drain_stderr_for_response(process.as_ref()).await;
         let response = ExecCommandToolOutput {
             event_call_id: context.call_id.clone(),
             chunk_id,
@@ -628,6 +693,8 @@ impl UnifiedExecProcessManager {
             exit_code,
             original_token_count: Some(original_token_count),
             hook_command: Some(request.hook_command.clone()),
+            stderr,
+            timed_out_after_ms: process.timed_out_after_ms(),
         };
 
         Ok(response)
@@ -711,6 +778,7 @@ impl UnifiedExecProcessManager {
         .await;
         let wall_time = Instant::now().saturating_duration_since(start);
 
+        let collected = self.post_process_collected(collected);
         let text = String::from_utf8_lossy(&collected).to_string();
         let original_token_count = approx_token_count(&text);
         let chunk_id = generate_chunk_id();
@@ -772,6 +840,7 @@ impl UnifiedExecProcessManager {
             }
         };
 
+        let stderr = self.drain_stderr_for_response(process.as_ref()).await;
         let response = ExecCommandToolOutput {
             event_call_id,
             chunk_id,
@@ -783,6 +852,8 @@ impl UnifiedExecProcessManager {
             exit_code,
             original_token_count: Some(original_token_count),
             hook_command: Some(hook_command),
+            stderr,
+            timed_out_after_ms: process.timed_out_after_ms(),
         };
 
         Ok(response)
@@ -918,6 +989,7 @@ impl UnifiedExecProcessManager {
         environment_id: Option<&str>,
         exec_server_env_config: Option<ExecServerEnvConfig>,
         tty: bool,
+        separate_stderr: bool,
         spawn_lifecycle: SpawnLifecycleHandle,
         environment: &codex_exec_server::Environment,
     ) -> Result<UnifiedExecProcess, ToolError> {
@@ -932,6 +1004,7 @@ impl UnifiedExecProcessManager {
             process_id,
             &request,
             tty,
+            separate_stderr,
             spawn_lifecycle,
             environment,
         )
@@ -952,6 +1025,7 @@ impl UnifiedExecProcessManager {
         process_id: i32,
         request: &ExecRequest,
         tty: bool,
+        separate_stderr: bool,
         mut spawn_lifecycle: SpawnLifecycleHandle,
         environment: &codex_exec_server::Environment,
     ) -> Result<UnifiedExecProcess, UnifiedExecError> {
@@ -1040,6 +1114,7 @@ impl UnifiedExecProcessManager {
                 spawned.map_err(|err| UnifiedExecError::create_process(err.to_string()))?,
                 request.sandbox,
                 spawn_lifecycle,
+                separate_stderr,
             )
             .await;
         }
@@ -1049,6 +1124,11 @@ impl UnifiedExecProcessManager {
                     "remote exec-server does not support inherited file descriptors".to_string(),
                 ));
             }
+            if separate_stderr {
+                return Err(UnifiedExecError::create_process(
+                    "separate stderr capture is not supported for remote environments".to_string(),
+                ));
+            }
 
             let started = environment
                 .get_exec_backend()
@@ -1096,7 +1176,8 @@ impl UnifiedExecProcessManager {
         let spawned =
             spawn_result.map_err(|err| UnifiedExecError::create_process(err.to_string()))?;
         spawn_lifecycle.after_spawn();
-        UnifiedExecProcess::from_spawned(spawned, request.sandbox, spawn_lifecycle).await
+        UnifiedExecProcess::from_spawned(spawned, request.sandbox, spawn_lifecycle, separate_stderr)
+            .await
     }
 
     pub(super) async fn open_session_with_sandbox(
@@ -1142,6 +1223,15 @@ impl UnifiedExecProcessManager {
                 prefix_rule: request.prefix_rule.clone(),
             })
             .await;
+        let exec_approval_requirement = crate::safety::enforce_write_precheck_on_exec_approval(
+            exec_approval_requirement,
+            &request.command,
+            context.turn.approval_policy.value(),
+            &context.turn.permission_profile(),
+            &context.turn.file_system_sandbox_policy(),
+            &cwd,
+            context.turn.windows_sandbox_level,
+        );
         let req = UnifiedExecToolRequest {
             command: request.command.clone(),
             shell_type: request.shell_type,
@@ -1161,6 +1251,7 @@ impl UnifiedExecProcessManager {
                 .clone(),
             network: request.network.clone(),
             tty: request.tty,
+            separate_stderr: request.separate_stderr,
             sandbox_permissions: request.sandbox_permissions,
             additional_permissions: request.additional_permissions.clone(),
             #[cfg(unix)]
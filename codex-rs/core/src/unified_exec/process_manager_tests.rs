@@ -274,6 +274,7 @@ async fn failed_initial_end_for_unstored_process_uses_fallback_output() {
         hook_command: "echo before".to_string(),
         process_id: 123,
         yield_time_ms: 1000,
+        timeout_ms: None,
         max_output_tokens: None,
         #[allow(deprecated)]
         cwd: turn.cwd.clone().into(),
@@ -287,6 +288,7 @@ async fn failed_initial_end_for_unstored_process_uses_fallback_output() {
         shell_mode: codex_tools::UnifiedExecShellMode::Direct,
         network: None,
         tty: true,
+        separate_stderr: false,
         sandbox_permissions: crate::sandboxing::SandboxPermissions::UseDefault,
         additional_permissions: None,
         additional_permissions_preapproved: false,
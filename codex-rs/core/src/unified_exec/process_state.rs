@@ -4,6 +4,8 @@ pub(crate) struct ProcessState {
     pub(crate) exit_code: Option<i32>,
     pub(crate) failure_message: Option<String>,
     pub(crate) sandbox_denied: bool,
+    /// Set when the per-command timeout expired and the process was killed.
+    pub(crate) timed_out_after_ms: Option<u64>,
 }
 
 impl ProcessState {
@@ -13,6 +15,7 @@ impl ProcessState {
             exit_code,
             failure_message: self.failure_message.clone(),
             sandbox_denied: self.sandbox_denied,
+            timed_out_after_ms: self.timed_out_after_ms,
         }
     }
 
@@ -22,6 +25,7 @@ impl ProcessState {
             exit_code: self.exit_code,
             failure_message: Some(message),
             sandbox_denied: self.sandbox_denied,
+            timed_out_after_ms: self.timed_out_after_ms,
         }
     }
 }
@@ -82,6 +82,12 @@ pub(crate) fn emit_feedback_auth_recovery_tags(
     );
 }
 
+/// Detects a Termux (Android terminal emulator) environment, where no
+/// OS-level sandbox backend (landlock/seccomp) is available.
+pub fn is_termux() -> bool {
+    std::env::var_os("TERMUX_VERSION").is_some() || std::env::var_os("TERMUX_APP_PID").is_some()
+}
+
 pub fn backoff(attempt: u64) -> Duration {
     let exp = BACKOFF_FACTOR.powi(attempt.saturating_sub(1) as i32);
     let base = (INITIAL_DELAY_MS as f64 * exp) as u64;
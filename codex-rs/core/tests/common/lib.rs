@@ -9,11 +9,9 @@ use std::sync::OnceLock;
 use tempfile::TempDir;
 
 use codex_config::CloudConfigBundleLoader;
-use codex_config::LoaderOverrides;
 use codex_config::test_support::CloudConfigBundleFixture;
 use codex_core::CodexThread;
 use codex_core::config::Config;
-use codex_core::config::ConfigBuilder;
 use codex_core::config::ConfigOverrides;
 pub use codex_core::test_support::TestCodexResponsesRequestKind;
 pub use codex_core::test_support::responses_metadata;
@@ -28,8 +26,8 @@ pub mod apps_test_server;
 pub mod context_snapshot;
 pub mod hooks;
 pub mod process;
-pub mod responses;
-pub mod streaming_sse;
+pub use codex_test_support::responses;
+pub use codex_test_support::streaming_sse;
 pub mod test_codex;
 pub mod test_codex_exec;
 mod test_environment;
@@ -207,14 +205,12 @@ pub async fn load_default_config_for_test_with_cloud_config_bundle(
     codex_home: &TempDir,
     cloud_config_bundle: CloudConfigBundleLoader,
 ) -> Config {
-    ConfigBuilder::default()
-        .loader_overrides(LoaderOverrides::without_managed_config_for_tests())
-        .codex_home(codex_home.path().to_path_buf())
-        .harness_overrides(default_test_overrides())
-        .cloud_config_bundle(cloud_config_bundle)
-        .build()
-        .await
-        .expect("defaults for test should always succeed")
+    codex_test_support::load_default_config_for_test_with_overrides(
+        codex_home,
+        default_test_overrides(),
+        cloud_config_bundle,
+    )
+    .await
 }
 
 pub fn managed_network_requirements_loader() -> CloudConfigBundleLoader {
@@ -259,16 +255,7 @@ pub fn find_codex_linux_sandbox_exe() -> Result<PathBuf, CargoBinError> {
     codex_utils_cargo_bin::cargo_bin("codex-linux-sandbox")
 }
 
-pub async fn wait_for_event<F>(
-    codex: &CodexThread,
-    predicate: F,
-) -> codex_protocol::protocol::EventMsg
-where
-    F: FnMut(&codex_protocol::protocol::EventMsg) -> bool,
-{
-    use tokio::time::Duration;
-    wait_for_event_with_timeout(codex, predicate, Duration::from_secs(1)).await
-}
+pub use codex_test_support::wait_for_event;
 
 /// Waits for a configured MCP server to finish startup and requires it to be ready.
 pub async fn wait_for_mcp_server(codex: &CodexThread, server_name: &str) -> anyhow::Result<()> {
@@ -328,35 +315,8 @@ pub async fn submit_thread_settings(
     }
 }
 
-pub async fn wait_for_event_match<T, F>(codex: &CodexThread, matcher: F) -> T
-where
-    F: Fn(&codex_protocol::protocol::EventMsg) -> Option<T>,
-{
-    let ev = wait_for_event(codex, |ev| matcher(ev).is_some()).await;
-    matcher(&ev).expect("EventMsg should match matcher predicate")
-}
-
-pub async fn wait_for_event_with_timeout<F>(
-    codex: &CodexThread,
-    mut predicate: F,
-    wait_time: tokio::time::Duration,
-) -> codex_protocol::protocol::EventMsg
-where
-    F: FnMut(&codex_protocol::protocol::EventMsg) -> bool,
-{
-    use tokio::time::Duration;
-    use tokio::time::timeout;
-    loop {
-        // Allow a bit more time to accommodate async startup work (e.g. config IO, tool discovery)
-        let ev = timeout(wait_time.max(Duration::from_secs(10)), codex.next_event())
-            .await
-            .expect("timeout waiting for event")
-            .expect("stream ended unexpectedly");
-        if predicate(&ev.msg) {
-            return ev.msg;
-        }
-    }
-}
+pub use codex_test_support::wait_for_event_match;
+pub use codex_test_support::wait_for_event_with_timeout;
 
 pub fn sandbox_env_var() -> &'static str {
     codex_core::spawn::CODEX_SANDBOX_ENV_VAR
@@ -127,6 +127,7 @@ async fn responses_stream_includes_subagent_header_on_review() {
         session_source.clone(),
         "test_originator".to_string(),
         config.model_verbosity,
+        codex_core::SamplingControls::default(),
         /*enable_request_compression*/ false,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
@@ -263,6 +264,7 @@ async fn responses_stream_includes_subagent_header_on_other() {
         session_source.clone(),
         "test_originator".to_string(),
         config.model_verbosity,
+        codex_core::SamplingControls::default(),
         /*enable_request_compression*/ false,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
@@ -385,6 +387,7 @@ async fn responses_respects_model_info_overrides_from_config() {
         session_source.clone(),
         "test_originator".to_string(),
         config.model_verbosity,
+        codex_core::SamplingControls::default(),
         /*enable_request_compression*/ false,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
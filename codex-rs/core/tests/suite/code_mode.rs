@@ -453,6 +453,7 @@ async fn run_code_mode_turn_with_rmcp_config(
                 disabled_reason: None,
                 startup_timeout_sec: Some(Duration::from_secs(10)),
                 tool_timeout_sec: None,
+                max_retries: None,
                 default_tools_approval_mode: None,
                 enabled_tools: None,
                 disabled_tools: None,
@@ -199,6 +199,7 @@ fn insert_rmcp_test_server(config: &mut Config, command: String, approval_mode:
             disabled_reason: None,
             startup_timeout_sec: Some(Duration::from_secs(10)),
             tool_timeout_sec: None,
+            max_retries: None,
             default_tools_approval_mode: Some(approval_mode),
             enabled_tools: None,
             disabled_tools: None,
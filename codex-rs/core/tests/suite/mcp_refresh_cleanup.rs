@@ -48,6 +48,7 @@ async fn refresh_keeps_superseded_mcp_server_alive_for_in_flight_calls() -> anyh
                     disabled_reason: None,
                     startup_timeout_sec: Some(Duration::from_secs(10)),
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: None,
                     disabled_tools: None,
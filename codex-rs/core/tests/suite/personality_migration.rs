@@ -85,10 +85,12 @@ async fn write_rollout_with_user_event(dir: &Path, thread_id: ThreadId) -> io::R
     };
     let meta_line = RolloutLine {
         timestamp: TEST_TIMESTAMP.to_string(),
+        turn: None,
         item: RolloutItem::SessionMeta(session_meta),
     };
     let user_event = RolloutLine {
         timestamp: TEST_TIMESTAMP.to_string(),
+        turn: None,
         item: RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
             client_id: None,
             message: "hello".to_string(),
@@ -139,6 +141,7 @@ async fn write_rollout_with_meta_only(dir: &Path, thread_id: ThreadId) -> io::Re
     };
     let meta_line = RolloutLine {
         timestamp: TEST_TIMESTAMP.to_string(),
+        turn: None,
         item: RolloutItem::SessionMeta(session_meta),
     };
 
@@ -273,6 +273,7 @@ struct TestMcpServerOptions {
     auth: McpServerAuth,
     supports_parallel_tool_calls: bool,
     tool_timeout_sec: Option<Duration>,
+    max_retries: Option<u32>,
 }
 
 impl Default for TestMcpServerOptions {
@@ -282,6 +283,7 @@ impl Default for TestMcpServerOptions {
             auth: McpServerAuth::default(),
             supports_parallel_tool_calls: false,
             tool_timeout_sec: None,
+            max_retries: None,
         }
     }
 }
@@ -328,6 +330,7 @@ fn insert_mcp_server(
             disabled_reason: None,
             startup_timeout_sec: Some(Duration::from_secs(10)),
             tool_timeout_sec: options.tool_timeout_sec,
+            max_retries: options.max_retries,
             default_tools_approval_mode: None,
             enabled_tools: None,
             disabled_tools: None,
@@ -1004,6 +1007,7 @@ async fn stdio_mcp_parallel_tool_calls_default_false_runs_serially() -> anyhow::
                 TestMcpServerOptions {
                     environment_id: remote_aware_environment_id(),
                     tool_timeout_sec: Some(Duration::from_secs(2)),
+                    max_retries: None,
                     ..Default::default()
                 },
             );
@@ -1145,6 +1149,7 @@ async fn stdio_mcp_read_only_tool_calls_run_concurrently_without_server_opt_in()
                 TestMcpServerOptions {
                     environment_id: remote_aware_environment_id(),
                     tool_timeout_sec: Some(Duration::from_secs(2)),
+                    max_retries: None,
                     ..Default::default()
                 },
             );
@@ -1236,6 +1241,7 @@ async fn stdio_mcp_parallel_tool_calls_opt_in_runs_concurrently() -> anyhow::Res
                     auth: Default::default(),
                     supports_parallel_tool_calls: true,
                     tool_timeout_sec: Some(Duration::from_secs(2)),
+                    max_retries: None,
                 },
             );
         })
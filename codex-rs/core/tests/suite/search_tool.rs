@@ -1108,6 +1108,7 @@ async fn tool_search_indexes_only_enabled_non_app_mcp_tools() -> Result<()> {
                     disabled_reason: None,
                     startup_timeout_sec: Some(Duration::from_secs(10)),
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: Some(vec!["echo".to_string(), "image".to_string()]),
                     disabled_tools: Some(vec!["image".to_string()]),
@@ -1235,6 +1236,7 @@ async fn tool_search_surfaced_mcp_tool_errors_are_returned_to_model() -> Result<
                     disabled_reason: None,
                     startup_timeout_sec: Some(Duration::from_secs(10)),
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: Some(vec!["echo".to_string()]),
                     disabled_tools: None,
@@ -1384,6 +1386,7 @@ async fn tool_search_uses_non_app_mcp_server_instructions_as_namespace_descripti
                     disabled_reason: None,
                     startup_timeout_sec: Some(Duration::from_secs(10)),
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: Some(vec!["echo".to_string()]),
                     disabled_tools: None,
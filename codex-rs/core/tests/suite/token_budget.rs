@@ -291,6 +291,7 @@ async fn token_budget_context_injects_plain_thread_hint_text() -> Result<()> {
                     disabled_reason: None,
                     startup_timeout_sec: Some(Duration::from_secs(10)),
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: None,
                     disabled_tools: None,
@@ -475,9 +475,9 @@ async fn user_shell_command_output_is_truncated_in_history() -> anyhow::Result<(
     let command_message = command_message.replace("\r\n", "\n");
 
     let head = (1..=69).map(|i| format!("{i}\n")).collect::<String>();
-    let tail = (352..=400).map(|i| format!("{i}\n")).collect::<String>();
+    let tail = (351..=400).map(|i| format!("{i}\n")).collect::<String>();
     let truncated_body = format!(
-        "Warning: truncated output (original token count: 373)\nTotal output lines: 400\n\n{head}70…273 tokens truncated…351\n{tail}"
+        "Warning: truncated output (original token count: 373)\nTotal output lines: 400\n\n{head}…274 tokens truncated…\n{tail}"
     );
     let escaped_command = escape(&command);
     let escaped_truncated_body = escape(&truncated_body);
@@ -12,7 +12,9 @@ use crate::NoiseRendezvousConnectProvider;
 use crate::client::LazyRemoteExecServerClient;
 use crate::client::http_client::ReqwestHttpClient;
 use crate::client_api::DEFAULT_REMOTE_EXEC_SERVER_CONNECT_TIMEOUT;
+use crate::client_api::DEFAULT_REMOTE_EXEC_SERVER_INITIALIZE_TIMEOUT;
 use crate::client_api::ExecServerTransportParams;
+use crate::client_api::StdioExecServerCommand;
 use crate::environment_provider::DefaultEnvironmentProvider;
 use crate::environment_provider::EnvironmentDefault;
 use crate::environment_provider::EnvironmentProvider;
@@ -60,6 +62,8 @@ pub struct EnvironmentManager {
 }
 
 pub const LOCAL_ENVIRONMENT_ID: &str = "local";
+/// Environment id used by [`EnvironmentManager::from_container_image`].
+pub const CONTAINER_ENVIRONMENT_ID: &str = "container";
 pub const REMOTE_ENVIRONMENT_ID: &str = "remote";
 
 impl EnvironmentManager {
@@ -111,6 +115,79 @@ impl EnvironmentManager {
         Self::from_snapshot(provider.snapshot().await?, local_runtime_paths)
     }
 
+    /// Builds a manager whose default environment runs every command inside
+    /// the given container image: the workspace is mounted read-write at its
+    /// host path, the container filesystem is read-only (with a tmpfs /tmp),
+    /// and the local codex binary is mounted so `codex exec-server` is
+    /// available in arbitrary images. Uses `docker` unless
+    /// `CODEX_CONTAINER_RUNTIME` selects another CLI-compatible runtime such
+    /// as `podman`. The local environment is excluded, so all shell and
+    /// exec_command tool calls route into the container while model traffic
+    /// stays on the host.
+    pub fn from_container_image(
+        image: &str,
+        workspace: &std::path::Path,
+        local_runtime_paths: Option<ExecServerRuntimePaths>,
+    ) -> Result<Self, ExecServerError> {
+        let image = image.trim();
+        if image.is_empty() {
+            return Err(ExecServerError::Protocol(
+                "container image cannot be empty".to_string(),
+            ));
+        }
+        let runtime =
+            std::env::var("CODEX_CONTAINER_RUNTIME").unwrap_or_else(|_| "docker".to_string());
+        let workspace = workspace.display().to_string();
+        let mut args = vec![
+            "run".to_string(),
+            "--rm".to_string(),
+            "-i".to_string(),
+            "--read-only".to_string(),
+            "--tmpfs".to_string(),
+            "/tmp".to_string(),
+            "-v".to_string(),
+            format!("{workspace}:{workspace}:rw"),
+            "-w".to_string(),
+            workspace,
+        ];
+        if let Some(codex_self_exe) = local_runtime_paths
+            .as_ref()
+            .map(|paths| paths.codex_self_exe.as_path())
+        {
+            args.push("-v".to_string());
+            args.push(format!(
+                "{}:/usr/local/bin/codex:ro",
+                codex_self_exe.display()
+            ));
+        }
+        args.push(image.to_string());
+        args.extend(
+            ["codex", "exec-server", "--listen", "stdio"]
+                .iter()
+                .map(ToString::to_string),
+        );
+
+        let transport = ExecServerTransportParams::StdioCommand {
+            command: StdioExecServerCommand {
+                program: runtime,
+                args,
+                env: HashMap::new(),
+                cwd: None,
+            },
+            initialize_timeout: DEFAULT_REMOTE_EXEC_SERVER_INITIALIZE_TIMEOUT,
+        };
+        let environment =
+            Environment::remote_with_transport(transport, local_runtime_paths.clone());
+        Self::from_snapshot(
+            EnvironmentProviderSnapshot {
+                environments: vec![(CONTAINER_ENVIRONMENT_ID.to_string(), environment)],
+                default: EnvironmentDefault::EnvironmentId(CONTAINER_ENVIRONMENT_ID.to_string()),
+                include_local: false,
+            },
+            local_runtime_paths,
+        )
+    }
+
     /// Builds a manager from the legacy environment-variable provider without
     /// reading user config files from `CODEX_HOME`.
     pub async fn from_env(
@@ -697,6 +774,44 @@ mod tests {
         assert_local_environment_unavailable(&manager);
     }
 
+    #[tokio::test]
+    async fn container_image_manager_defaults_to_container_environment() {
+        let manager = EnvironmentManager::from_container_image(
+            "ghcr.io/example/dev:latest",
+            std::path::Path::new("/workspace/project"),
+            Some(test_runtime_paths()),
+        )
+        .expect("container manager");
+
+        assert_eq!(
+            manager.default_environment_id(),
+            Some(CONTAINER_ENVIRONMENT_ID)
+        );
+        assert!(
+            manager
+                .default_environment()
+                .expect("container environment")
+                .is_remote()
+        );
+        assert_local_environment_unavailable(&manager);
+        assert!(manager.get_environment(LOCAL_ENVIRONMENT_ID).is_none());
+    }
+
+    #[tokio::test]
+    async fn container_image_manager_rejects_empty_image() {
+        let err = EnvironmentManager::from_container_image(
+            "  ",
+            std::path::Path::new("/workspace/project"),
+            Some(test_runtime_paths()),
+        )
+        .expect_err("empty image should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "exec-server protocol error: container image cannot be empty"
+        );
+    }
+
     #[tokio::test]
     async fn create_local_environment_does_not_connect() {
         let environment = Environment::create(/*exec_server_url*/ None, test_runtime_paths())
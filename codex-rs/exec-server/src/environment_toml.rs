@@ -150,6 +150,9 @@ fn parse_environment_toml(
         initialize_timeout_sec.unwrap_or(DEFAULT_REMOTE_EXEC_SERVER_INITIALIZE_TIMEOUT);
 
     let transport_params = match (url, program) {
+        (Some(url), None) if url.trim().starts_with("ssh://") => {
+            ssh_stdio_transport_params(&id, url.trim(), initialize_timeout)?
+        }
         (Some(url), None) => {
             let url = validate_websocket_url(url)?;
             ExecServerTransportParams::WebSocketUrl {
@@ -186,6 +189,64 @@ fn parse_environment_toml(
     Ok((id, transport_params))
 }
 
+/// Expands `ssh://[user@]host[:port]` sugar into the stdio transport that
+/// launches `codex exec-server --listen stdio` on the remote host. The SSH
+/// connection is pooled via ControlMaster/ControlPersist so successive tool
+/// calls and file operations reuse one authenticated connection, while model
+/// and provider traffic stays local.
+fn ssh_stdio_transport_params(
+    id: &str,
+    url: &str,
+    initialize_timeout: Duration,
+) -> Result<ExecServerTransportParams, ExecServerError> {
+    let destination = url
+        .strip_prefix("ssh://")
+        .unwrap_or(url)
+        .trim_end_matches('/');
+    if destination.is_empty() {
+        return Err(ExecServerError::Protocol(format!(
+            "environment `{id}` ssh url must name a destination, e.g. ssh://user@host"
+        )));
+    }
+    let (destination, port) = match destination.rsplit_once(':') {
+        Some((host, port))
+            if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) && !host.is_empty() =>
+        {
+            (host, Some(port))
+        }
+        _ => (destination, None),
+    };
+
+    let mut args = vec![
+        // Reuse one authenticated connection across tool calls.
+        "-o".to_string(),
+        "ControlMaster=auto".to_string(),
+        "-o".to_string(),
+        format!("ControlPath=~/.ssh/codex-exec-{id}-%C"),
+        "-o".to_string(),
+        "ControlPersist=60".to_string(),
+        // Never hang on interactive prompts inside the agent loop.
+        "-o".to_string(),
+        "BatchMode=yes".to_string(),
+    ];
+    if let Some(port) = port {
+        args.push("-p".to_string());
+        args.push(port.to_string());
+    }
+    args.push(destination.to_string());
+    args.push("codex exec-server --listen stdio".to_string());
+
+    Ok(ExecServerTransportParams::StdioCommand {
+        command: StdioExecServerCommand {
+            program: "ssh".to_string(),
+            args,
+            env: HashMap::new(),
+            cwd: None,
+        },
+        initialize_timeout,
+    })
+}
+
 fn normalize_stdio_cwd(
     id: &str,
     cwd: Option<PathBuf>,
@@ -676,6 +737,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ssh_url_expands_to_pooled_stdio_transport() {
+        let (id, transport) = parse_environment_toml(
+            EnvironmentToml {
+                id: "devbox".to_string(),
+                url: Some("ssh://dev@example.com:2222".to_string()),
+                ..Default::default()
+            },
+            /*config_dir*/ None,
+        )
+        .expect("ssh url should parse");
+
+        assert_eq!(id, "devbox");
+        let ExecServerTransportParams::StdioCommand { command, .. } = transport else {
+            panic!("expected stdio transport for ssh url");
+        };
+        assert_eq!(command.program, "ssh");
+        assert_eq!(
+            command.args,
+            vec![
+                "-o".to_string(),
+                "ControlMaster=auto".to_string(),
+                "-o".to_string(),
+                "ControlPath=~/.ssh/codex-exec-devbox-%C".to_string(),
+                "-o".to_string(),
+                "ControlPersist=60".to_string(),
+                "-o".to_string(),
+                "BatchMode=yes".to_string(),
+                "-p".to_string(),
+                "2222".to_string(),
+                "dev@example.com".to_string(),
+                "codex exec-server --listen stdio".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn ssh_url_without_destination_is_rejected() {
+        let err = parse_environment_toml(
+            EnvironmentToml {
+                id: "devbox".to_string(),
+                url: Some("ssh://".to_string()),
+                ..Default::default()
+            },
+            /*config_dir*/ None,
+        )
+        .expect_err("empty ssh destination should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "exec-server protocol error: environment `devbox` ssh url must name a destination, e.g. ssh://user@host"
+        );
+    }
+
     #[test]
     fn toml_provider_rejects_duplicate_ids() {
         let err = TomlEnvironmentProvider::new(EnvironmentsToml {
@@ -0,0 +1,202 @@
+//! Task definitions and summary output for `codex exec --batch`.
+//!
+//! A batch file is JSONL with one task per line:
+//!
+//! ```json
+//! {"prompt": "fix the lint errors", "cwd": "/repo/a", "images": ["shot.png"]}
+//! ```
+//!
+//! Each task runs in its own conversation; the summary collects per-task
+//! thread ids, completion statuses, and last agent messages.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub(crate) struct BatchTask {
+    pub prompt: String,
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+    #[serde(default)]
+    pub images: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum BatchTaskStatus {
+    Completed,
+    Failed,
+    Interrupted,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct BatchTaskResult {
+    pub index: usize,
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+    pub status: BatchTaskStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_message: Option<String>,
+    /// Worktree checkout used for this task, when `--batch-worktrees` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worktree_path: Option<PathBuf>,
+    /// Branch created for this task, when `--batch-worktrees` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worktree_branch: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct BatchSummary {
+    pub total: usize,
+    pub completed: usize,
+    pub failed: usize,
+    pub results: Vec<BatchTaskResult>,
+}
+
+impl BatchSummary {
+    pub(crate) fn new(results: Vec<BatchTaskResult>) -> Self {
+        let completed = results
+            .iter()
+            .filter(|result| result.status == BatchTaskStatus::Completed)
+            .count();
+        Self {
+            total: results.len(),
+            completed,
+            failed: results.len() - completed,
+            results,
+        }
+    }
+
+    pub(crate) fn any_failed(&self) -> bool {
+        self.failed > 0
+    }
+}
+
+pub(crate) fn load_batch_tasks(path: &Path) -> Result<Vec<BatchTask>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read batch file {}", path.display()))?;
+    let mut tasks = Vec::new();
+    for (line_number, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let task: BatchTask = serde_json::from_str(line).with_context(|| {
+            format!(
+                "invalid batch task on line {} of {}",
+                line_number + 1,
+                path.display()
+            )
+        })?;
+        tasks.push(task);
+    }
+    if tasks.is_empty() {
+        bail!("batch file {} contains no tasks", path.display());
+    }
+    Ok(tasks)
+}
+
+/// Write the summary JSON to `path`, or to stdout when no path is given.
+#[allow(clippy::print_stdout)]
+pub(crate) fn write_batch_summary(path: Option<&Path>, summary: &BatchSummary) -> Result<()> {
+    let json = serde_json::to_string_pretty(summary)?;
+    match path {
+        Some(path) => std::fs::write(path, format!("{json}\n"))
+            .with_context(|| format!("failed to write batch summary to {}", path.display()))?,
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_tasks_and_skips_blank_lines() {
+        let dir = tempfile::TempDir::new().expect("create temp dir");
+        let path = dir.path().join("tasks.jsonl");
+        std::fs::write(
+            &path,
+            r#"{"prompt":"task one"}
+
+{"prompt":"task two","cwd":"/repo","images":["a.png"]}
+"#,
+        )
+        .expect("write batch file");
+
+        let tasks = load_batch_tasks(&path).expect("load tasks");
+
+        assert_eq!(
+            tasks,
+            vec![
+                BatchTask {
+                    prompt: "task one".to_string(),
+                    cwd: None,
+                    images: Vec::new(),
+                },
+                BatchTask {
+                    prompt: "task two".to_string(),
+                    cwd: Some(PathBuf::from("/repo")),
+                    images: vec![PathBuf::from("a.png")],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_lines_are_reported_with_their_line_number() {
+        let dir = tempfile::TempDir::new().expect("create temp dir");
+        let path = dir.path().join("tasks.jsonl");
+        std::fs::write(&path, "{\"prompt\":\"ok\"}\nnot json\n").expect("write batch file");
+
+        let error = load_batch_tasks(&path).expect_err("load should fail");
+
+        assert!(error.to_string().contains("line 2"), "{error}");
+    }
+
+    #[test]
+    fn empty_batch_is_an_error() {
+        let dir = tempfile::TempDir::new().expect("create temp dir");
+        let path = dir.path().join("tasks.jsonl");
+        std::fs::write(&path, "\n\n").expect("write batch file");
+
+        assert!(load_batch_tasks(&path).is_err());
+    }
+
+    #[test]
+    fn summary_counts_completed_and_failed() {
+        let summary = BatchSummary::new(vec![
+            BatchTaskResult {
+                index: 0,
+                prompt: "a".to_string(),
+                thread_id: Some("t1".to_string()),
+                status: BatchTaskStatus::Completed,
+                last_message: Some("done".to_string()),
+                worktree_path: None,
+                worktree_branch: None,
+            },
+            BatchTaskResult {
+                index: 1,
+                prompt: "b".to_string(),
+                thread_id: None,
+                status: BatchTaskStatus::Failed,
+                last_message: None,
+                worktree_path: None,
+                worktree_branch: None,
+            },
+        ]);
+
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.failed, 1);
+        assert!(summary.any_failed());
+    }
+}
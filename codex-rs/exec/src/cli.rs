@@ -4,6 +4,8 @@ use clap::Parser;
 use clap::ValueEnum;
 use codex_utils_cli::CliConfigOverrides;
 use codex_utils_cli::SharedCliOptions;
+
+use crate::review_poster::ReviewPostProvider;
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -31,6 +33,67 @@ pub struct Cli {
     #[arg(long = "ephemeral", global = true, default_value_t = false)]
     pub ephemeral: bool,
 
+    /// Propose changes without applying them: patches are verified and
+    /// recorded but not written, and non-read-only commands are echoed as
+    /// "would run" without execution.
+    #[arg(long = "dry-run", global = true, default_value_t = false)]
+    pub dry_run: bool,
+
+    /// Apply a named role preset from the `[agents]` config section (e.g.
+    /// "planner", "implementer"), constraining instructions, tools, and
+    /// sandbox/approval policy for this run.
+    #[arg(long = "role", value_name = "NAME", global = true)]
+    pub role: Option<String>,
+
+    /// Run all commands inside this container image (docker/podman): the
+    /// workspace is mounted read-write, the rest of the container filesystem
+    /// is read-only.
+    #[arg(long = "container", value_name = "IMAGE", global = true)]
+    pub container: Option<String>,
+
+    /// Write each model request/response pair (tool schemas included,
+    /// secrets redacted) as numbered JSON files into this directory.
+    #[arg(long = "log-provider-traffic", value_name = "DIR", global = true)]
+    pub log_provider_traffic: Option<PathBuf>,
+
+    /// Attach the contents of a local file to the prompt (repeatable).
+    #[arg(long = "file", value_name = "PATH", global = true)]
+    pub file: Vec<PathBuf>,
+
+    /// Attach the fetched contents of a URL to the prompt (repeatable).
+    #[arg(long = "url", value_name = "URL", global = true)]
+    pub url: Vec<String>,
+
+    /// Sampling temperature forwarded to providers that support it.
+    #[arg(long = "temperature", value_name = "T", global = true)]
+    pub temperature: Option<f64>,
+
+    /// Nucleus sampling parameter forwarded to providers that support it.
+    #[arg(long = "top-p", value_name = "P", global = true)]
+    pub top_p: Option<f64>,
+
+    /// Deterministic sampling seed forwarded to providers that support it.
+    #[arg(long = "seed", value_name = "N", global = true)]
+    pub seed: Option<i64>,
+
+    /// Only print the final answer, warnings, and errors.
+    #[arg(
+        long = "quiet",
+        global = true,
+        default_value_t = false,
+        conflicts_with = "verbose"
+    )]
+    pub quiet: bool,
+
+    /// Include reasoning summaries and MCP payload sizes in the output.
+    #[arg(long = "verbose", global = true, default_value_t = false)]
+    pub verbose: bool,
+
+    /// Additionally write each proposed `apply_patch` call as a numbered
+    /// unified diff file into this directory.
+    #[arg(long = "patch-out", value_name = "DIR", global = true)]
+    pub patch_out: Option<PathBuf>,
+
     /// Do not load `$CODEX_HOME/config.toml`; auth still uses `CODEX_HOME`.
     #[arg(long = "ignore-user-config", global = true, default_value_t = false)]
     pub ignore_user_config: bool,
@@ -60,6 +123,34 @@ pub struct Cli {
     #[arg(long = "color", value_enum, default_value_t = Color::Auto)]
     pub color: Color,
 
+    /// Run a batch of prompts from a JSONL file (one
+    /// `{"prompt", "cwd"?, "images"?}` object per line), each task in its own
+    /// conversation, and emit a summary JSON when all tasks finish.
+    #[arg(long = "batch", value_name = "TASKS_JSONL", conflicts_with = "prompt")]
+    pub batch: Option<PathBuf>,
+
+    /// Write the batch summary JSON to this file instead of stdout.
+    #[arg(long = "batch-summary", value_name = "FILE", requires = "batch")]
+    pub batch_summary: Option<PathBuf>,
+
+    /// Run each batch task in its own temporary git worktree so concurrent
+    /// tasks never trample each other's working tree. The worktree path and
+    /// branch are reported in the batch summary.
+    #[arg(long = "batch-worktrees", default_value_t = false, requires = "batch")]
+    pub batch_worktrees: bool,
+
+    /// Listen on this Unix domain socket (or named pipe on Windows) for
+    /// control commands injected into the live session: one JSON line per
+    /// connection, e.g. {"op":"interrupt"}, {"op":"user_input","text":"..."},
+    /// or {"op":"status"}.
+    #[arg(long = "control-socket", value_name = "PATH", global = true)]
+    pub control_socket: Option<PathBuf>,
+
+    /// Print the fully merged configuration (secrets masked) as JSON and
+    /// exit without starting a session.
+    #[arg(long = "print-config-json", default_value_t = false, global = true)]
+    pub print_config_json: bool,
+
     /// Print events to stdout as JSONL.
     #[arg(
         long = "json",
@@ -295,6 +386,16 @@ pub struct ReviewArgs {
     /// Custom review instructions. If `-` is used, read from stdin.
     #[arg(value_name = "PROMPT", value_hint = clap::ValueHint::Other)]
     pub prompt: Option<String>,
+
+    /// Post each finding as a PR/MR comment via the given provider once the
+    /// review completes. Credentials and the repository come from the
+    /// provider's conventional environment variables.
+    #[arg(long = "post-review", value_enum, value_name = "PROVIDER")]
+    pub post_review: Option<ReviewPostProvider>,
+
+    /// Pull/merge request number to post review comments to.
+    #[arg(long = "pr", value_name = "NUMBER", requires = "post_review")]
+    pub pr: Option<u64>,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
@@ -0,0 +1,101 @@
+//! Unix-domain-socket control channel for a running exec session.
+//!
+//! With `--control-socket <PATH>`, external tools can drive the live session:
+//! each connection sends one JSON line and receives one JSON reply.
+//!
+//! ```json
+//! {"op": "interrupt"}
+//! {"op": "user_input", "text": "also fix the tests"}
+//! {"op": "status"}
+//! ```
+//!
+//! Commands are forwarded into the exec event loop, which owns the app-server
+//! client, so injection follows the same paths as ctrl-c and prompts.
+
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+use tracing::warn;
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub(crate) enum ControlCommand {
+    /// Interrupt the active turn.
+    Interrupt,
+    /// Queue additional user input on the primary thread.
+    UserInput { text: String },
+    /// Report the primary thread and turn ids.
+    Status,
+}
+
+pub(crate) struct ControlRequest {
+    pub command: ControlCommand,
+    /// One-line JSON reply written back to the connection.
+    pub respond: oneshot::Sender<Value>,
+}
+
+/// Bind the control socket and forward parsed commands to the event loop.
+/// Returns the receiving end consumed by the exec select loop.
+pub(crate) async fn spawn_control_socket(
+    path: std::path::PathBuf,
+) -> anyhow::Result<mpsc::UnboundedReceiver<ControlRequest>> {
+    use tokio::io::AsyncBufReadExt;
+    use tokio::io::AsyncWriteExt;
+
+    if codex_uds::is_stale_socket_path(&path)
+        .await
+        .unwrap_or(false)
+    {
+        let _ = tokio::fs::remove_file(&path).await;
+    }
+    let mut listener = codex_uds::UnixListener::bind(&path).await?;
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        loop {
+            let stream = match listener.accept().await {
+                Ok(stream) => stream,
+                Err(err) => {
+                    warn!("control socket accept failed: {err}");
+                    break;
+                }
+            };
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let (read_half, mut write_half) = tokio::io::split(stream);
+                let mut lines = tokio::io::BufReader::new(read_half).lines();
+                let line = match lines.next_line().await {
+                    Ok(Some(line)) => line,
+                    Ok(None) => return,
+                    Err(err) => {
+                        warn!("control socket read failed: {err}");
+                        return;
+                    }
+                };
+                let reply = match serde_json::from_str::<ControlCommand>(&line) {
+                    Ok(command) => {
+                        let (respond, reply_rx) = oneshot::channel();
+                        if tx.send(ControlRequest { command, respond }).is_err() {
+                            serde_json::json!({"ok": false, "error": "session is shutting down"})
+                        } else {
+                            reply_rx.await.unwrap_or_else(|_| {
+                                serde_json::json!({"ok": false, "error": "session is shutting down"})
+                            })
+                        }
+                    }
+                    Err(err) => {
+                        serde_json::json!({"ok": false, "error": format!("invalid control command: {err}")})
+                    }
+                };
+                let mut reply = reply.to_string();
+                reply.push('\n');
+                if let Err(err) = write_half.write_all(reply.as_bytes()).await {
+                    warn!("control socket write failed: {err}");
+                }
+            });
+        }
+    });
+
+    Ok(rx)
+}
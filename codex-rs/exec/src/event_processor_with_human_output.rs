@@ -20,6 +20,17 @@ use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
 use crate::event_processor::handle_last_message;
 
+/// Verbosity tier for human output, independent of `RUST_LOG`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum HumanOutputTier {
+    /// Only the final answer, warnings, and errors.
+    Quiet,
+    #[default]
+    Normal,
+    /// Additionally force reasoning summaries and MCP payload sizes.
+    Verbose,
+}
+
 pub(crate) struct EventProcessorWithHumanOutput {
     bold: Style,
     cyan: Style,
@@ -31,11 +42,26 @@ pub(crate) struct EventProcessorWithHumanOutput {
     yellow: Style,
     show_agent_reasoning: bool,
     show_raw_agent_reasoning: bool,
+    redact_secrets: bool,
     last_message_path: Option<PathBuf>,
     final_message: Option<String>,
     final_message_rendered: bool,
     emit_final_message_on_shutdown: bool,
     last_total_token_usage: Option<ThreadTokenUsage>,
+    latest_plan: Option<Vec<codex_app_server_protocol::TurnPlanStep>>,
+    changed_files: Vec<(String, codex_app_server_protocol::PatchChangeKind)>,
+    latest_turn_diff: Option<String>,
+    tier: HumanOutputTier,
+    ascii_only: bool,
+    fold: Option<FoldState>,
+}
+
+/// Tracks a run of consecutive Read/Search/ListFiles commands on the same
+/// target so long exploration phases collapse into one summarized line.
+struct FoldState {
+    label: &'static str,
+    target: String,
+    count: usize,
 }
 
 impl EventProcessorWithHumanOutput {
@@ -43,30 +69,111 @@ impl EventProcessorWithHumanOutput {
         with_ansi: bool,
         config: &Config,
         last_message_path: Option<PathBuf>,
+        tier: HumanOutputTier,
     ) -> Self {
         let style = |styled: Style, plain: Style| if with_ansi { styled } else { plain };
+        // Colorblind-safe output swaps the red/green success/failure pair for
+        // blue/magenta, which stay distinguishable under the common
+        // red-green color vision deficiencies.
+        let success = if config.colorblind_output {
+            Style::new().blue()
+        } else {
+            Style::new().green()
+        };
+        let failure = if config.colorblind_output {
+            Style::new().magenta()
+        } else {
+            Style::new().red()
+        };
         Self {
             bold: style(Style::new().bold(), Style::new()),
             cyan: style(Style::new().cyan(), Style::new()),
             dimmed: style(Style::new().dimmed(), Style::new()),
-            green: style(Style::new().green(), Style::new()),
+            green: style(success, Style::new()),
             italic: style(Style::new().italic(), Style::new()),
             magenta: style(Style::new().magenta(), Style::new()),
-            red: style(Style::new().red(), Style::new()),
+            red: style(failure, Style::new()),
             yellow: style(Style::new().yellow(), Style::new()),
-            show_agent_reasoning: !config.hide_agent_reasoning,
+            show_agent_reasoning: matches!(tier, HumanOutputTier::Verbose)
+                || !config.hide_agent_reasoning,
             show_raw_agent_reasoning: config.show_raw_agent_reasoning,
+            redact_secrets: config.redact_secrets,
             last_message_path,
             final_message: None,
             final_message_rendered: false,
             emit_final_message_on_shutdown: false,
             last_total_token_usage: None,
+            latest_plan: None,
+            changed_files: Vec::new(),
+            latest_turn_diff: None,
+            tier,
+            ascii_only: config.ascii_output,
+            fold: None,
+        }
+    }
+
+    fn render_plan(&self, plan: &[codex_app_server_protocol::TurnPlanStep]) {
+        let completed = plan
+            .iter()
+            .filter(|step| {
+                matches!(
+                    step.status,
+                    codex_app_server_protocol::TurnPlanStepStatus::Completed
+                )
+            })
+            .count();
+        eprintln!(
+            "{}",
+            format!("plan ({completed}/{} done):", plan.len()).style(self.bold)
+        );
+        for step in plan {
+            match step.status {
+                codex_app_server_protocol::TurnPlanStepStatus::Completed => {
+                    let marker = if self.ascii_only { "[x]" } else { "✓" };
+                    eprintln!("  {} {}", marker.style(self.green), step.step);
+                }
+                codex_app_server_protocol::TurnPlanStepStatus::InProgress => {
+                    let marker = if self.ascii_only { ">" } else { "→" };
+                    eprintln!("  {} {}", marker.style(self.cyan), step.step);
+                }
+                codex_app_server_protocol::TurnPlanStepStatus::Pending => {
+                    let marker = if self.ascii_only { "[ ]" } else { "•" };
+                    eprintln!(
+                        "  {} {}",
+                        marker.style(self.dimmed),
+                        step.step.style(self.dimmed)
+                    );
+                }
+            }
         }
     }
 
-    fn render_item_started(&self, item: &ThreadItem) {
+    fn render_item_started(&mut self, item: &ThreadItem) {
+        if self.tier == HumanOutputTier::Quiet {
+            return;
+        }
+        if !matches!(item, ThreadItem::CommandExecution { .. }) {
+            self.flush_fold();
+        }
         match item {
             ThreadItem::CommandExecution { command, cwd, .. } => {
+                if let Some((label, target)) = foldable_exploration_target(command) {
+                    if let Some(fold) = self.fold.as_mut()
+                        && fold.label == label
+                        && fold.target == target
+                    {
+                        fold.count += 1;
+                        return;
+                    }
+                    self.flush_fold();
+                    self.fold = Some(FoldState {
+                        label,
+                        target,
+                        count: 1,
+                    });
+                } else {
+                    self.flush_fold();
+                }
                 eprintln!(
                     "{}\n{} in {cwd}",
                     "exec".style(self.italic).style(self.magenta),
@@ -94,9 +201,40 @@ impl EventProcessorWithHumanOutput {
         }
     }
 
+    fn redact_if_enabled(&self, text: String) -> String {
+        if self.redact_secrets {
+            codex_secrets::redact_secrets(text)
+        } else {
+            text
+        }
+    }
+
     fn render_item_completed(&mut self, item: ThreadItem) {
+        if let ThreadItem::CommandExecution { command, .. } = &item
+            && let Some(fold) = &self.fold
+            && fold.count > 1
+            && foldable_exploration_target(command)
+                .is_some_and(|(label, target)| label == fold.label && target == fold.target)
+        {
+            // Detail for repeated exploration reads is summarized when the
+            // run ends; the rollout keeps the full record.
+            return;
+        }
+        if !matches!(item, ThreadItem::CommandExecution { .. }) {
+            self.flush_fold();
+        }
+        if self.tier == HumanOutputTier::Quiet {
+            // Quiet mode only surfaces the final answer (printed on
+            // shutdown) and errors.
+            if let ThreadItem::AgentMessage { text, .. } = item {
+                self.final_message = Some(self.redact_if_enabled(text));
+                self.final_message_rendered = false;
+            }
+            return;
+        }
         match item {
             ThreadItem::AgentMessage { text, .. } => {
+                let text = self.redact_if_enabled(text);
                 eprintln!(
                     "{}\n{}",
                     "codex".style(self.italic).style(self.magenta),
@@ -157,7 +295,7 @@ impl EventProcessorWithHumanOutput {
                 if let Some(output) = aggregated_output
                     && !output.trim().is_empty()
                 {
-                    eprintln!("{output}");
+                    eprintln!("{}", self.redact_if_enabled(output));
                 }
             }
             ThreadItem::FileChange {
@@ -172,6 +310,9 @@ impl EventProcessorWithHumanOutput {
                 eprintln!("{} {}", "patch:".style(self.bold), status_text);
                 for change in changes {
                     eprintln!("{}", change.path.style(self.dimmed));
+                    if matches!(status, PatchApplyStatus::Completed) {
+                        self.changed_files.push((change.path, change.kind));
+                    }
                 }
             }
             ThreadItem::McpToolCall {
@@ -179,6 +320,8 @@ impl EventProcessorWithHumanOutput {
                 tool,
                 status,
                 error,
+                arguments,
+                result,
                 ..
             } => {
                 let status_text = match status {
@@ -192,6 +335,21 @@ impl EventProcessorWithHumanOutput {
                     format!("{server}/{tool}").style(self.cyan),
                     format!("({status_text})").style(self.dimmed)
                 );
+                if self.tier == HumanOutputTier::Verbose {
+                    let arguments_bytes = serde_json::to_string(&arguments)
+                        .map(|json| json.len())
+                        .unwrap_or(0);
+                    let result_bytes = result
+                        .as_deref()
+                        .and_then(|result| serde_json::to_string(result).ok())
+                        .map(|json| json.len())
+                        .unwrap_or(0);
+                    eprintln!(
+                        "{}",
+                        format!("payload: {arguments_bytes} B args, {result_bytes} B result")
+                            .style(self.dimmed)
+                    );
+                }
                 if let Some(error) = error {
                     eprintln!("{}", error.message.style(self.red));
                 }
@@ -214,6 +372,9 @@ impl EventProcessor for EventProcessorWithHumanOutput {
         prompt: &str,
         session_configured_event: &SessionConfiguredEvent,
     ) {
+        if self.tier == HumanOutputTier::Quiet {
+            return;
+        }
         const VERSION: &str = env!("CARGO_PKG_VERSION");
         eprintln!("OpenAI Codex v{VERSION}\n--------");
         for (key, value) in config_summary_entries(config, session_configured_event) {
@@ -259,6 +420,9 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                 CodexStatus::Running
             }
             ServerNotification::HookStarted(notification) => {
+                if self.tier == HumanOutputTier::Quiet {
+                    return CodexStatus::Running;
+                }
                 eprintln!(
                     "{} {}",
                     "hook:".style(self.bold),
@@ -267,6 +431,9 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                 CodexStatus::Running
             }
             ServerNotification::HookCompleted(notification) => {
+                if self.tier == HumanOutputTier::Quiet {
+                    return CodexStatus::Running;
+                }
                 eprintln!(
                     "{} {} {:?}",
                     "hook:".style(self.bold),
@@ -284,6 +451,9 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                 CodexStatus::Running
             }
             ServerNotification::ModelRerouted(notification) => {
+                if self.tier == HumanOutputTier::Quiet {
+                    return CodexStatus::Running;
+                }
                 eprintln!(
                     "{} {} -> {}",
                     "model rerouted:".style(self.yellow).style(self.bold),
@@ -297,67 +467,63 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                 self.last_total_token_usage = Some(notification.token_usage);
                 CodexStatus::Running
             }
-            ServerNotification::TurnCompleted(notification) => match notification.turn.status {
-                TurnStatus::Completed => {
-                    let rendered_message = self
-                        .final_message_rendered
-                        .then(|| self.final_message.clone())
-                        .flatten();
-                    if let Some(final_message) =
-                        final_message_from_turn_items(notification.turn.items.as_slice())
-                    {
-                        self.final_message_rendered =
-                            rendered_message.as_deref() == Some(final_message.as_str());
-                        self.final_message = Some(final_message);
+            ServerNotification::TurnCompleted(notification) => {
+                self.flush_fold();
+                match notification.turn.status {
+                    TurnStatus::Completed => {
+                        self.print_turn_diff_stat();
+                        let rendered_message = self
+                            .final_message_rendered
+                            .then(|| self.final_message.clone())
+                            .flatten();
+                        if let Some(final_message) =
+                            final_message_from_turn_items(notification.turn.items.as_slice())
+                        {
+                            self.final_message_rendered =
+                                rendered_message.as_deref() == Some(final_message.as_str());
+                            self.final_message = Some(final_message);
+                        }
+                        self.emit_final_message_on_shutdown = true;
+                        CodexStatus::InitiateShutdown
                     }
-                    self.emit_final_message_on_shutdown = true;
-                    CodexStatus::InitiateShutdown
-                }
-                TurnStatus::Failed => {
-                    self.final_message = None;
-                    self.final_message_rendered = false;
-                    self.emit_final_message_on_shutdown = false;
-                    if let Some(error) = notification.turn.error {
-                        eprintln!("{} {}", "ERROR:".style(self.red).style(self.bold), error);
+                    TurnStatus::Failed => {
+                        self.final_message = None;
+                        self.final_message_rendered = false;
+                        self.emit_final_message_on_shutdown = false;
+                        if let Some(error) = notification.turn.error {
+                            eprintln!("{} {}", "ERROR:".style(self.red).style(self.bold), error);
+                        }
+                        CodexStatus::InitiateShutdown
                     }
-                    CodexStatus::InitiateShutdown
-                }
-                TurnStatus::Interrupted => {
-                    self.final_message = None;
-                    self.final_message_rendered = false;
-                    self.emit_final_message_on_shutdown = false;
-                    eprintln!("{}", "turn interrupted".style(self.dimmed));
-                    CodexStatus::InitiateShutdown
+                    TurnStatus::Interrupted => {
+                        self.final_message = None;
+                        self.final_message_rendered = false;
+                        self.emit_final_message_on_shutdown = false;
+                        eprintln!("{}", "turn interrupted".style(self.dimmed));
+                        CodexStatus::InitiateShutdown
+                    }
+                    TurnStatus::InProgress => CodexStatus::Running,
                 }
-                TurnStatus::InProgress => CodexStatus::Running,
-            },
+            }
             ServerNotification::TurnDiffUpdated(notification) => {
+                if self.tier == HumanOutputTier::Quiet {
+                    return CodexStatus::Running;
+                }
                 if !notification.diff.trim().is_empty() {
                     eprintln!("{}", notification.diff);
+                    self.latest_turn_diff = Some(notification.diff);
                 }
                 CodexStatus::Running
             }
             ServerNotification::TurnPlanUpdated(notification) => {
+                if self.tier == HumanOutputTier::Quiet {
+                    return CodexStatus::Running;
+                }
                 if let Some(explanation) = notification.explanation {
                     eprintln!("{}", explanation.style(self.italic));
                 }
-                for step in notification.plan {
-                    match step.status {
-                        codex_app_server_protocol::TurnPlanStepStatus::Completed => {
-                            eprintln!("  {} {}", "✓".style(self.green), step.step);
-                        }
-                        codex_app_server_protocol::TurnPlanStepStatus::InProgress => {
-                            eprintln!("  {} {}", "→".style(self.cyan), step.step);
-                        }
-                        codex_app_server_protocol::TurnPlanStepStatus::Pending => {
-                            eprintln!(
-                                "  {} {}",
-                                "•".style(self.dimmed),
-                                step.step.style(self.dimmed)
-                            );
-                        }
-                    }
-                }
+                self.render_plan(&notification.plan);
+                self.latest_plan = Some(notification.plan);
                 CodexStatus::Running
             }
             ServerNotification::TurnStarted(_) => CodexStatus::Running,
@@ -365,6 +531,54 @@ impl EventProcessor for EventProcessorWithHumanOutput {
         }
     }
 
+    /// Prints the pending fold summary, e.g. `Read foo.rs (4 slices)`.
+    fn flush_fold(&mut self) {
+        let Some(fold) = self.fold.take() else {
+            return;
+        };
+        if fold.count > 1 {
+            let noun = if fold.label == "Read" {
+                "slices"
+            } else {
+                "times"
+            };
+            eprintln!(
+                "{}",
+                format!("{} {} ({} {noun})", fold.label, fold.target, fold.count)
+                    .style(self.dimmed)
+            );
+        }
+    }
+
+    /// Prints a compact `N files changed, +A −D` line with a per-file
+    /// breakdown for the just-finished turn, computed from the aggregated
+    /// turn diff, so headless logs mirror the TUI's diff summary.
+    fn print_turn_diff_stat(&mut self) {
+        let Some(diff) = self.latest_turn_diff.take() else {
+            return;
+        };
+        let stats = diff_stat_per_file(&diff);
+        if stats.is_empty() {
+            return;
+        }
+        let files = stats.len();
+        let added: usize = stats.iter().map(|stat| stat.added).sum();
+        let removed: usize = stats.iter().map(|stat| stat.removed).sum();
+        let noun = if files == 1 { "file" } else { "files" };
+        let minus = if self.ascii_only { "-" } else { "−" };
+        eprintln!(
+            "{}",
+            format!("{files} {noun} changed, +{added} {minus}{removed}").style(self.bold)
+        );
+        for stat in stats {
+            eprintln!(
+                "  {} {}",
+                format!("+{} {minus}{}", stat.added, stat.removed).style(self.dimmed),
+                stat.path
+            );
+        }
+    }
+
     fn process_warning(&mut self, message: String) -> CodexStatus {
         eprintln!(
             "{} {message}",
@@ -374,13 +588,40 @@ impl EventProcessor for EventProcessorWithHumanOutput {
     }
 
     fn print_final_output(&mut self) {
+        self.flush_fold();
         if self.emit_final_message_on_shutdown
             && let Some(path) = self.last_message_path.as_deref()
         {
             handle_last_message(self.final_message.as_deref(), path);
         }
 
-        if let Some(usage) = &self.last_total_token_usage {
+        if let Some(plan) = self.latest_plan.take()
+            && self.tier != HumanOutputTier::Quiet
+        {
+            self.render_plan(&plan);
+        }
+
+        if !self.changed_files.is_empty() && self.tier != HumanOutputTier::Quiet {
+            let mut changed_files = std::mem::take(&mut self.changed_files);
+            changed_files.sort_by(|(a, _), (b, _)| a.cmp(b));
+            changed_files.dedup_by(|(a, _), (b, _)| a == b);
+            eprintln!(
+                "{}",
+                format!("files changed ({}):", changed_files.len()).style(self.bold)
+            );
+            for (path, kind) in changed_files {
+                let label = match kind {
+                    codex_app_server_protocol::PatchChangeKind::Add => "A",
+                    codex_app_server_protocol::PatchChangeKind::Delete => "D",
+                    codex_app_server_protocol::PatchChangeKind::Update { .. } => "M",
+                };
+                eprintln!("  {label} {path}");
+            }
+        }
+
+        if let Some(usage) = &self.last_total_token_usage
+            && self.tier != HumanOutputTier::Quiet
+        {
             eprintln!(
                 "{}\n{}",
                 "tokens used".style(self.dimmed),
@@ -432,14 +673,32 @@ fn config_summary_entries(
             "approval",
             config.permissions.approval_policy.value().to_string(),
         ),
-        (
-            "sandbox",
-            summarize_permission_profile(
+        ("parallel", {
+            let parallel = &config.parallel;
+            if !parallel.enabled {
+                "disabled".to_string()
+            } else {
+                let cap = parallel
+                    .max_concurrent_calls
+                    .map(|calls| calls.to_string())
+                    .unwrap_or_else(|| "unlimited".to_string());
+                format!(
+                    "enabled (max {cap} concurrent, min delay {}ms)",
+                    parallel.min_delay_ms
+                )
+            }
+        }),
+        ("sandbox", {
+            let mut summary = summarize_permission_profile(
                 &permission_profile,
                 &config.cwd,
                 config.effective_workspace_roots().as_slice(),
-            ),
-        ),
+            );
+            if codex_core::util::is_termux() {
+                summary.push_str(" (degraded: no OS sandbox backend under Termux)");
+            }
+            summary
+        }),
     ];
     if config.model_provider.wire_api == WireApi::Responses {
         entries.push((
@@ -521,6 +780,64 @@ fn should_print_final_message_to_tty(
     final_message.is_some() && !final_message_rendered && stdout_is_terminal && stderr_is_terminal
 }
 
+/// Classifies a command as a foldable exploration step: a single-file read,
+/// a search, or a listing, returning the display label and target.
+fn foldable_exploration_target(command: &str) -> Option<(&'static str, String)> {
+    let tokens = shlex::split(command)?;
+    let parsed = codex_shell_command::parse_command::parse_command(&tokens);
+    match parsed.as_slice() {
+        [codex_protocol::parse_command::ParsedCommand::Read { name, .. }] => {
+            Some(("Read", name.clone()))
+        }
+        [codex_protocol::parse_command::ParsedCommand::Search { query, path, .. }] => {
+            let target = match (query, path) {
+                (Some(query), Some(path)) => format!("{query} in {path}"),
+                (Some(query), None) => query.clone(),
+                (None, Some(path)) => path.clone(),
+                (None, None) => return None,
+            };
+            Some(("Search", target))
+        }
+        [codex_protocol::parse_command::ParsedCommand::ListFiles { path, .. }] => {
+            Some(("List", path.clone()?))
+        }
+        _ => None,
+    }
+}
+
+struct FileDiffStat {
+    path: String,
+    added: usize,
+    removed: usize,
+}
+
+/// Per-file added/removed line counts parsed from a unified diff.
+fn diff_stat_per_file(diff: &str) -> Vec<FileDiffStat> {
+    let mut stats: Vec<FileDiffStat> = Vec::new();
+    for line in diff.lines() {
+        if let Some(rest) = line.strip_prefix("diff --git a/") {
+            // `diff --git a/<old> b/<new>`: report the post-change path.
+            let path = rest
+                .split_once(" b/")
+                .map(|(_, new_path)| new_path)
+                .unwrap_or(rest)
+                .to_string();
+            stats.push(FileDiffStat {
+                path,
+                added: 0,
+                removed: 0,
+            });
+        } else if let Some(stat) = stats.last_mut() {
+            if line.starts_with('+') && !line.starts_with("+++") {
+                stat.added += 1;
+            } else if line.starts_with('-') && !line.starts_with("---") {
+                stat.removed += 1;
+            }
+        }
+    }
+    stats
+}
+
 #[cfg(test)]
 #[path = "event_processor_with_human_output_tests.rs"]
 mod tests;
@@ -20,7 +20,9 @@ use owo_colors::Style;
 use pretty_assertions::assert_eq;
 
 use super::EventProcessorWithHumanOutput;
+use super::HumanOutputTier;
 use super::config_summary_entries;
+use super::diff_stat_per_file;
 use super::final_message_from_turn_items;
 use super::reasoning_text;
 use super::should_print_final_message_to_stdout;
@@ -303,6 +305,12 @@ fn turn_completed_recovers_final_message_from_turn_items() {
         final_message_rendered: false,
         emit_final_message_on_shutdown: false,
         last_total_token_usage: None,
+        latest_plan: None,
+        changed_files: Vec::new(),
+        latest_turn_diff: None,
+        tier: HumanOutputTier::Normal,
+        ascii_only: false,
+        fold: None,
     };
 
     let status = processor.process_server_notification(ServerNotification::TurnCompleted(
@@ -351,6 +359,12 @@ fn turn_completed_overwrites_stale_final_message_from_turn_items() {
         final_message_rendered: true,
         emit_final_message_on_shutdown: false,
         last_total_token_usage: None,
+        latest_plan: None,
+        changed_files: Vec::new(),
+        latest_turn_diff: None,
+        tier: HumanOutputTier::Normal,
+        ascii_only: false,
+        fold: None,
     };
 
     let status = processor.process_server_notification(ServerNotification::TurnCompleted(
@@ -400,6 +414,12 @@ fn turn_completed_preserves_streamed_final_message_when_turn_items_are_empty() {
         final_message_rendered: false,
         emit_final_message_on_shutdown: false,
         last_total_token_usage: None,
+        latest_plan: None,
+        changed_files: Vec::new(),
+        latest_turn_diff: None,
+        tier: HumanOutputTier::Normal,
+        ascii_only: false,
+        fold: None,
     };
 
     let status = processor.process_server_notification(ServerNotification::TurnCompleted(
@@ -444,6 +464,12 @@ fn turn_failed_clears_stale_final_message() {
         final_message_rendered: true,
         emit_final_message_on_shutdown: true,
         last_total_token_usage: None,
+        latest_plan: None,
+        changed_files: Vec::new(),
+        latest_turn_diff: None,
+        tier: HumanOutputTier::Normal,
+        ascii_only: false,
+        fold: None,
     };
 
     let status = processor.process_server_notification(ServerNotification::TurnCompleted(
@@ -489,6 +515,12 @@ fn turn_interrupted_clears_stale_final_message() {
         final_message_rendered: true,
         emit_final_message_on_shutdown: true,
         last_total_token_usage: None,
+        latest_plan: None,
+        changed_files: Vec::new(),
+        latest_turn_diff: None,
+        tier: HumanOutputTier::Normal,
+        ascii_only: false,
+        fold: None,
     };
 
     let status = processor.process_server_notification(ServerNotification::TurnCompleted(
@@ -515,3 +547,32 @@ fn turn_interrupted_clears_stale_final_message() {
     assert!(!processor.final_message_rendered);
     assert!(!processor.emit_final_message_on_shutdown);
 }
+
+#[test]
+fn diff_stat_counts_per_file_additions_and_removals() {
+    let diff = "diff --git a/src/a.rs b/src/a.rs\n\
+--- a/src/a.rs\n\
++++ b/src/a.rs\n\
+@@ -1,2 +1,3 @@\n\
+ context\n\
++added one\n\
++added two\n\
+-removed\n\
+diff --git a/src/b.rs b/src/b.rs\n\
+--- a/src/b.rs\n\
++++ b/src/b.rs\n\
+@@ -1 +1 @@\n\
++only add\n";
+
+    let stats = diff_stat_per_file(diff);
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].path, "src/a.rs");
+    assert_eq!((stats[0].added, stats[0].removed), (2, 1));
+    assert_eq!(stats[1].path, "src/b.rs");
+    assert_eq!((stats[1].added, stats[1].removed), (1, 0));
+}
+
+#[test]
+fn diff_stat_is_empty_for_non_diff_text() {
+    assert!(diff_stat_per_file("no changes here").is_empty());
+}
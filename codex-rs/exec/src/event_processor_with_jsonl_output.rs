@@ -42,6 +42,7 @@ use crate::exec_events::McpToolCallStatus as ExecMcpToolCallStatus;
 use crate::exec_events::PatchApplyStatus as ExecPatchApplyStatus;
 use crate::exec_events::PatchChangeKind as ExecPatchChangeKind;
 use crate::exec_events::ReasoningItem;
+
 use crate::exec_events::ThreadErrorEvent;
 use crate::exec_events::ThreadEvent;
 use crate::exec_events::ThreadItem as ExecThreadItem;
@@ -57,6 +58,7 @@ use crate::exec_events::WebSearchItem;
 
 pub struct EventProcessorWithJsonOutput {
     last_message_path: Option<PathBuf>,
+    redact_secrets: bool,
     next_item_id: AtomicU64,
     raw_to_exec_item_id: HashMap<String, String>,
     running_todo_list: Option<RunningTodoList>,
@@ -82,6 +84,7 @@ impl EventProcessorWithJsonOutput {
     pub fn new(last_message_path: Option<PathBuf>) -> Self {
         Self {
             last_message_path,
+            redact_secrets: false,
             next_item_id: AtomicU64::new(0),
             raw_to_exec_item_id: HashMap::new(),
             running_todo_list: None,
@@ -92,6 +95,12 @@ impl EventProcessorWithJsonOutput {
         }
     }
 
+    /// Enable best-effort secret redaction of emitted event JSON.
+    pub fn with_secret_redaction(mut self, redact_secrets: bool) -> Self {
+        self.redact_secrets = redact_secrets;
+        self
+    }
+
     pub fn final_message(&self) -> Option<&str> {
         self.final_message.as_deref()
     }
@@ -102,16 +111,19 @@ impl EventProcessorWithJsonOutput {
 
     #[allow(clippy::print_stdout)]
     fn emit(&self, event: ThreadEvent) {
-        println!(
-            "{}",
-            serde_json::to_string(&event).unwrap_or_else(|err| {
-                json!({
-                    "type": "error",
-                    "message": format!("failed to serialize exec json event: {err}"),
-                })
-                .to_string()
+        let line = serde_json::to_string(&event).unwrap_or_else(|err| {
+            json!({
+                "type": "error",
+                "message": format!("failed to serialize exec json event: {err}"),
             })
-        );
+            .to_string()
+        });
+        let line = if self.redact_secrets {
+            codex_secrets::redact_secrets(line)
+        } else {
+            line
+        };
+        println!("{line}");
     }
 
     fn usage_from_last_total(&self) -> Usage {
@@ -293,6 +305,12 @@ impl EventProcessorWithJsonOutput {
                     },
                 }),
             }),
+            ThreadItem::ExitedReviewMode { review, .. } => Some(ExecThreadItem {
+                id: make_id(),
+                details: ThreadItemDetails::ReviewOutput(
+                    crate::review_poster::review_output_from_review_text(&review),
+                ),
+            }),
             ThreadItem::WebSearch(item) => Some(ExecThreadItem {
                 id: make_id(),
                 details: ThreadItemDetails::WebSearch(WebSearchItem {
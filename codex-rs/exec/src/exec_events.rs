@@ -3,6 +3,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use ts_rs::TS;
 
 /// Top-level JSONL events emitted by codex exec
@@ -127,6 +128,8 @@ pub enum ThreadItemDetails {
     TodoList(TodoListItem),
     /// Describes a non-fatal error surfaced as an item.
     Error(ErrorItem),
+    /// Structured findings produced when a review session completes.
+    ReviewOutput(ReviewOutputItem),
 }
 
 /// Response from the agent.
@@ -304,6 +307,25 @@ pub struct ErrorItem {
     pub message: String,
 }
 
+/// Structured review results emitted by `codex exec review` in `--json` mode.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct ReviewOutputItem {
+    pub findings: Vec<ReviewOutputFinding>,
+    pub overall_correctness: String,
+    pub overall_explanation: String,
+}
+
+/// One review finding, with enough location data to post as a PR comment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct ReviewOutputFinding {
+    pub title: String,
+    pub body: String,
+    pub priority: i32,
+    pub file: PathBuf,
+    pub line_start: u32,
+    pub line_end: u32,
+}
+
 /// An item in agent's to-do list.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
 pub struct TodoItem {
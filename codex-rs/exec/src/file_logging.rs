@@ -0,0 +1,121 @@
+//! Per-run JSON log files for headless sessions.
+//!
+//! Every `codex exec` run writes structured JSON logs to
+//! `<log_dir>/codex-exec-<timestamp>-<pid>.log` (log_dir defaults to
+//! `$CODEX_HOME/log`), independent of the stderr env-filter, so postmortems
+//! of headless runs are possible without re-running with `RUST_LOG`. Old run
+//! logs beyond a retention cap are deleted at startup.
+
+use std::fs::File;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Maximum number of `codex-exec-*.log` files kept in the log directory.
+const LOG_RETENTION_FILES: usize = 50;
+const LOG_FILE_PREFIX: &str = "codex-exec-";
+const LOG_FILE_SUFFIX: &str = ".log";
+
+/// Default level filter for the file log, overridable independently of the
+/// stderr filter via `CODEX_FILE_LOG`.
+const FILE_LOG_ENV_VAR: &str = "CODEX_FILE_LOG";
+const FILE_LOG_DEFAULT_FILTER: &str = "info";
+
+pub(crate) struct FileLog {
+    pub writer: Mutex<File>,
+    pub path: PathBuf,
+}
+
+/// Create the per-run log file and prune old run logs. Returns `None` when
+/// the directory or file cannot be created; file logging is best-effort and
+/// never blocks the run.
+pub(crate) fn create_file_log(log_dir: &Path) -> Option<FileLog> {
+    std::fs::create_dir_all(log_dir).ok()?;
+    enforce_retention(log_dir);
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_secs();
+    let path = log_dir.join(format!(
+        "{LOG_FILE_PREFIX}{timestamp}-{}{LOG_FILE_SUFFIX}",
+        std::process::id()
+    ));
+    let file = File::create(&path).ok()?;
+    Some(FileLog {
+        writer: Mutex::new(file),
+        path,
+    })
+}
+
+/// Filter applied to the file log layer.
+pub(crate) fn file_log_filter() -> tracing_subscriber::EnvFilter {
+    std::env::var(FILE_LOG_ENV_VAR)
+        .ok()
+        .and_then(|filter| tracing_subscriber::EnvFilter::try_new(filter).ok())
+        .unwrap_or_else(|| tracing_subscriber::EnvFilter::new(FILE_LOG_DEFAULT_FILTER))
+}
+
+/// Delete the oldest run logs so at most [`LOG_RETENTION_FILES`] remain
+/// (including the file about to be created).
+fn enforce_retention(log_dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(log_dir) else {
+        return;
+    };
+    let mut run_logs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(LOG_FILE_PREFIX) && name.ends_with(LOG_FILE_SUFFIX)
+                })
+        })
+        .collect();
+    if run_logs.len() < LOG_RETENTION_FILES {
+        return;
+    }
+    // File names embed the creation timestamp, so lexicographic order is
+    // oldest-first.
+    run_logs.sort();
+    let excess = run_logs.len() + 1 - LOG_RETENTION_FILES;
+    for stale in run_logs.into_iter().take(excess) {
+        let _ = std::fs::remove_file(stale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn creates_a_run_log_and_prunes_old_ones() {
+        let dir = tempfile::TempDir::new().expect("create log dir");
+        for index in 0..LOG_RETENTION_FILES + 5 {
+            std::fs::write(
+                dir.path()
+                    .join(format!("{LOG_FILE_PREFIX}{index:012}{LOG_FILE_SUFFIX}")),
+                "old",
+            )
+            .expect("write stale log");
+        }
+
+        let log = create_file_log(dir.path()).expect("create file log");
+        assert!(log.path.exists());
+
+        let remaining = std::fs::read_dir(dir.path()).expect("read log dir").count();
+        assert_eq!(remaining, LOG_RETENTION_FILES);
+    }
+
+    #[test]
+    fn ignores_unrelated_files_when_pruning() {
+        let dir = tempfile::TempDir::new().expect("create log dir");
+        std::fs::write(dir.path().join("keep.txt"), "keep").expect("write unrelated file");
+
+        let _log = create_file_log(dir.path()).expect("create file log");
+
+        assert!(dir.path().join("keep.txt").exists());
+    }
+}
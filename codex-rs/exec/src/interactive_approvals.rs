@@ -0,0 +1,63 @@
+//! Interactive y/n/a approval prompts for human-output exec mode.
+//!
+//! When stdin and stderr are TTYs and the approval policy is not `Never`,
+//! pending command and patch approvals are rendered to stderr and answered on
+//! stdin: `y` approves once, `n` denies, and `a` approves and trusts the
+//! command (or files) for the remainder of the session via the session-scoped
+//! approval cache.
+
+use tokio::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InteractiveDecision {
+    Approve,
+    ApproveForSession,
+    Deny,
+}
+
+pub(crate) struct InteractiveApprovals {
+    /// Serializes prompts so concurrent approval requests do not interleave
+    /// their stderr output and stdin reads.
+    prompt_lock: Mutex<()>,
+}
+
+impl InteractiveApprovals {
+    pub(crate) fn new() -> Self {
+        Self {
+            prompt_lock: Mutex::new(()),
+        }
+    }
+
+    /// Render `summary` to stderr and read a y/n/a answer from stdin. EOF and
+    /// read errors deny.
+    pub(crate) async fn prompt(&self, summary: String) -> InteractiveDecision {
+        let _guard = self.prompt_lock.lock().await;
+        tokio::task::spawn_blocking(move || prompt_blocking(&summary))
+            .await
+            .unwrap_or(InteractiveDecision::Deny)
+    }
+}
+
+#[allow(clippy::print_stderr)]
+fn prompt_blocking(summary: &str) -> InteractiveDecision {
+    use std::io::Write;
+
+    eprintln!("\n{summary}");
+    loop {
+        eprint!("Approve? [y]es / [n]o / [a]lways for this session: ");
+        let _ = std::io::stderr().flush();
+        let mut line = String::new();
+        match std::io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) => return InteractiveDecision::Deny,
+            Ok(_) => {}
+        }
+        match line.trim().to_ascii_lowercase().as_str() {
+            "y" | "yes" => return InteractiveDecision::Approve,
+            "a" | "always" => return InteractiveDecision::ApproveForSession,
+            "n" | "no" => return InteractiveDecision::Deny,
+            _ => {
+                eprintln!("Please answer y, n, or a.");
+            }
+        }
+    }
+}
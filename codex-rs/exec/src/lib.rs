@@ -4,12 +4,20 @@
 // For both modes, any other output must be written to stderr.
 #![deny(clippy::print_stdout)]
 
+mod batch;
 mod cli;
 mod event_processor;
 mod event_processor_with_human_output;
 pub(crate) mod event_processor_with_jsonl_output;
 pub(crate) mod exec_events;
-
+mod interactive_approvals;
+mod review_poster;
+mod stdin_approvals;
+
+use batch::BatchSummary;
+use batch::BatchTask;
+use batch::BatchTaskResult;
+use batch::BatchTaskStatus;
 pub use cli::Cli;
 pub use cli::Command;
 pub use cli::ReviewArgs;
@@ -101,7 +109,10 @@ use codex_utils_absolute_path::canonicalize_existing_preserving_symlinks;
 use codex_utils_cli::SharedCliOptions;
 use codex_utils_oss::ensure_oss_provider_ready;
 use codex_utils_oss::get_default_model_for_oss_provider;
+use control_socket::ControlCommand;
+use control_socket::ControlRequest;
 use event_processor_with_human_output::EventProcessorWithHumanOutput;
+use event_processor_with_human_output::HumanOutputTier;
 pub use event_processor_with_jsonl_output::CodexStatus;
 pub use event_processor_with_jsonl_output::CollectedThreadEvents;
 pub use event_processor_with_jsonl_output::EventProcessorWithJsonOutput;
@@ -138,6 +149,10 @@ pub use exec_events::TurnFailedEvent;
 pub use exec_events::TurnStartedEvent;
 pub use exec_events::Usage;
 pub use exec_events::WebSearchItem;
+use interactive_approvals::InteractiveApprovals;
+use interactive_approvals::InteractiveDecision;
+use review_poster::post_review_findings;
+use review_poster::review_output_from_review_text;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::future::Future;
@@ -145,6 +160,7 @@ use std::io::IsTerminal;
 use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
+use stdin_approvals::StdinApprovals;
 use supports_color::Stream;
 use tokio::sync::mpsc;
 use tracing::Instrument;
@@ -203,6 +219,10 @@ impl RequestIdSequencer {
 
 struct ExecRunArgs {
     in_process_start_args: InProcessClientStartArgs,
+    batch: Option<PathBuf>,
+    batch_summary: Option<PathBuf>,
+    batch_worktrees: bool,
+    control_socket: Option<PathBuf>,
     state_db: Option<StateDbHandle>,
     command: Option<ExecCommand>,
     config: Config,
@@ -253,6 +273,18 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
         shared,
         skip_git_repo_check,
         ephemeral,
+        dry_run,
+        role,
+        container,
+        log_provider_traffic,
+        file: file_attachments,
+        url: url_attachments,
+        temperature,
+        top_p,
+        seed,
+        quiet,
+        verbose,
+        patch_out,
         ignore_user_config,
         ignore_rules,
         removed_full_auto,
@@ -262,6 +294,11 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
         prompt,
         output_schema: output_schema_path,
         config_overrides,
+        batch,
+        batch_summary,
+        batch_worktrees,
+        print_config_json,
+        control_socket,
     } = cli;
     let shared = shared.into_inner();
     let SharedCliOptions {
@@ -290,12 +327,22 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
         .with_writer(std::io::stderr)
         .with_filter(exec_stderr_env_filter());
 
+    if let Some(dir) = log_provider_traffic {
+        codex_core::init_provider_traffic_log(dir);
+    }
+
     let sandbox_mode = if removed_full_auto {
         Some(SandboxMode::WorkspaceWrite)
     } else if dangerously_bypass_approvals_and_sandbox {
         Some(SandboxMode::DangerFullAccess)
+    } else if let Some(sandbox_mode) = sandbox_mode_cli_arg.map(Into::<SandboxMode>::into) {
+        Some(sandbox_mode)
+    } else if matches!(command, Some(Command::Review(_))) {
+        // Reviews only need to read the tree; constrain the sandbox unless the
+        // caller explicitly asked for something else.
+        Some(SandboxMode::ReadOnly)
     } else {
-        sandbox_mode_cli_arg.map(Into::<SandboxMode>::into)
+        None
     };
 
     // Parse `-c` overrides from the CLI.
@@ -444,6 +491,11 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
         show_raw_agent_reasoning: oss.then_some(true),
         tools_web_search_request: None,
         ephemeral: ephemeral.then_some(true),
+        dry_run: dry_run.then_some(true),
+        patch_output_dir: patch_out,
+        model_temperature: temperature,
+        model_top_p: top_p,
+        model_seed: seed,
         bypass_hook_trust: bypass_hook_trust.then_some(true),
         additional_writable_roots: add_dir,
     };
@@ -458,12 +510,33 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
             .cloud_config_bundle(cloud_config_bundle.clone())
             .build()
     };
-    let config = build_exec_config(
+    let mut config = build_exec_config(
         overrides,
         dangerously_bypass_approvals_and_sandbox || removed_full_auto,
         build_config,
     )
     .await?;
+    if let Some(role) = role.as_deref() {
+        if let Err(err) = codex_core::apply_agent_role(&mut config, role).await {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Failed to apply role `{role}`: {err}");
+            }
+            std::process::exit(1);
+        }
+    }
+    let config = config;
+    if print_config_json {
+        #[allow(clippy::print_stdout)]
+        {
+            let json =
+                serde_json::to_string_pretty(&codex_core::config::effective_config_json(&config))
+                    .unwrap_or_else(|err| format!("{{\"error\":\"{err}\"}}"));
+            println!("{json}");
+        }
+        return Ok(());
+    }
+
     let resume_approvals_reviewer_override = cli_kv_overrides
         .iter()
         .any(|(key, _)| key == "approvals_reviewer")
@@ -524,8 +597,19 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
 
     let otel_tracing_layer = otel.as_ref().and_then(|o| o.tracing_layer());
 
+    // Structured per-run JSON logs, filtered independently of the stderr
+    // env-filter (override with CODEX_FILE_LOG).
+    let file_log_layer = file_logging::create_file_log(&config.log_dir).map(|file_log| {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_ansi(false)
+            .with_writer(file_log.writer)
+            .with_filter(file_logging::file_log_filter())
+    });
+
     let _ = tracing_subscriber::registry()
         .with(fmt_layer)
+        .with(file_log_layer)
         .with(otel_tracing_layer)
         .with(otel_logger_layer)
         .try_init();
@@ -549,7 +633,13 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
         arg0_paths.codex_linux_sandbox_exe.clone(),
     )?;
     let state_db = codex_core::init_state_db(&config).await;
-    let environment_manager = if run_loader_overrides.ignore_user_config {
+    let environment_manager = if let Some(image) = container.as_deref() {
+        EnvironmentManager::from_container_image(
+            image,
+            config.cwd.as_path(),
+            Some(local_runtime_paths),
+        )?
+    } else if run_loader_overrides.ignore_user_config {
         EnvironmentManager::from_env(Some(local_runtime_paths)).await?
     } else {
         EnvironmentManager::from_codex_home(config.codex_home.clone(), Some(local_runtime_paths))
@@ -578,6 +668,10 @@ pub async fn run_main(cli: Cli, arg0_paths: Arg0DispatchPaths) -> anyhow::Result
     };
     run_exec_session(ExecRunArgs {
         in_process_start_args,
+        batch,
+        batch_summary,
+        batch_worktrees,
+        control_socket,
         state_db,
         command,
         config,
@@ -676,6 +770,10 @@ async fn load_bootstrap_config_or_exit(
 async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
     let ExecRunArgs {
         in_process_start_args,
+        batch,
+        batch_summary,
+        batch_worktrees,
+        control_socket,
         state_db,
         command,
         config,
@@ -693,13 +791,36 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
         stderr_with_ansi,
     } = args;
 
+    let approvals = if json_mode {
+        ExecApprovals::Json(StdinApprovals::new())
+    } else if std::io::stdin().is_terminal()
+        && std::io::stderr().is_terminal()
+        && config.permissions.approval_policy.value() != AskForApproval::Never
+    {
+        ExecApprovals::Interactive(InteractiveApprovals::new())
+    } else {
+        ExecApprovals::Disabled
+    };
     let mut event_processor: Box<dyn EventProcessor> = match json_mode {
-        true => Box::new(EventProcessorWithJsonOutput::new(last_message_file.clone())),
-        _ => Box::new(EventProcessorWithHumanOutput::create_with_ansi(
-            stderr_with_ansi,
-            &config,
-            last_message_file.clone(),
-        )),
+        true => Box::new(
+            EventProcessorWithJsonOutput::new(last_message_file.clone())
+                .with_secret_redaction(config.redact_secrets),
+        ),
+        _ => {
+            let tier = if quiet {
+                HumanOutputTier::Quiet
+            } else if verbose {
+                HumanOutputTier::Verbose
+            } else {
+                HumanOutputTier::Normal
+            };
+            Box::new(EventProcessorWithHumanOutput::create_with_ansi(
+                stderr_with_ansi,
+                &config,
+                last_message_file.clone(),
+                tier,
+            ))
+        }
     };
     if oss {
         // We're in the oss section, so provider_id should be Some
@@ -722,6 +843,53 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
     let default_approval_policy = config.permissions.approval_policy.value();
     let default_effort = config.model_reasoning_effort.clone();
 
+    if let Some(batch_file) = batch {
+        if !skip_git_repo_check
+            && !dangerously_bypass_approvals_and_sandbox
+            && get_git_repo_root(&default_cwd).is_none()
+        {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!(
+                    "Not inside a trusted directory and --skip-git-repo-check was not specified."
+                );
+            }
+            std::process::exit(1);
+        }
+        let tasks = batch::load_batch_tasks(&batch_file)?;
+        let mut request_ids = RequestIdSequencer::new();
+        let mut client = InProcessAppServerClient::start(in_process_start_args)
+            .await
+            .map_err(|err| {
+                anyhow::anyhow!("failed to initialize in-process app-server client: {err}")
+            })?;
+        let summary = run_batch_tasks(
+            &mut client,
+            &mut request_ids,
+            &config,
+            default_approval_policy,
+            default_effort,
+            tasks,
+            batch_worktrees,
+        )
+        .await?;
+        batch::write_batch_summary(batch_summary.as_deref(), &summary)?;
+        if let Err(err) = client.shutdown().await {
+            warn!("in-process app-server shutdown failed: {err}");
+        }
+        if summary.any_failed() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let post_review = match command.as_ref() {
+        Some(ExecCommand::Review(review_cli)) => review_cli
+            .post_review
+            .map(|provider| (provider, review_cli.pr)),
+        _ => None,
+    };
+
     let (initial_operation, prompt_summary) = match (command.as_ref(), prompt, images) {
         (Some(ExecCommand::Review(review_cli)), _, _) => {
             let review_request = build_review_request(review_cli)?;
@@ -746,6 +914,18 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
                 .chain(args.images.iter().cloned())
                 .map(|path| UserInput::LocalImage { path, detail: None })
                 .collect();
+            items.extend(
+                file_attachments
+                    .iter()
+                    .cloned()
+                    .map(|path| UserInput::LocalFile { path }),
+            );
+            items.extend(
+                url_attachments
+                    .iter()
+                    .cloned()
+                    .map(|url| UserInput::Url { url }),
+            );
             items.push(UserInput::Text {
                 text: prompt_text.clone(),
                 // CLI input doesn't track UI element ranges, so none are available here.
@@ -766,6 +946,18 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
                 .into_iter()
                 .map(|path| UserInput::LocalImage { path, detail: None })
                 .collect();
+            items.extend(
+                file_attachments
+                    .iter()
+                    .cloned()
+                    .map(|path| UserInput::LocalFile { path }),
+            );
+            items.extend(
+                url_attachments
+                    .iter()
+                    .cloned()
+                    .map(|url| UserInput::Url { url }),
+            );
             items.push(UserInput::Text {
                 text: prompt_text.clone(),
                 // CLI input doesn't track UI element ranges, so none are available here.
@@ -877,6 +1069,17 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
 
     info!("Codex initialized with event: {session_configured:?}");
 
+    let mut control_rx = match control_socket {
+        Some(path) => match control_socket::spawn_control_socket(path).await {
+            Ok(rx) => Some(rx),
+            Err(err) => {
+                warn!("failed to start control socket: {err:#}");
+                None
+            }
+        },
+        None => None,
+    };
+
     let (interrupt_tx, mut interrupt_rx) = mpsc::unbounded_channel::<()>();
     tokio::spawn(async move {
         if tokio::signal::ctrl_c().await.is_ok() {
@@ -885,7 +1088,9 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
         }
     });
 
-    let task_id = match initial_operation {
+    // Latest turn id on the primary thread; control-socket `user_input` can
+    // start new turns, and interrupt/status must target the current one.
+    let mut task_id = match initial_operation {
         InitialOperation::UserTurn {
             items,
             output_schema,
@@ -957,10 +1162,35 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
     // Track whether a fatal error was reported by the server so we can
     // exit with a non-zero status for automation-friendly signaling.
     let mut error_seen = false;
+    let mut last_review_output = None;
     let mut interrupt_channel_open = true;
     let primary_thread_id_for_requests = primary_thread_id.to_string();
     loop {
         let server_event = tokio::select! {
+            maybe_control = async {
+                match control_rx.as_mut() {
+                    Some(control_rx) => control_rx.recv().await,
+                    // Without a control socket (or after it closes), park this
+                    // branch forever instead of spinning on a closed channel.
+                    None => std::future::pending().await,
+                }
+            } => {
+                let Some(ControlRequest { command, respond }) = maybe_control else {
+                    control_rx = None;
+                    continue;
+                };
+                let reply = handle_control_command(
+                    &client,
+                    &mut request_ids,
+                    &primary_thread_id_for_requests,
+                    &mut task_id,
+                    default_approval_policy,
+                    command,
+                )
+                .await;
+                let _ = respond.send(reply);
+                continue;
+            }
             maybe_interrupt = interrupt_rx.recv(), if interrupt_channel_open => {
                 if maybe_interrupt.is_none() {
                     interrupt_channel_open = false;
@@ -992,7 +1222,7 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
 
         match server_event {
             InProcessServerEvent::ServerRequest(request) => {
-                handle_server_request(&client, request, &mut error_seen).await;
+                handle_server_request(&client, request, &approvals, &mut error_seen).await;
             }
             InProcessServerEvent::ServerNotification(mut notification) => {
                 if let ServerNotification::Error(payload) = &notification {
@@ -1014,6 +1244,15 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
                     error_seen = true;
                 }
 
+                if post_review.is_some()
+                    && let ServerNotification::ItemCompleted(payload) = &notification
+                    && let codex_app_server_protocol::ThreadItem::ExitedReviewMode {
+                        review, ..
+                    } = &payload.item
+                {
+                    last_review_output = Some(review_output_from_review_text(review));
+                }
+
                 maybe_backfill_turn_completed_items(
                     config.ephemeral,
                     &client,
@@ -1056,6 +1295,27 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
         warn!("in-process app-server shutdown failed: {err}");
     }
     event_processor.print_final_output();
+
+    if let Some((provider, pull_request)) = post_review {
+        match (last_review_output.as_ref(), pull_request) {
+            (Some(review), Some(pull_request)) => {
+                if let Err(err) =
+                    post_review_findings(provider, pull_request, review, config.dry_run).await
+                {
+                    warn!("failed to post review findings: {err:#}");
+                    error_seen = true;
+                }
+            }
+            (Some(_), None) => {
+                warn!("--post-review requires --pr <NUMBER> to know where to post");
+                error_seen = true;
+            }
+            (None, _) => {
+                warn!("--post-review was set but the session produced no review output");
+            }
+        }
+    }
+
     if error_seen {
         std::process::exit(1);
     }
@@ -1063,6 +1323,195 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Run batch tasks sequentially, each in its own conversation on the shared
+/// in-process app-server, collecting per-task statuses and last messages.
+#[allow(clippy::too_many_arguments)]
+async fn run_batch_tasks(
+    client: &mut InProcessAppServerClient,
+    request_ids: &mut RequestIdSequencer,
+    config: &Config,
+    default_approval_policy: AskForApproval,
+    default_effort: Option<codex_protocol::openai_models::ReasoningEffort>,
+    tasks: Vec<BatchTask>,
+    batch_worktrees: bool,
+) -> anyhow::Result<BatchSummary> {
+    let mut results = Vec::with_capacity(tasks.len());
+    let mut error_seen = false;
+    let batch_run_id = Uuid::new_v4().simple().to_string();
+
+    for (index, task) in tasks.into_iter().enumerate() {
+        info!("starting batch task {index}: {}", task.prompt);
+        let mut task_cwd = task.cwd.clone();
+        let mut worktree_path = None;
+        let mut worktree_branch = None;
+        if batch_worktrees {
+            let repo_cwd = task_cwd.clone().unwrap_or_else(|| config.cwd.to_path_buf());
+            let worktree_name = format!("{}-{index}", &batch_run_id[..8]);
+            match codex_git_utils::create_task_worktree(&repo_cwd, &worktree_name).await {
+                Ok(worktree) => {
+                    task_cwd = Some(worktree.path.clone());
+                    worktree_path = Some(worktree.path);
+                    worktree_branch = Some(worktree.branch);
+                }
+                Err(err) => {
+                    warn!("batch task {index} failed to create a worktree: {err}");
+                    results.push(BatchTaskResult {
+                        index,
+                        prompt: task.prompt,
+                        thread_id: None,
+                        status: BatchTaskStatus::Failed,
+                        last_message: None,
+                        worktree_path: None,
+                        worktree_branch: None,
+                    });
+                    continue;
+                }
+            }
+        }
+        let thread_id = match send_request_with_response::<ThreadStartResponse>(
+            client,
+            ClientRequest::ThreadStart {
+                request_id: request_ids.next(),
+                params: thread_start_params_from_config(config),
+            },
+            "thread/start",
+        )
+        .await
+        {
+            Ok(response) => response.thread.id,
+            Err(err) => {
+                warn!("batch task {index} failed to start a thread: {err}");
+                results.push(BatchTaskResult {
+                    index,
+                    prompt: task.prompt,
+                    thread_id: None,
+                    status: BatchTaskStatus::Failed,
+                    last_message: None,
+                    worktree_path,
+                    worktree_branch,
+                });
+                continue;
+            }
+        };
+
+        let mut items: Vec<UserInput> = task
+            .images
+            .iter()
+            .cloned()
+            .map(|path| UserInput::LocalImage { path, detail: None })
+            .collect();
+        items.push(UserInput::Text {
+            text: task.prompt.clone(),
+            text_elements: Vec::new(),
+        });
+        let turn_id = match send_request_with_response::<TurnStartResponse>(
+            client,
+            ClientRequest::TurnStart {
+                request_id: request_ids.next(),
+                params: TurnStartParams {
+                    thread_id: thread_id.clone(),
+                    client_user_message_id: None,
+                    input: items.into_iter().map(Into::into).collect(),
+                    responsesapi_client_metadata: None,
+                    additional_context: None,
+                    environments: None,
+                    cwd: Some(task.cwd.clone().unwrap_or_else(|| config.cwd.to_path_buf())),
+                    runtime_workspace_roots: None,
+                    approval_policy: Some(default_approval_policy.into()),
+                    approvals_reviewer: None,
+                    sandbox_policy: None,
+                    permissions: None,
+                    model: None,
+                    service_tier: None,
+                    effort: default_effort.clone(),
+                    summary: None,
+                    personality: None,
+                    output_schema: None,
+                    collaboration_mode: None,
+                    multi_agent_mode: None,
+                },
+            },
+            "turn/start",
+        )
+        .await
+        {
+            Ok(response) => response.turn.id,
+            Err(err) => {
+                warn!("batch task {index} failed to start a turn: {err}");
+                results.push(BatchTaskResult {
+                    index,
+                    prompt: task.prompt,
+                    thread_id: Some(thread_id),
+                    status: BatchTaskStatus::Failed,
+                    last_message: None,
+                    worktree_path,
+                    worktree_branch,
+                });
+                continue;
+            }
+        };
+
+        let mut last_message = None;
+        let status = loop {
+            let Some(server_event) = client.next_event().await else {
+                break BatchTaskStatus::Interrupted;
+            };
+            match server_event {
+                InProcessServerEvent::ServerRequest(request) => {
+                    handle_server_request(
+                        client,
+                        request,
+                        &ExecApprovals::Disabled,
+                        &mut error_seen,
+                    )
+                    .await;
+                }
+                InProcessServerEvent::ServerNotification(notification) => match &notification {
+                    ServerNotification::ItemCompleted(payload)
+                        if payload.thread_id == thread_id =>
+                    {
+                        if let codex_app_server_protocol::ThreadItem::AgentMessage {
+                            text, ..
+                        } = &payload.item
+                        {
+                            last_message = Some(text.clone());
+                        }
+                    }
+                    ServerNotification::TurnCompleted(payload)
+                        if payload.thread_id == thread_id && payload.turn.id == turn_id =>
+                    {
+                        break match payload.turn.status {
+                            codex_app_server_protocol::TurnStatus::Completed => {
+                                BatchTaskStatus::Completed
+                            }
+                            codex_app_server_protocol::TurnStatus::Interrupted => {
+                                BatchTaskStatus::Interrupted
+                            }
+                            _ => BatchTaskStatus::Failed,
+                        };
+                    }
+                    _ => {}
+                },
+                InProcessServerEvent::Lagged { skipped } => {
+                    warn!("{}", lagged_event_warning_message(skipped));
+                }
+            }
+        };
+
+        results.push(BatchTaskResult {
+            index,
+            prompt: task.prompt,
+            thread_id: Some(thread_id),
+            status,
+            last_message,
+            worktree_path,
+            worktree_branch,
+        });
+    }
+
+    Ok(BatchSummary::new(results))
+}
+
 fn thread_start_params_from_config(config: &Config) -> ThreadStartParams {
     let permissions = permissions_selection_from_config(config);
     let sandbox = permissions.is_none().then(|| {
@@ -1609,6 +2058,157 @@ async fn request_shutdown(
         .map(|_| ())
 }
 
+/// Execute one control-socket command against the live session and build the
+/// JSON reply sent back over the connection.
+async fn handle_control_command(
+    client: &InProcessAppServerClient,
+    request_ids: &mut RequestIdSequencer,
+    thread_id: &str,
+    turn_id: &mut String,
+    default_approval_policy: AskForApproval,
+    command: ControlCommand,
+) -> Value {
+    match command {
+        ControlCommand::Interrupt => {
+            match send_request_with_response::<TurnInterruptResponse>(
+                client,
+                ClientRequest::TurnInterrupt {
+                    request_id: request_ids.next(),
+                    params: TurnInterruptParams {
+                        thread_id: thread_id.to_string(),
+                        turn_id: turn_id.to_string(),
+                    },
+                },
+                "turn/interrupt",
+            )
+            .await
+            {
+                Ok(_) => serde_json::json!({"ok": true}),
+                Err(err) => serde_json::json!({"ok": false, "error": err}),
+            }
+        }
+        ControlCommand::UserInput { text } => {
+            let input = vec![UserInput::Text {
+                text,
+                text_elements: Vec::new(),
+            }];
+            match send_request_with_response::<TurnStartResponse>(
+                client,
+                ClientRequest::TurnStart {
+                    request_id: request_ids.next(),
+                    params: TurnStartParams {
+                        thread_id: thread_id.to_string(),
+                        client_user_message_id: None,
+                        input: input.into_iter().map(Into::into).collect(),
+                        responsesapi_client_metadata: None,
+                        additional_context: None,
+                        environments: None,
+                        cwd: None,
+                        runtime_workspace_roots: None,
+                        approval_policy: Some(default_approval_policy.into()),
+                        approvals_reviewer: None,
+                        sandbox_policy: None,
+                        permissions: None,
+                        model: None,
+                        service_tier: None,
+                        effort: None,
+                        summary: None,
+                        personality: None,
+                        output_schema: None,
+                        collaboration_mode: None,
+                        multi_agent_mode: None,
+                    },
+                },
+                "turn/start",
+            )
+            .await
+            {
+                Ok(response) => {
+                    // Later interrupt/status commands must target this turn.
+                    *turn_id = response.turn.id.clone();
+                    serde_json::json!({"ok": true, "turn_id": response.turn.id})
+                }
+                Err(err) => serde_json::json!({"ok": false, "error": err}),
+            }
+        }
+        ControlCommand::Status => {
+            serde_json::json!({"ok": true, "thread_id": thread_id, "turn_id": turn_id})
+        }
+    }
+}
+
+/// Decision vocabulary expected by an approval response.
+enum ApprovalVocabulary {
+    /// v1 approvals answered with a `ReviewDecision`.
+    Review,
+    /// v2 item approvals answered with the item-scoped decision enums.
+    ItemDecision,
+}
+
+impl ApprovalVocabulary {
+    fn decision_value(&self, decision: InteractiveDecision) -> Value {
+        match (self, decision) {
+            (Self::Review, InteractiveDecision::Approve) => serde_json::json!("approved"),
+            (Self::Review, InteractiveDecision::ApproveForSession) => {
+                serde_json::json!("approved_for_session")
+            }
+            (Self::Review, InteractiveDecision::Deny) => serde_json::json!("denied"),
+            (Self::ItemDecision, InteractiveDecision::Approve) => serde_json::json!("accept"),
+            (Self::ItemDecision, InteractiveDecision::ApproveForSession) => {
+                serde_json::json!("acceptForSession")
+            }
+            (Self::ItemDecision, InteractiveDecision::Deny) => serde_json::json!("decline"),
+        }
+    }
+}
+
+/// Route an approval-style server request through the active approval
+/// mechanism, or reject it as unsupported when approvals are disabled.
+#[allow(clippy::too_many_arguments)]
+async fn handle_approval_request(
+    client: &InProcessAppServerClient,
+    approvals: &ExecApprovals,
+    request_id: RequestId,
+    method: &str,
+    vocabulary: ApprovalVocabulary,
+    params: Value,
+    summary: String,
+    unsupported_reason: String,
+) -> Result<(), String> {
+    let decision = match approvals {
+        ExecApprovals::Disabled => {
+            return reject_server_request(client, request_id, method, unsupported_reason).await;
+        }
+        ExecApprovals::Json(stdin_approvals) => {
+            match stdin_approvals
+                .request_decision(method, &request_id, params)
+                .await
+            {
+                Some(decision) => decision,
+                None => {
+                    return reject_server_request(
+                        client,
+                        request_id,
+                        method,
+                        "no approval decision received on stdin".to_string(),
+                    )
+                    .await;
+                }
+            }
+        }
+        ExecApprovals::Interactive(interactive) => {
+            vocabulary.decision_value(interactive.prompt(summary).await)
+        }
+    };
+    resolve_server_request(
+        client,
+        request_id,
+        serde_json::json!({ "decision": decision }),
+        method,
+    )
+    .await
+}
+
 async fn resolve_server_request(
     client: &InProcessAppServerClient,
     request_id: RequestId,
@@ -1652,9 +2252,21 @@ fn server_request_method_name(request: &ServerRequest) -> String {
         .unwrap_or_else(|| "unknown".to_string())
 }
 
+/// How exec resolves approval-style server requests.
+enum ExecApprovals {
+    /// Approvals are rejected as unsupported (headless default).
+    Disabled,
+    /// `--json` mode: requests and decisions flow over stdout/stdin as JSON
+    /// lines.
+    Json(StdinApprovals),
+    /// Human-output mode on a TTY: prompt y/n/a on stderr/stdin.
+    Interactive(InteractiveApprovals),
+}
+
 async fn handle_server_request(
     client: &InProcessAppServerClient,
     request: ServerRequest,
+    approvals: &ExecApprovals,
     error_seen: &mut bool,
 ) {
     let method = server_request_method_name(&request);
@@ -1677,10 +2289,18 @@ async fn handle_server_request(
             }
         }
         ServerRequest::CommandExecutionRequestApproval { request_id, params } => {
-            reject_server_request(
+            let summary = format!(
+                "codex wants to run: {}",
+                params.command.as_deref().unwrap_or("<unknown command>")
+            );
+            handle_approval_request(
                 client,
+                approvals,
                 request_id,
                 &method,
+                ApprovalVocabulary::ItemDecision,
+                serde_json::to_value(&params).unwrap_or_default(),
+                summary,
                 format!(
                     "command execution approval is not supported in exec mode for thread `{}`",
                     params.thread_id
@@ -1689,10 +2309,18 @@ async fn handle_server_request(
             .await
         }
         ServerRequest::FileChangeRequestApproval { request_id, params } => {
-            reject_server_request(
+            let summary = match params.reason.as_deref() {
+                Some(reason) => format!("codex wants to apply file changes ({reason})"),
+                None => "codex wants to apply file changes".to_string(),
+            };
+            handle_approval_request(
                 client,
+                approvals,
                 request_id,
                 &method,
+                ApprovalVocabulary::ItemDecision,
+                serde_json::to_value(&params).unwrap_or_default(),
+                summary,
                 format!(
                     "file change approval is not supported in exec mode for thread `{}`",
                     params.thread_id
@@ -1752,10 +2380,21 @@ async fn handle_server_request(
             .await
         }
         ServerRequest::ApplyPatchApproval { request_id, params } => {
-            reject_server_request(
+            let mut files: Vec<String> = params
+                .file_changes
+                .keys()
+                .map(|path| path.display().to_string())
+                .collect();
+            files.sort();
+            let summary = format!("codex wants to apply a patch to: {}", files.join(", "));
+            handle_approval_request(
                 client,
+                approvals,
                 request_id,
                 &method,
+                ApprovalVocabulary::Review,
+                serde_json::to_value(&params).unwrap_or_default(),
+                summary,
                 format!(
                     "apply_patch approval is not supported in exec mode for thread `{}`",
                     params.conversation_id
@@ -1764,10 +2403,18 @@ async fn handle_server_request(
             .await
         }
         ServerRequest::ExecCommandApproval { request_id, params } => {
-            reject_server_request(
+            let mut summary = format!("codex wants to run: {}", params.command.join(" "));
+            if let Some(reason) = params.reason.as_deref() {
+                summary.push_str(&format!("\n  reason: {reason}"));
+            }
+            handle_approval_request(
                 client,
+                approvals,
                 request_id,
                 &method,
+                ApprovalVocabulary::Review,
+                serde_json::to_value(&params).unwrap_or_default(),
+                summary,
                 format!(
                     "exec command approval is not supported in exec mode for thread `{}`",
                     params.conversation_id
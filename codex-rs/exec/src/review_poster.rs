@@ -0,0 +1,189 @@
+//! Post structured review findings as PR/MR comments.
+//!
+//! Used by `codex exec review --post-review <provider>`. The repository and
+//! credentials come from the provider's conventional environment variables so
+//! automation can reuse existing CI secrets:
+//!
+//! * GitHub: `GITHUB_TOKEN` and `GITHUB_REPOSITORY` (`owner/repo`).
+//! * GitLab: `GITLAB_TOKEN` and `CI_PROJECT_ID` (or `GITLAB_PROJECT_ID`), with
+//!   an optional `CI_SERVER_URL` for self-hosted instances.
+//!
+//! With `--dry-run`, the comments are rendered to stderr instead of posted.
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+
+use crate::exec_events::ReviewOutputFinding;
+use crate::exec_events::ReviewOutputItem;
+
+/// Parse the serialized `ReviewOutputEvent` carried by an `ExitedReviewMode`
+/// item; free-form text that is not valid JSON becomes the explanation.
+pub(crate) fn review_output_from_review_text(review: &str) -> ReviewOutputItem {
+    let output = serde_json::from_str::<codex_protocol::protocol::ReviewOutputEvent>(review)
+        .unwrap_or_else(|_| codex_protocol::protocol::ReviewOutputEvent {
+            overall_explanation: review.to_string(),
+            ..Default::default()
+        });
+    ReviewOutputItem {
+        findings: output
+            .findings
+            .into_iter()
+            .map(|finding| ReviewOutputFinding {
+                title: finding.title,
+                body: finding.body,
+                priority: finding.priority,
+                file: finding.code_location.absolute_file_path,
+                line_start: finding.code_location.line_range.start,
+                line_end: finding.code_location.line_range.end,
+            })
+            .collect(),
+        overall_correctness: output.overall_correctness,
+        overall_explanation: output.overall_explanation,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum ReviewPostProvider {
+    Github,
+    Gitlab,
+}
+
+/// Post each finding of `review` as a comment on the given PR/MR.
+pub(crate) async fn post_review_findings(
+    provider: ReviewPostProvider,
+    pull_request: u64,
+    review: &ReviewOutputItem,
+    dry_run: bool,
+) -> Result<()> {
+    let comments: Vec<String> = review.findings.iter().map(render_finding).collect();
+    if comments.is_empty() {
+        tracing::info!("review produced no findings; nothing to post");
+        return Ok(());
+    }
+
+    if dry_run {
+        #[allow(clippy::print_stderr)]
+        for comment in &comments {
+            eprintln!("dry run: would post review comment:\n{comment}\n");
+        }
+        return Ok(());
+    }
+
+    match provider {
+        ReviewPostProvider::Github => post_github_comments(pull_request, &comments).await,
+        ReviewPostProvider::Gitlab => post_gitlab_comments(pull_request, &comments).await,
+    }
+}
+
+fn render_finding(finding: &ReviewOutputFinding) -> String {
+    let location = format!(
+        "{}:{}-{}",
+        finding.file.display(),
+        finding.line_start,
+        finding.line_end
+    );
+    format!(
+        "**{title}** (priority {priority})\n\n`{location}`\n\n{body}",
+        title = finding.title,
+        priority = finding.priority,
+        body = finding.body,
+    )
+}
+
+fn require_env(name: &str) -> Result<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .with_context(|| format!("environment variable `{name}` must be set to post reviews"))
+}
+
+async fn post_github_comments(pull_request: u64, comments: &[String]) -> Result<()> {
+    let token = require_env("GITHUB_TOKEN")?;
+    let repository = require_env("GITHUB_REPOSITORY")?;
+    let api_base = std::env::var("GITHUB_API_URL")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "https://api.github.com".to_string());
+    let url = format!("{api_base}/repos/{repository}/issues/{pull_request}/comments");
+
+    let client = reqwest::Client::builder()
+        .user_agent("codex-exec-review")
+        .build()?;
+    for comment in comments {
+        let response = client
+            .post(&url)
+            .bearer_auth(&token)
+            .header("Accept", "application/vnd.github+json")
+            .json(&serde_json::json!({ "body": comment }))
+            .send()
+            .await
+            .context("failed to send GitHub review comment")?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            bail!("GitHub rejected review comment with {status}: {body}");
+        }
+    }
+    Ok(())
+}
+
+async fn post_gitlab_comments(merge_request: u64, comments: &[String]) -> Result<()> {
+    let token = require_env("GITLAB_TOKEN")?;
+    let project = std::env::var("CI_PROJECT_ID")
+        .or_else(|_| std::env::var("GITLAB_PROJECT_ID"))
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .context("CI_PROJECT_ID or GITLAB_PROJECT_ID must be set to post reviews")?;
+    let server = std::env::var("CI_SERVER_URL")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "https://gitlab.com".to_string());
+    let url = format!("{server}/api/v4/projects/{project}/merge_requests/{merge_request}/notes");
+
+    let client = reqwest::Client::builder()
+        .user_agent("codex-exec-review")
+        .build()?;
+    for comment in comments {
+        let response = client
+            .post(&url)
+            .header("PRIVATE-TOKEN", &token)
+            .json(&serde_json::json!({ "body": comment }))
+            .send()
+            .await
+            .context("failed to send GitLab review comment")?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            bail!("GitLab rejected review comment with {status}: {body}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    #[test]
+    fn findings_render_with_location_and_priority() {
+        let finding = ReviewOutputFinding {
+            title: "Possible off-by-one".to_string(),
+            body: "The loop skips the final element.".to_string(),
+            priority: 1,
+            file: PathBuf::from("src/lib.rs"),
+            line_start: 10,
+            line_end: 12,
+        };
+
+        let rendered = render_finding(&finding);
+
+        assert_eq!(
+            rendered,
+            "**Possible off-by-one** (priority 1)\n\n`src/lib.rs:10-12`\n\nThe loop skips the final element."
+        );
+    }
+}
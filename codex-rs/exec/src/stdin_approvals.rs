@@ -0,0 +1,141 @@
+//! Bidirectional approval protocol for `--json` mode.
+//!
+//! When active, approval-style server requests are emitted to stdout as
+//! single JSON lines:
+//!
+//! ```json
+//! {"type":"approval_request","id":"0","method":"execCommandApproval","params":{...}}
+//! ```
+//!
+//! and the wrapper answers on stdin with a matching line:
+//!
+//! ```json
+//! {"id":"0","decision":"approved"}
+//! ```
+//!
+//! The `decision` payload is passed through verbatim as the `decision` field
+//! of the corresponding approval response, so each request kind accepts its
+//! own decision vocabulary (`ReviewDecision` for v1 approvals, the
+//! item-scoped decision enums for v2 approvals). When stdin closes before a
+//! decision arrives, pending and future requests are denied.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use codex_app_server_protocol::RequestId;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::io::AsyncBufReadExt;
+use tokio::sync::Mutex;
+use tokio::sync::oneshot;
+use tracing::warn;
+
+pub(crate) struct StdinApprovals {
+    pending: Arc<Mutex<HashMap<String, oneshot::Sender<Value>>>>,
+    reader_started: AtomicBool,
+    reader_closed: Arc<AtomicBool>,
+}
+
+#[derive(Deserialize)]
+struct ApprovalResponseLine {
+    id: String,
+    decision: Value,
+}
+
+impl StdinApprovals {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            reader_started: AtomicBool::new(false),
+            reader_closed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Emit the approval request as a JSON line on stdout and wait for the
+    /// wrapper's decision on stdin. Returns `None` when stdin is closed or the
+    /// response cannot be delivered, in which case the caller should deny.
+    #[allow(clippy::print_stdout)]
+    pub(crate) async fn request_decision(
+        &self,
+        method: &str,
+        request_id: &RequestId,
+        params: Value,
+    ) -> Option<Value> {
+        self.ensure_reader();
+        if self.reader_closed.load(Ordering::Acquire) {
+            return None;
+        }
+
+        let id = request_id_key(request_id);
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().await.insert(id.clone(), tx);
+        // Re-check after registering: if the reader shut down between the
+        // first check and the insert, its final cleanup may have missed this
+        // entry, which would leave the request waiting forever.
+        if self.reader_closed.load(Ordering::Acquire) {
+            self.pending.lock().await.remove(&id);
+            return None;
+        }
+
+        let line = serde_json::json!({
+            "type": "approval_request",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        println!("{line}");
+
+        rx.await.ok()
+    }
+
+    /// Start the stdin reader lazily, after any prompt input has already been
+    /// consumed at startup.
+    fn ensure_reader(&self) {
+        if self.reader_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let pending = Arc::clone(&self.pending);
+        let reader_closed = Arc::clone(&self.reader_closed);
+        tokio::spawn(async move {
+            let mut lines = tokio::io::BufReader::new(tokio::io::stdin()).lines();
+            loop {
+                match lines.next_line().await {
+                    Ok(Some(line)) => {
+                        let line = line.trim();
+                        if line.is_empty() {
+                            continue;
+                        }
+                        match serde_json::from_str::<ApprovalResponseLine>(line) {
+                            Ok(response) => {
+                                if let Some(tx) = pending.lock().await.remove(&response.id) {
+                                    let _ = tx.send(response.decision);
+                                } else {
+                                    warn!(
+                                        "approval response for unknown request id `{}`",
+                                        response.id
+                                    );
+                                }
+                            }
+                            Err(err) => {
+                                warn!("ignoring malformed approval response line: {err}");
+                            }
+                        }
+                    }
+                    Ok(None) | Err(_) => break,
+                }
+            }
+            reader_closed.store(true, Ordering::Release);
+            // Dropping pending senders denies every in-flight request.
+            pending.lock().await.clear();
+        });
+    }
+}
+
+fn request_id_key(request_id: &RequestId) -> String {
+    match request_id {
+        RequestId::String(id) => id.clone(),
+        RequestId::Integer(id) => id.to_string(),
+    }
+}
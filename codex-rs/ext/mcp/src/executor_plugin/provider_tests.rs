@@ -182,6 +182,7 @@ async fn reads_declared_config_only_through_executor_file_system() {
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: None,
                     disabled_tools: None,
@@ -208,6 +209,7 @@ async fn reads_declared_config_only_through_executor_file_system() {
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
+                    max_retries: None,
                     default_tools_approval_mode: None,
                     enabled_tools: None,
                     disabled_tools: None,
@@ -265,6 +267,7 @@ async fn reads_manifest_object_config_without_executor_file_system_access() {
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
+                max_retries: None,
                 default_tools_approval_mode: None,
                 enabled_tools: None,
                 disabled_tools: None,
@@ -0,0 +1,264 @@
+//! C-ABI bindings over the core conversation API.
+//!
+//! This is the substrate for Python (`ctypes`/`cffi`) and Node (`ffi-napi`)
+//! bindings: sessions are driven with the same serde-serializable [`Op`] and
+//! [`Event`] types the Rust clients use, passed across the boundary as JSON
+//! strings.
+//!
+//! Lifecycle:
+//!
+//! 1. `codex_session_start(codex_home_or_null)` boots a runtime, loads config,
+//!    and starts a thread; returns an opaque handle or null.
+//! 2. `codex_session_submit(session, op_json)` submits an `Op`.
+//! 3. `codex_session_next_event(session, timeout_ms)` returns the next
+//!    `Event` as JSON (caller frees with `codex_string_free`), or null on
+//!    timeout or channel close.
+//! 4. `codex_session_free(session)` shuts the session down.
+//!
+//! Errors are reported through `codex_last_error` (thread-local, freed with
+//! `codex_string_free`).
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_core::config::ConfigBuilder;
+use codex_core_api::AuthManager;
+use codex_core_api::CodexHomeUserInstructionsProvider;
+use codex_core_api::CodexThread;
+use codex_core_api::Config;
+use codex_core_api::EnvironmentManager;
+use codex_core_api::ExtensionRegistryBuilder;
+use codex_core_api::SessionSource;
+use codex_core_api::ThreadManager;
+use codex_protocol::protocol::Op;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message)
+        .unwrap_or_else(|_| CString::new("error message contained a NUL byte").unwrap_or_default());
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(message));
+}
+
+/// Opaque session handle owned by the embedding runtime.
+pub struct CodexFfiSession {
+    runtime: tokio::runtime::Runtime,
+    thread: Arc<CodexThread>,
+    /// Keeps shared thread-manager state (stores, MCP manager) alive for the
+    /// lifetime of the session.
+    _thread_manager: ThreadManager,
+}
+
+async fn start_thread(
+    codex_home: Option<PathBuf>,
+) -> anyhow::Result<(ThreadManager, Arc<CodexThread>)> {
+    let mut builder = ConfigBuilder::default();
+    if let Some(codex_home) = codex_home {
+        builder = builder.codex_home(codex_home);
+    }
+    let config: Config = builder.build().await?;
+    let config = Arc::new(config);
+
+    let auth_manager =
+        AuthManager::shared_from_config(config.as_ref(), /*enable_codex_api_key_env*/ true).await;
+    let environment_manager = Arc::new(
+        EnvironmentManager::from_codex_home(config.codex_home.clone(), /*runtime_paths*/ None)
+            .await
+            .map_err(|err| anyhow::anyhow!("failed to initialize environment manager: {err}"))?,
+    );
+    let user_instructions_provider = Arc::new(CodexHomeUserInstructionsProvider::new(
+        config.codex_home.clone(),
+    ));
+    let extensions = Arc::new(ExtensionRegistryBuilder::<Config>::new().build());
+    let state_db = codex_core::init_state_db(&config).await;
+    let installation_id = codex_core::resolve_installation_id(&config.codex_home).await?;
+
+    let thread_manager = ThreadManager::new(
+        config.as_ref(),
+        auth_manager.clone(),
+        SessionSource::Exec,
+        environment_manager,
+        extensions,
+        user_instructions_provider,
+        /*analytics_events_client*/ None,
+        codex_core::thread_store_from_config(config.as_ref(), state_db.clone()),
+        codex_core::local_agent_graph_store_from_state_db(state_db.as_ref()),
+        installation_id,
+        /*attestation_provider*/ None,
+        /*external_time_provider*/ None,
+    );
+    let new_thread = thread_manager.start_thread(config.as_ref().clone()).await?;
+    Ok((thread_manager, new_thread.thread))
+}
+
+/// Start a session. `codex_home` may be null to use the default resolution.
+/// Returns null on failure; see `codex_last_error`.
+///
+/// # Safety
+///
+/// `codex_home`, when non-null, must point to a NUL-terminated UTF-8 string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn codex_session_start(codex_home: *const c_char) -> *mut CodexFfiSession {
+    let codex_home = if codex_home.is_null() {
+        None
+    } else {
+        match unsafe { CStr::from_ptr(codex_home) }.to_str() {
+            Ok(path) => Some(PathBuf::from(path)),
+            Err(_) => {
+                set_last_error("codex_home is not valid UTF-8".to_string());
+                return std::ptr::null_mut();
+            }
+        }
+    };
+
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            set_last_error(format!("failed to start tokio runtime: {err}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    match runtime.block_on(start_thread(codex_home)) {
+        Ok((thread_manager, thread)) => Box::into_raw(Box::new(CodexFfiSession {
+            runtime,
+            thread,
+            _thread_manager: thread_manager,
+        })),
+        Err(err) => {
+            set_last_error(format!("failed to start session: {err:#}"));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Submit a serde-serialized [`Op`]. Returns 0 on success, -1 on failure.
+///
+/// # Safety
+///
+/// `session` must be a live handle from `codex_session_start`; `op_json` must
+/// point to a NUL-terminated UTF-8 string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn codex_session_submit(
+    session: *mut CodexFfiSession,
+    op_json: *const c_char,
+) -> i32 {
+    let Some(session) = (unsafe { session.as_ref() }) else {
+        set_last_error("session is null".to_string());
+        return -1;
+    };
+    let op_json = match unsafe { CStr::from_ptr(op_json) }.to_str() {
+        Ok(op_json) => op_json,
+        Err(_) => {
+            set_last_error("op JSON is not valid UTF-8".to_string());
+            return -1;
+        }
+    };
+    let op: Op = match serde_json::from_str(op_json) {
+        Ok(op) => op,
+        Err(err) => {
+            set_last_error(format!("invalid Op JSON: {err}"));
+            return -1;
+        }
+    };
+    match session.runtime.block_on(session.thread.submit(op)) {
+        Ok(_) => 0,
+        Err(err) => {
+            set_last_error(format!("submit failed: {err}"));
+            -1
+        }
+    }
+}
+
+/// Wait up to `timeout_ms` for the next [`Event`] and return it as JSON.
+/// Returns null on timeout, channel close, or error (see `codex_last_error`);
+/// free non-null results with `codex_string_free`.
+///
+/// # Safety
+///
+/// `session` must be a live handle from `codex_session_start`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn codex_session_next_event(
+    session: *mut CodexFfiSession,
+    timeout_ms: i64,
+) -> *mut c_char {
+    let Some(session) = (unsafe { session.as_ref() }) else {
+        set_last_error("session is null".to_string());
+        return std::ptr::null_mut();
+    };
+    let timeout = Duration::from_millis(timeout_ms.max(0) as u64);
+    let event = session.runtime.block_on(async {
+        tokio::time::timeout(timeout, session.thread.next_event())
+            .await
+            .ok()
+    });
+    let event = match event {
+        Some(Ok(event)) => event,
+        Some(Err(err)) => {
+            set_last_error(format!("next_event failed: {err}"));
+            return std::ptr::null_mut();
+        }
+        None => return std::ptr::null_mut(),
+    };
+    match serde_json::to_string(&event) {
+        Ok(json) => CString::new(json)
+            .map(CString::into_raw)
+            .unwrap_or(std::ptr::null_mut()),
+        Err(err) => {
+            set_last_error(format!("failed to serialize event: {err}"));
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Return the last error message for this thread, or null. Free with
+/// `codex_string_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn codex_last_error() -> *mut c_char {
+    LAST_ERROR.with(|slot| {
+        slot.borrow_mut()
+            .take()
+            .map(CString::into_raw)
+            .unwrap_or(std::ptr::null_mut())
+    })
+}
+
+/// Free a string returned by this library.
+///
+/// # Safety
+///
+/// `string` must have been returned by this library and not freed before.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn codex_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Shut down and free a session handle.
+///
+/// # Safety
+///
+/// `session` must have been returned by `codex_session_start` and not freed
+/// before.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn codex_session_free(session: *mut CodexFfiSession) {
+    if session.is_null() {
+        return;
+    }
+    let session = unsafe { Box::from_raw(session) };
+    let _ = session
+        .runtime
+        .block_on(session.thread.submit(Op::Shutdown));
+    session.runtime.shutdown_timeout(Duration::from_secs(5));
+}
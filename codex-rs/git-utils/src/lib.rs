@@ -6,6 +6,8 @@ mod fsmonitor;
 mod info;
 mod operations;
 mod platform;
+mod snapshot;
+mod worktree;
 
 pub use apply::ApplyGitRequest;
 pub use apply::ApplyGitResult;
@@ -43,3 +45,7 @@ pub use info::local_git_branches;
 pub use info::recent_commits;
 pub use info::resolve_root_git_project_for_trust;
 pub use platform::create_symlink;
+pub use snapshot::create_turn_snapshot;
+pub use snapshot::restore_turn_snapshot;
+pub use worktree::TaskWorktree;
+pub use worktree::create_task_worktree;
@@ -0,0 +1,156 @@
+//! Lightweight per-turn git snapshots used as a safety net for agent edits.
+//!
+//! Snapshots are created with `git stash create`, which records the working
+//! tree (and index) as a dangling commit without moving `HEAD` or touching the
+//! user's stash list. Each snapshot is pinned under `refs/codex/snapshots/` so
+//! it survives `git gc` until the ref is overwritten or deleted.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::path::PathBuf;
+
+use tokio::task;
+
+use crate::GitToolingError;
+use crate::operations::ensure_git_repository;
+use crate::operations::resolve_head;
+use crate::operations::run_git_for_status;
+use crate::operations::run_git_for_stdout;
+
+const SNAPSHOT_REF_PREFIX: &str = "refs/codex/snapshots";
+
+/// Snapshot the working tree at `cwd` without disturbing `HEAD`, the index,
+/// or the stash list. Returns `Ok(None)` when `cwd` is not inside a git
+/// repository or the repository has no commits yet.
+pub async fn create_turn_snapshot(
+    cwd: &Path,
+    turn_id: &str,
+) -> Result<Option<String>, GitToolingError> {
+    let cwd = cwd.to_path_buf();
+    let turn_id = turn_id.to_string();
+    task::spawn_blocking(move || create_turn_snapshot_blocking(&cwd, &turn_id))
+        .await
+        .map_err(|source| GitToolingError::Io(std::io::Error::other(source)))?
+}
+
+fn create_turn_snapshot_blocking(
+    cwd: &Path,
+    turn_id: &str,
+) -> Result<Option<String>, GitToolingError> {
+    if ensure_git_repository(cwd).is_err() {
+        return Ok(None);
+    }
+    let Some(head) = resolve_head(cwd)? else {
+        // `git stash create` requires at least one commit.
+        return Ok(None);
+    };
+
+    let stashed = run_git_for_stdout(
+        cwd,
+        vec![
+            OsString::from("stash"),
+            OsString::from("create"),
+            OsString::from(format!("codex snapshot for turn {turn_id}")),
+        ],
+        /*env*/ None,
+    )?;
+    // A clean working tree produces no stash commit; the snapshot is HEAD.
+    let commit = if stashed.is_empty() { head } else { stashed };
+
+    // Pin the commit so it is not garbage collected while the session runs.
+    run_git_for_status(
+        cwd,
+        vec![
+            OsString::from("update-ref"),
+            OsString::from(format!("{SNAPSHOT_REF_PREFIX}/{turn_id}")),
+            OsString::from(commit.as_str()),
+        ],
+        /*env*/ None,
+    )?;
+
+    Ok(Some(commit))
+}
+
+/// Restore tracked files (working tree and index) to the state captured by
+/// `snapshot_commit`. Files created after the snapshot are deliberately left
+/// in place so a revert never deletes user data.
+pub async fn restore_turn_snapshot(
+    cwd: &Path,
+    snapshot_commit: &str,
+) -> Result<(), GitToolingError> {
+    let cwd: PathBuf = cwd.to_path_buf();
+    let snapshot_commit = snapshot_commit.to_string();
+    task::spawn_blocking(move || {
+        ensure_git_repository(&cwd)?;
+        run_git_for_status(
+            &cwd,
+            vec![
+                OsString::from("restore"),
+                OsString::from("--source"),
+                OsString::from(snapshot_commit.as_str()),
+                OsString::from("--worktree"),
+                OsString::from("--staged"),
+                OsString::from("--"),
+                OsString::from(":/"),
+            ],
+            /*env*/ None,
+        )
+    })
+    .await
+    .map_err(|source| GitToolingError::Io(std::io::Error::other(source)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn init_repo(dir: &Path) {
+        git(dir, &["init", "--quiet"]);
+        git(dir, &["config", "user.email", "codex@example.com"]);
+        git(dir, &["config", "user.name", "Codex"]);
+        std::fs::write(dir.join("file.txt"), "original\n").expect("write file");
+        git(dir, &["add", "."]);
+        git(dir, &["commit", "--quiet", "-m", "initial"]);
+    }
+
+    #[tokio::test]
+    async fn snapshot_and_restore_round_trips_tracked_edits() {
+        let repo = TempDir::new().expect("create repo dir");
+        init_repo(repo.path());
+
+        std::fs::write(repo.path().join("file.txt"), "before turn\n").expect("write file");
+        let snapshot = create_turn_snapshot(repo.path(), "turn-1")
+            .await
+            .expect("snapshot should succeed")
+            .expect("repo should produce a snapshot");
+
+        std::fs::write(repo.path().join("file.txt"), "clobbered by agent\n").expect("write file");
+        restore_turn_snapshot(repo.path(), &snapshot)
+            .await
+            .expect("restore should succeed");
+
+        let contents = std::fs::read_to_string(repo.path().join("file.txt")).expect("read file");
+        assert_eq!(contents, "before turn\n");
+    }
+
+    #[tokio::test]
+    async fn snapshot_outside_a_repository_is_none() {
+        let dir = TempDir::new().expect("create dir");
+        let snapshot = create_turn_snapshot(dir.path(), "turn-1")
+            .await
+            .expect("snapshot should succeed");
+        assert_eq!(snapshot, None);
+    }
+}
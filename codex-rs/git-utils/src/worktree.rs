@@ -0,0 +1,115 @@
+//! Temporary git worktrees for isolated batch/parallel task runs.
+//!
+//! Each task gets its own checkout and branch under
+//! `<repo>/.git/codex-worktrees/`, so concurrent tasks never trample each
+//! other's working tree. Callers report the worktree path and branch back to
+//! the user; cleanup is deliberately left to the user (or `git worktree
+//! prune`) because the worktree holds the task's results.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::path::PathBuf;
+
+use tokio::task;
+
+use crate::GitToolingError;
+use crate::operations::ensure_git_repository;
+use crate::operations::resolve_repository_root;
+use crate::operations::run_git_for_status;
+
+const WORKTREES_SUBDIR: &str = "codex-worktrees";
+
+/// A task worktree created by [`create_task_worktree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskWorktree {
+    /// Checkout directory for the task.
+    pub path: PathBuf,
+    /// Branch created for the task, based on the repository's current `HEAD`.
+    pub branch: String,
+}
+
+/// Create a worktree and branch for the named task off the repository that
+/// contains `repo_cwd`. The name must be unique within the run; it becomes
+/// both the directory name and the `codex/<name>` branch suffix.
+pub async fn create_task_worktree(
+    repo_cwd: &Path,
+    name: &str,
+) -> Result<TaskWorktree, GitToolingError> {
+    let repo_cwd = repo_cwd.to_path_buf();
+    let name = name.to_string();
+    task::spawn_blocking(move || create_task_worktree_blocking(&repo_cwd, &name))
+        .await
+        .map_err(|source| GitToolingError::Io(std::io::Error::other(source)))?
+}
+
+fn create_task_worktree_blocking(
+    repo_cwd: &Path,
+    name: &str,
+) -> Result<TaskWorktree, GitToolingError> {
+    ensure_git_repository(repo_cwd)?;
+    let repo_root = resolve_repository_root(repo_cwd)?;
+    let path = repo_root.join(".git").join(WORKTREES_SUBDIR).join(name);
+    let branch = format!("codex/{name}");
+    run_git_for_status(
+        repo_cwd,
+        vec![
+            OsString::from("worktree"),
+            OsString::from("add"),
+            OsString::from("-b"),
+            OsString::from(branch.as_str()),
+            path.clone().into_os_string(),
+        ],
+        /*env*/ None,
+    )?;
+    Ok(TaskWorktree { path, branch })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn init_repo(dir: &Path) {
+        git(dir, &["init", "--quiet"]);
+        git(dir, &["config", "user.email", "codex@example.com"]);
+        git(dir, &["config", "user.name", "Codex"]);
+        std::fs::write(dir.join("file.txt"), "original\n").expect("write file");
+        git(dir, &["add", "."]);
+        git(dir, &["commit", "--quiet", "-m", "initial"]);
+    }
+
+    #[tokio::test]
+    async fn creates_an_isolated_worktree_and_branch() {
+        let repo = TempDir::new().expect("create repo dir");
+        init_repo(repo.path());
+
+        let worktree = create_task_worktree(repo.path(), "batch-0")
+            .await
+            .expect("create worktree");
+
+        assert_eq!(worktree.branch, "codex/batch-0");
+        assert!(worktree.path.join("file.txt").exists());
+        // Edits in the worktree do not affect the main checkout.
+        std::fs::write(worktree.path.join("file.txt"), "changed\n").expect("write file");
+        let original =
+            std::fs::read_to_string(repo.path().join("file.txt")).expect("read original");
+        assert_eq!(original, "original\n");
+    }
+
+    #[tokio::test]
+    async fn fails_outside_a_git_repository() {
+        let dir = TempDir::new().expect("create dir");
+        assert!(create_task_worktree(dir.path(), "batch-0").await.is_err());
+    }
+}
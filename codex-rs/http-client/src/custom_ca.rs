@@ -60,6 +60,9 @@ use tracing::warn;
 
 pub const CODEX_CA_CERT_ENV: &str = "CODEX_CA_CERTIFICATE";
 pub const SSL_CERT_FILE_ENV: &str = "SSL_CERT_FILE";
+/// PEM file containing a client certificate chain and private key for mutual
+/// TLS against corporate gateways.
+pub const CODEX_CLIENT_CERT_ENV: &str = "CODEX_CLIENT_CERTIFICATE";
 const CA_CERT_HINT: &str = "If you set CODEX_CA_CERTIFICATE or SSL_CERT_FILE, ensure it points to a PEM file containing one or more CERTIFICATE blocks, or unset it to use system roots.";
 type PemSection = (SectionKind, Vec<u8>);
 
@@ -129,6 +132,24 @@ pub enum BuildCustomCaTransportError {
     #[error("Failed to build HTTP client while using system root certificates: {0}")]
     BuildClientWithSystemRoots(#[source] reqwest::Error),
 
+    /// Reading the configured client certificate identity from disk failed.
+    #[error(
+        "Failed to read client certificate file {} selected by {CODEX_CLIENT_CERT_ENV}: {source}",
+        path.display()
+    )]
+    ReadClientIdentity { path: PathBuf, source: io::Error },
+
+    /// The configured client certificate file was not a usable PEM identity.
+    #[error(
+        "Failed to parse client certificate identity from {} selected by {CODEX_CLIENT_CERT_ENV}: {source}. Ensure the PEM file contains the certificate chain and private key.",
+        path.display()
+    )]
+    InvalidClientIdentity {
+        path: PathBuf,
+        #[source]
+        source: reqwest::Error,
+    },
+
     /// One parsed certificate block could not be registered with the websocket TLS root store.
     #[error(
         "Failed to register certificate #{certificate_index} from {} selected by {} in rustls root store: {source}. {hint}",
@@ -147,12 +168,14 @@ pub enum BuildCustomCaTransportError {
 impl From<BuildCustomCaTransportError> for io::Error {
     fn from(error: BuildCustomCaTransportError) -> Self {
         match error {
-            BuildCustomCaTransportError::ReadCaFile { ref source, .. } => {
+            BuildCustomCaTransportError::ReadCaFile { ref source, .. }
+            | BuildCustomCaTransportError::ReadClientIdentity { ref source, .. } => {
                 io::Error::new(source.kind(), error)
             }
             BuildCustomCaTransportError::InvalidCaFile { .. }
             | BuildCustomCaTransportError::RegisterCertificate { .. }
-            | BuildCustomCaTransportError::RegisterRustlsCertificate { .. } => {
+            | BuildCustomCaTransportError::RegisterRustlsCertificate { .. }
+            | BuildCustomCaTransportError::InvalidClientIdentity { .. } => {
                 io::Error::new(io::ErrorKind::InvalidData, error)
             }
             BuildCustomCaTransportError::BuildClientWithCustomCa { .. }
@@ -297,6 +320,7 @@ fn build_reqwest_client_with_env(
     env_source: &dyn EnvSource,
     mut builder: reqwest::ClientBuilder,
 ) -> Result<reqwest::Client, BuildCustomCaTransportError> {
+    builder = apply_client_identity(env_source, builder)?;
     if let Some(bundle) = env_source.configured_ca_bundle() {
         ensure_rustls_crypto_provider();
         info!(
@@ -367,6 +391,37 @@ fn build_reqwest_client_with_env(
     }
 }
 
+/// Applies an optional mutual-TLS client identity from
+/// `CODEX_CLIENT_CERTIFICATE` (a PEM file containing the certificate chain
+/// and private key). Shared by every client built through this module so
+/// corporate gateways that require client certificates work for model
+/// provider, MCP, and bootstrap traffic alike.
+fn apply_client_identity(
+    env_source: &dyn EnvSource,
+    builder: reqwest::ClientBuilder,
+) -> Result<reqwest::ClientBuilder, BuildCustomCaTransportError> {
+    let Some(path) = env_source.non_empty_path(CODEX_CLIENT_CERT_ENV) else {
+        return Ok(builder);
+    };
+    ensure_rustls_crypto_provider();
+    let pem =
+        std::fs::read(&path).map_err(|source| BuildCustomCaTransportError::ReadClientIdentity {
+            path: path.clone(),
+            source,
+        })?;
+    let identity = reqwest::Identity::from_pem(&pem).map_err(|source| {
+        BuildCustomCaTransportError::InvalidClientIdentity {
+            path: path.clone(),
+            source,
+        }
+    })?;
+    info!(
+        client_certificate = %path.display(),
+        "building HTTP client with mutual-TLS client identity"
+    );
+    Ok(builder.use_rustls_tls().identity(identity))
+}
+
 /// Abstracts environment access so tests can cover precedence rules without mutating process-wide
 /// variables.
 trait EnvSource {
@@ -16,6 +16,7 @@ pub use crate::custom_ca::BuildCustomCaTransportError;
 /// is hidden from normal docs because ordinary callers should use
 /// [`build_reqwest_client_with_custom_ca`] instead.
 #[doc(hidden)]
+pub use crate::custom_ca::CODEX_CLIENT_CERT_ENV;
 pub use crate::custom_ca::build_reqwest_client_for_subprocess_tests;
 pub use crate::custom_ca::build_reqwest_client_with_custom_ca;
 pub use crate::custom_ca::build_rustls_client_config_with_custom_ca;
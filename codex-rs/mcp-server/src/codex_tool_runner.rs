@@ -233,6 +233,7 @@ async fn run_codex_tool_session_inner(
                             network_approval_context: _,
                             additional_permissions: _,
                             available_decisions: _,
+                            explanation: _,
                         } = ev;
                         handle_exec_approval_request(
                             command,
@@ -324,7 +325,10 @@ async fn run_codex_tool_session_inner(
                     EventMsg::ThreadGoalUpdated(_) => {
                         // Ignore thread goal metadata updates in MCP tool runner.
                     }
-                    EventMsg::McpStartupUpdate(_) | EventMsg::McpStartupComplete(_) => {
+                    EventMsg::McpStartupUpdate(_)
+                    | EventMsg::McpStartupComplete(_)
+                    | EventMsg::McpStatus(_)
+                    | EventMsg::ConversationExported(_) => {
                         // Ignored in MCP tool runner.
                     }
                     EventMsg::AgentMessage(AgentMessageEvent { .. }) => {
@@ -344,6 +348,7 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::ExecCommandOutputDelta(_)
                     | EventMsg::ExecCommandEnd(_)
                     | EventMsg::StreamError(_)
+                    | EventMsg::StreamRetrying(_)
                     | EventMsg::PatchApplyBegin(_)
                     | EventMsg::PatchApplyUpdated(_)
                     | EventMsg::PatchApplyEnd(_)
@@ -374,6 +379,15 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::ContextCompacted(_)
                     | EventMsg::ModelReroute(_)
                     | EventMsg::ThreadRolledBack(_)
+                    | EventMsg::ThreadCheckpoint(_)
+                    | EventMsg::GitSnapshot(_)
+                    | EventMsg::TurnReverted(_)
+                    | EventMsg::ApprovalLog(_)
+                    | EventMsg::FilesChanged(_)
+                    | EventMsg::EffectiveConfig(_)
+                    | EventMsg::ConfigReloaded(_)
+                    | EventMsg::SessionStatus(_)
+                    | EventMsg::SessionInfo(_)
                     | EventMsg::CollabAgentSpawnBegin(_)
                     | EventMsg::CollabAgentSpawnEnd(_)
                     | EventMsg::CollabAgentInteractionBegin(_)
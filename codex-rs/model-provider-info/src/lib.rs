@@ -422,9 +422,13 @@ impl ModelProviderInfo {
 
 pub const DEFAULT_LMSTUDIO_PORT: u16 = 1234;
 pub const DEFAULT_OLLAMA_PORT: u16 = 11434;
+pub const DEFAULT_LLAMACPP_PORT: u16 = 8080;
+pub const DEFAULT_VLLM_PORT: u16 = 8000;
 
 pub const LMSTUDIO_OSS_PROVIDER_ID: &str = "lmstudio";
 pub const OLLAMA_OSS_PROVIDER_ID: &str = "ollama";
+pub const LLAMACPP_OSS_PROVIDER_ID: &str = "llamacpp";
+pub const VLLM_OSS_PROVIDER_ID: &str = "vllm";
 
 /// Built-in default provider list.
 pub fn built_in_model_providers(
@@ -449,6 +453,14 @@ pub fn built_in_model_providers(
             LMSTUDIO_OSS_PROVIDER_ID,
             create_oss_provider(DEFAULT_LMSTUDIO_PORT, WireApi::Responses),
         ),
+        (
+            LLAMACPP_OSS_PROVIDER_ID,
+            create_oss_provider(DEFAULT_LLAMACPP_PORT, WireApi::Responses),
+        ),
+        (
+            VLLM_OSS_PROVIDER_ID,
+            create_oss_provider(DEFAULT_VLLM_PORT, WireApi::Responses),
+        ),
     ]
     .into_iter()
     .map(|(k, v)| (k.to_string(), v))
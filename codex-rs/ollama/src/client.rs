@@ -64,10 +64,10 @@ impl OllamaClient {
             .expect("oss provider must have a base_url");
         let uses_openai_compat = is_openai_compatible_base_url(base_url);
         let host_root = base_url_to_host_root(base_url);
-        let client = reqwest::Client::builder()
-            .connect_timeout(std::time::Duration::from_secs(5))
-            .build()
-            .unwrap_or_else(|_| reqwest::Client::new());
+        let client = codex_http_client::build_reqwest_client_with_custom_ca(
+            reqwest::Client::builder().connect_timeout(std::time::Duration::from_secs(5)),
+        )
+        .unwrap_or_else(|_| reqwest::Client::new());
         let client = Self {
             client,
             host_root,
@@ -272,6 +272,11 @@ pub struct ExecApprovalRequestEvent {
     #[ts(optional)]
     pub available_decisions: Option<Vec<ReviewDecision>>,
     pub parsed_cmd: Vec<ParsedCommand>,
+    /// One-line heuristic explanation of what the command does, for display
+    /// next to the approval prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub explanation: Option<String>,
 }
 
 impl ExecApprovalRequestEvent {
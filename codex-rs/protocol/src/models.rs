@@ -1763,6 +1763,14 @@ impl ResponseInputItem {
                         }
                     }
                     UserInput::Skill { .. } | UserInput::Mention { .. } => Vec::new(), // Tool bodies are injected later in core
+                    // Expanded to Text by core before serialization; if one
+                    // slips through, surface a placeholder instead of silence.
+                    UserInput::LocalFile { path } => vec![ContentItem::InputText {
+                        text: format!("[unresolved file attachment: {}]", path.display()),
+                    }],
+                    UserInput::Url { url } => vec![ContentItem::InputText {
+                        text: format!("[unresolved url attachment: {url}]"),
+                    }],
                 })
                 .collect::<Vec<ContentItem>>(),
             phase: None,
@@ -15,6 +15,12 @@ pub enum ParsedCommand {
         /// be resolved against the `cwd`` that will be used to run the command
         /// to derive the absolute path.
         path: PathBuf,
+        /// 1-based inclusive line range when the read targets a slice, e.g.
+        /// `sed -n '10,20p'`, `head -n 50`, or `tail -n +10` (open ends use
+        /// `u32::MAX`).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        range: Option<(u32, u32)>,
     },
     ListFiles {
         cmd: String,
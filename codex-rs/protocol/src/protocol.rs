@@ -640,6 +640,28 @@ pub enum Op {
     /// Request MCP servers to reinitialize and refresh cached tool lists.
     RefreshMcpServers { config: McpServerRefreshConfig },
 
+    /// Request a health check of the configured MCP servers. The session
+    /// responds with an [`EventMsg::McpStatus`] event.
+    McpStatus,
+
+    /// Tear down and re-spawn a single MCP server. Startup progress is
+    /// reported through the usual MCP startup events.
+    RestartMcpServer { server: String },
+
+    /// Trust a command pattern so future matching invocations skip the
+    /// approval prompt, scoped to this session or persisted to the user's
+    /// execpolicy rules.
+    TrustCommand {
+        /// Command argv prefix to trust.
+        pattern: Vec<String>,
+        scope: TrustCommandScope,
+    },
+
+    /// Export the current conversation as a portable `.codexsession` archive
+    /// at the given path. The session responds with an
+    /// [`EventMsg::ConversationExported`] event on success.
+    ExportConversation { path: PathBuf },
+
     /// Reload user config layer overrides for the active session.
     ///
     /// This updates runtime config-derived behavior (for example app
@@ -663,6 +685,49 @@ pub enum Op {
     /// responsible for undoing any edits on disk.
     ThreadRollback { num_turns: u32 },
 
+    /// Bookmark the current conversation state under a name so it can be
+    /// returned to precisely with [`Op::ForkFromCheckpoint`].
+    CreateCheckpoint { name: String },
+
+    /// Drop all user turns recorded after the named checkpoint, as if
+    /// [`Op::ThreadRollback`] had been issued with the exact count.
+    ForkFromCheckpoint { name: String },
+
+    /// Restore the working tree to the git snapshot taken before the last
+    /// turn. Only available when `git_snapshots` is enabled in config.
+    RevertLastTurn,
+
+    /// Undo the last `num_turns` user turns in place: restores the most
+    /// recent git snapshot when one was recorded (best effort) and rolls the
+    /// conversation back as if `Op::ThreadRollback` had been issued.
+    UndoTurns { num_turns: u32 },
+
+    /// Adjust parallel tool-call dispatch limits live. `None` fields keep
+    /// their current value; a cap of `0` removes the concurrency limit.
+    SetParallelConfig {
+        enabled: Option<bool>,
+        max_concurrent_calls: Option<u64>,
+        min_delay_ms: Option<u64>,
+    },
+
+    /// Request the approval audit log recorded under codex_home. The session
+    /// responds with an [`EventMsg::ApprovalLog`] event.
+    GetApprovalLog,
+
+    /// Request the fully merged configuration (secrets masked) as JSON. The
+    /// session responds with an [`EventMsg::EffectiveConfig`] event.
+    GetEffectiveConfig,
+
+    /// Request the session's identity: session UUID, thread id, rollout
+    /// path, and user turn count. The same information is emitted
+    /// proactively in `SessionConfigured`; this op lets late-attaching
+    /// clients (e.g. the TUI backtrack flow) re-query it on demand.
+    GetSessionInfo,
+
+    /// Request session diagnostics. The session responds with an
+    /// [`EventMsg::SessionStatus`] event.
+    Status,
+
     /// Request a code review from the agent.
     Review { review_request: ReviewRequest },
 
@@ -683,6 +748,17 @@ pub enum Op {
     },
 }
 
+/// How long a [`Op::TrustCommand`] grant lasts.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TrustCommandScope {
+    /// Trust for the remainder of this session only.
+    Session,
+    /// Append a prefix rule to the user's execpolicy so the trust persists
+    /// across sessions.
+    Persistent,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ThreadMemoryMode {
@@ -880,10 +956,23 @@ impl Op {
             Self::RequestPermissionsResponse { .. } => "request_permissions_response",
             Self::DynamicToolResponse { .. } => "dynamic_tool_response",
             Self::RefreshMcpServers { .. } => "refresh_mcp_servers",
+            Self::McpStatus => "mcp_status",
+            Self::RestartMcpServer { .. } => "restart_mcp_server",
+            Self::TrustCommand { .. } => "trust_command",
+            Self::ExportConversation { .. } => "export_conversation",
             Self::ReloadUserConfig => "reload_user_config",
             Self::Compact => "compact",
             Self::SetThreadMemoryMode { .. } => "set_thread_memory_mode",
             Self::ThreadRollback { .. } => "thread_rollback",
+            Self::CreateCheckpoint { .. } => "create_checkpoint",
+            Self::ForkFromCheckpoint { .. } => "fork_from_checkpoint",
+            Self::RevertLastTurn => "revert_last_turn",
+            Self::UndoTurns { .. } => "undo_turns",
+            Self::SetParallelConfig { .. } => "set_parallel_config",
+            Self::GetApprovalLog => "get_approval_log",
+            Self::GetEffectiveConfig => "get_effective_config",
+            Self::GetSessionInfo => "get_session_info",
+            Self::Status => "status",
             Self::Review { .. } => "review",
             Self::ApproveGuardianDeniedAction { .. } => "approve_guardian_denied_action",
             Self::Shutdown => "shutdown",
@@ -1317,6 +1406,35 @@ pub enum EventMsg {
     /// Conversation history was rolled back by dropping the last N user turns.
     ThreadRolledBack(ThreadRolledBackEvent),
 
+    /// Named checkpoint recorded in the conversation history.
+    ThreadCheckpoint(ThreadCheckpointEvent),
+
+    /// Git snapshot taken before a turn started mutating the working tree.
+    GitSnapshot(GitSnapshotEvent),
+
+    /// The working tree was restored to the snapshot taken before a turn.
+    TurnReverted(TurnRevertedEvent),
+
+    /// Contents of the approval audit log, in response to
+    /// [`Op::GetApprovalLog`].
+    ApprovalLog(ApprovalLogEvent),
+
+    /// Summary of every file the session changed, emitted at shutdown.
+    FilesChanged(FilesChangedEvent),
+
+    /// Fully merged configuration (secrets masked), in response to
+    /// [`Op::GetEffectiveConfig`].
+    EffectiveConfig(EffectiveConfigEvent),
+
+    /// Session diagnostics, in response to [`Op::Status`].
+    SessionStatus(SessionStatusEvent),
+
+    /// Response to `Op::GetSessionInfo`.
+    SessionInfo(SessionInfoEvent),
+
+    /// User config layers were re-parsed and applied after an on-disk change.
+    ConfigReloaded(ConfigReloadedEvent),
+
     /// Agent has started a turn.
     /// v1 wire format uses `task_started`; accept `turn_started` for v2 interop.
     #[serde(rename = "task_started", alias = "turn_started")]
@@ -1362,6 +1480,12 @@ pub enum EventMsg {
     /// Aggregate MCP startup completion summary.
     McpStartupComplete(McpStartupCompleteEvent),
 
+    /// Point-in-time health snapshot of the configured MCP servers.
+    McpStatus(McpStatusEvent),
+
+    /// The conversation was exported to a portable session archive.
+    ConversationExported(ConversationExportedEvent),
+
     McpToolCallBegin(McpToolCallBeginEvent),
 
     McpToolCallEnd(McpToolCallEndEvent),
@@ -1413,6 +1537,10 @@ pub enum EventMsg {
     /// and the system is handling it (e.g., retrying with backoff).
     StreamError(StreamErrorEvent),
 
+    /// Structured notification that a dropped model stream is being retried
+    /// with backoff; lets front-ends explain why output paused.
+    StreamRetrying(StreamRetryingEvent),
+
     /// Notification that the agent is about to apply a code patch. Mirrors
     /// `ExecCommandBegin` so front‑ends can show progress indicators.
     PatchApplyBegin(PatchApplyBeginEvent),
@@ -3325,6 +3453,12 @@ impl Mul<f64> for TruncationPolicy {
 #[derive(Serialize, Deserialize, Clone, JsonSchema)]
 pub struct RolloutLine {
     pub timestamp: String,
+    /// Turn id of the turn this line was recorded under (v2 envelopes).
+    /// `TurnStarted`/`TurnComplete` event lines mark the explicit
+    /// boundaries; lines written outside any turn, and all v1 files, carry
+    /// no turn so old rollouts keep deserializing unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turn: Option<String>,
     #[serde(flatten)]
     pub item: RolloutItem,
 }
@@ -3576,6 +3710,103 @@ pub struct ThreadRolledBackEvent {
     pub num_turns: u32,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionInfoEvent {
+    pub session_id: SessionId,
+    pub thread_id: ThreadId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub rollout_path: Option<PathBuf>,
+    /// Number of user turns recorded in this session.
+    pub user_turn_count: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionStatusEvent {
+    /// Effective model for new turns.
+    pub model: String,
+    pub model_provider_id: String,
+    pub approval_policy: AskForApproval,
+    /// Active permission profile, when one is selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub active_permission_profile: Option<ActivePermissionProfile>,
+    /// Total token usage so far, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub token_usage: Option<TokenUsage>,
+    /// Number of MCP servers that completed startup.
+    pub mcp_servers_connected: u64,
+    /// Number of live background terminal processes.
+    pub active_exec_sessions: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub rollout_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, Default)]
+pub struct ConfigReloadedEvent {
+    /// Config files whose change triggered the reload.
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, Default)]
+pub struct EffectiveConfigEvent {
+    /// Merged configuration rendered as JSON with secret values masked.
+    pub config: Value,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, Default)]
+pub struct FilesChangedEvent {
+    pub files: Vec<ChangedFileSummary>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq, Eq)]
+pub struct ChangedFileSummary {
+    pub path: PathBuf,
+    pub kind: ChangedFileKind,
+    pub lines_added: u64,
+    pub lines_removed: u64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema, TS, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[ts(rename_all = "snake_case")]
+pub enum ChangedFileKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, Default)]
+pub struct ApprovalLogEvent {
+    /// Audit entries as recorded in `approvals.jsonl`, oldest first.
+    pub entries: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct GitSnapshotEvent {
+    /// Turn the snapshot was taken for.
+    pub turn_id: String,
+    /// Snapshot commit SHA, pinned under `refs/codex/snapshots/`.
+    pub commit: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct TurnRevertedEvent {
+    /// Snapshot commit the working tree was restored to.
+    pub commit: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ThreadCheckpointEvent {
+    /// User-chosen checkpoint name. Creating a checkpoint with an existing
+    /// name shadows the earlier one.
+    pub name: String,
+    /// Number of user turns recorded when the checkpoint was created.
+    pub user_turn_count: u32,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct StreamErrorEvent {
     pub message: String,
@@ -3593,6 +3824,16 @@ pub struct StreamInfoEvent {
     pub message: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct StreamRetryingEvent {
+    /// 1-based retry attempt about to be made.
+    pub attempt: u64,
+    /// Retry budget for this stream.
+    pub max_attempts: u64,
+    /// Delay before the retry attempt starts.
+    pub delay_ms: u64,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct PatchApplyBeginEvent {
     /// Identifier so this can be paired with the PatchApplyEnd event.
@@ -3692,6 +3933,32 @@ pub struct McpStartupFailure {
     pub error: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ConversationExportedEvent {
+    /// Final path of the written archive.
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, Default)]
+pub struct McpStatusEvent {
+    pub servers: Vec<McpServerHealth>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct McpServerHealth {
+    /// Server name as configured.
+    pub server: String,
+    /// Whether the server responded to the health probe.
+    pub connected: bool,
+    /// Number of tools currently advertised by the server.
+    pub tool_count: u64,
+    /// Round-trip latency of the health probe. Absent when the probe failed
+    /// or the server never finished starting up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional = nullable)]
+    pub latency_ms: Option<u64>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, TS)]
 #[serde(rename_all = "snake_case")]
 #[ts(rename_all = "snake_case")]
@@ -49,6 +49,14 @@ pub enum UserInput {
     /// `path` identifies the exact mention target, for example
     /// `app://<connector-id>` or `plugin://<plugin-name>@<marketplace-name>`.
     Mention { name: String, path: String },
+
+    /// Local file whose textual contents should be attached to the prompt.
+    /// Core reads and truncates the file before request serialization.
+    LocalFile { path: std::path::PathBuf },
+
+    /// URL whose fetched contents should be attached to the prompt. Core
+    /// fetches the page before request serialization.
+    Url { url: String },
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, TS, JsonSchema)]
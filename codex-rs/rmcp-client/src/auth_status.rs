@@ -17,6 +17,7 @@ use crate::oauth::StoredOAuthTokenStatus;
 use crate::oauth::oauth_token_status;
 use crate::oauth_http_client::OAuthHttpClientAdapter;
 use crate::utils::apply_default_headers;
+use crate::utils::apply_network_policy;
 use crate::utils::build_default_headers;
 use codex_config::types::AuthKeyringBackendKind;
 use codex_config::types::OAuthCredentialsStoreMode;
@@ -208,9 +209,7 @@ async fn discover_streamable_http_oauth_with_headers(
     url: &str,
     default_headers: &HeaderMap,
 ) -> Result<Option<StreamableHttpOAuthDiscovery>> {
-    // Use no_proxy to avoid a bug in the system-configuration crate that
-    // can result in a panic. See #8912.
-    let builder = Client::builder().timeout(DISCOVERY_TIMEOUT).no_proxy();
+    let builder = apply_network_policy(Client::builder().timeout(DISCOVERY_TIMEOUT));
     let client = apply_default_headers(builder, default_headers).build()?;
     let mut authorization_manager = AuthorizationManager::new(url).await?;
     authorization_manager.with_client(client)?;
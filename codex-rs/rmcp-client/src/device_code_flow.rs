@@ -0,0 +1,316 @@
+//! OAuth 2.0 Device Authorization Grant (RFC 8628) login for MCP servers.
+//!
+//! This is the fallback for environments where the authorization-code flow in
+//! `perform_oauth_login` cannot work because no local callback server can
+//! receive the browser redirect (headless hosts, remote shells, containers).
+//! The user authorizes on a second device using a short user code while Codex
+//! polls the token endpoint.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use anyhow::bail;
+use reqwest::Client;
+use reqwest::Url;
+use rmcp::transport::auth::OAuthTokenResponse;
+use serde::Deserialize;
+use tokio::time::Instant;
+use tokio::time::sleep;
+
+use crate::StoredOAuthTokens;
+use crate::WrappedOAuthTokenResponse;
+use crate::oauth::compute_expires_at_millis;
+use crate::save_oauth_tokens;
+use crate::utils::apply_default_headers;
+use crate::utils::apply_network_policy;
+use crate::utils::build_default_headers;
+use codex_config::types::AuthKeyringBackendKind;
+use codex_config::types::OAuthCredentialsStoreMode;
+
+const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Extra delay added on top of the current interval when the provider answers
+/// `slow_down`, as required by RFC 8628 §3.5.
+const SLOW_DOWN_BACKOFF: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Deserialize)]
+struct AuthorizationServerMetadata {
+    token_endpoint: String,
+    #[serde(default)]
+    device_authorization_endpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct DeviceAuthorizationResponse {
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    #[serde(default)]
+    verification_uri_complete: Option<String>,
+    expires_in: u64,
+    #[serde(default)]
+    interval: Option<u64>,
+}
+
+impl DeviceAuthorizationResponse {
+    fn poll_interval(&self) -> Duration {
+        self.interval
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_POLL_INTERVAL)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DeviceTokenError {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// Authenticate with an MCP server using the device authorization grant and
+/// persist the resulting tokens the same way the redirect-based login does.
+#[allow(clippy::too_many_arguments)]
+pub async fn perform_oauth_device_login(
+    server_name: &str,
+    server_url: &str,
+    store_mode: OAuthCredentialsStoreMode,
+    keyring_backend_kind: AuthKeyringBackendKind,
+    http_headers: Option<HashMap<String, String>>,
+    env_http_headers: Option<HashMap<String, String>>,
+    scopes: &[String],
+    oauth_client_id: Option<&str>,
+) -> Result<()> {
+    let Some(client_id) = oauth_client_id.filter(|client_id| !client_id.trim().is_empty()) else {
+        bail!(
+            "the device code flow requires a pre-registered OAuth client; configure `oauth_client_id` for MCP server `{server_name}`"
+        );
+    };
+
+    let default_headers = build_default_headers(http_headers, env_http_headers)?;
+    let builder = apply_network_policy(Client::builder().timeout(DISCOVERY_TIMEOUT));
+    let client = apply_default_headers(builder, &default_headers).build()?;
+
+    let metadata = discover_authorization_server_metadata(&client, server_url).await?;
+    let Some(device_authorization_endpoint) = metadata.device_authorization_endpoint else {
+        bail!(
+            "MCP server `{server_name}` does not advertise a device authorization endpoint; use `codex mcp login {server_name}` instead"
+        );
+    };
+
+    let mut form: Vec<(&str, String)> = vec![("client_id", client_id.to_string())];
+    let scope = scopes.join(" ");
+    if !scope.trim().is_empty() {
+        form.push(("scope", scope));
+    }
+    let response = client
+        .post(&device_authorization_endpoint)
+        .form(&form)
+        .send()
+        .await
+        .context("device authorization request failed")?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        bail!("device authorization request failed with {status}: {body}");
+    }
+    let authorization: DeviceAuthorizationResponse = response
+        .json()
+        .await
+        .context("failed to parse device authorization response")?;
+
+    announce_verification(server_name, &authorization);
+
+    let token_response =
+        poll_for_device_token(&client, &metadata.token_endpoint, client_id, &authorization).await?;
+
+    let expires_at = compute_expires_at_millis(&token_response);
+    let stored = StoredOAuthTokens {
+        server_name: server_name.to_string(),
+        url: server_url.to_string(),
+        client_id: client_id.to_string(),
+        token_response: WrappedOAuthTokenResponse(token_response),
+        expires_at,
+    };
+    save_oauth_tokens(server_name, &stored, store_mode, keyring_backend_kind)?;
+
+    Ok(())
+}
+
+fn announce_verification(server_name: &str, authorization: &DeviceAuthorizationResponse) {
+    let user_code = &authorization.user_code;
+    let verification_uri = &authorization.verification_uri;
+    println!(
+        "Authorize `{server_name}` by visiting {verification_uri} and entering the code {user_code}\n"
+    );
+
+    let browser_url = authorization
+        .verification_uri_complete
+        .as_deref()
+        .unwrap_or(verification_uri);
+    match crate::utils::open_url(browser_url) {
+        Ok(crate::utils::OpenUrlStatus::Opened) => {}
+        Ok(crate::utils::OpenUrlStatus::Suppressed { reason }) => {
+            eprintln!("(Browser launch suppressed: {reason})");
+        }
+        Err(err) => {
+            eprintln!("(Browser launch failed: {err})");
+        }
+    }
+}
+
+async fn poll_for_device_token(
+    client: &Client,
+    token_endpoint: &str,
+    client_id: &str,
+    authorization: &DeviceAuthorizationResponse,
+) -> Result<OAuthTokenResponse> {
+    let deadline = Instant::now() + Duration::from_secs(authorization.expires_in.max(1));
+    let mut interval = authorization.poll_interval();
+
+    loop {
+        sleep(interval).await;
+        if Instant::now() >= deadline {
+            bail!("timed out waiting for the device authorization to be approved");
+        }
+
+        let response = client
+            .post(token_endpoint)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+                ("device_code", &authorization.device_code),
+                ("client_id", client_id),
+            ])
+            .send()
+            .await
+            .context("device token request failed")?;
+
+        if response.status().is_success() {
+            return response
+                .json::<OAuthTokenResponse>()
+                .await
+                .context("failed to parse device token response");
+        }
+
+        let status = response.status();
+        let body = response.bytes().await.unwrap_or_default();
+        let Ok(token_error) = serde_json::from_slice::<DeviceTokenError>(&body) else {
+            bail!(
+                "device token request failed with {status}: {}",
+                String::from_utf8_lossy(&body)
+            );
+        };
+        match token_error.error.as_str() {
+            "authorization_pending" => {}
+            "slow_down" => {
+                interval += SLOW_DOWN_BACKOFF;
+            }
+            error => {
+                let description = token_error
+                    .error_description
+                    .unwrap_or_else(|| "device authorization was not granted".to_string());
+                return Err(anyhow!("OAuth provider returned `{error}`: {description}"));
+            }
+        }
+    }
+}
+
+async fn discover_authorization_server_metadata(
+    client: &Client,
+    server_url: &str,
+) -> Result<AuthorizationServerMetadata> {
+    for candidate in authorization_server_metadata_urls(server_url)? {
+        let response = match client.get(candidate.clone()).send().await {
+            Ok(response) => response,
+            Err(_) => continue,
+        };
+        if !response.status().is_success() {
+            continue;
+        }
+        if let Ok(metadata) = response.json::<AuthorizationServerMetadata>().await {
+            return Ok(metadata);
+        }
+    }
+    bail!("failed to discover OAuth authorization server metadata for `{server_url}`")
+}
+
+/// Well-known metadata locations per RFC 8414: the path-scoped document is
+/// preferred, falling back to the origin-level document.
+fn authorization_server_metadata_urls(server_url: &str) -> Result<Vec<Url>> {
+    let parsed =
+        Url::parse(server_url).with_context(|| format!("invalid MCP server URL `{server_url}`"))?;
+    let origin = parsed
+        .join("/")
+        .map_err(|err| anyhow!("failed to resolve origin of `{server_url}`: {err}"))?;
+
+    let mut urls = Vec::new();
+    let path = parsed.path().trim_end_matches('/');
+    if !path.is_empty() {
+        urls.push(origin.join(&format!(".well-known/oauth-authorization-server{path}"))?);
+    }
+    urls.push(origin.join(".well-known/oauth-authorization-server")?);
+    Ok(urls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn poll_interval_defaults_when_missing() {
+        let authorization = DeviceAuthorizationResponse {
+            device_code: "device".to_string(),
+            user_code: "ABCD-EFGH".to_string(),
+            verification_uri: "https://example.com/device".to_string(),
+            verification_uri_complete: None,
+            expires_in: 600,
+            interval: None,
+        };
+
+        assert_eq!(authorization.poll_interval(), DEFAULT_POLL_INTERVAL);
+    }
+
+    #[test]
+    fn poll_interval_uses_provider_value() {
+        let authorization: DeviceAuthorizationResponse =
+            serde_json::from_value(serde_json::json!({
+                "device_code": "device",
+                "user_code": "ABCD-EFGH",
+                "verification_uri": "https://example.com/device",
+                "verification_uri_complete": "https://example.com/device?user_code=ABCD-EFGH",
+                "expires_in": 600,
+                "interval": 7,
+            }))
+            .expect("device authorization response should parse");
+
+        assert_eq!(authorization.poll_interval(), Duration::from_secs(7));
+    }
+
+    #[test]
+    fn metadata_urls_prefer_path_scoped_document() {
+        let urls = authorization_server_metadata_urls("https://mcp.example.com/mcp")
+            .expect("server URL should parse");
+
+        assert_eq!(
+            urls.iter().map(Url::as_str).collect::<Vec<_>>(),
+            vec![
+                "https://mcp.example.com/.well-known/oauth-authorization-server/mcp",
+                "https://mcp.example.com/.well-known/oauth-authorization-server",
+            ]
+        );
+    }
+
+    #[test]
+    fn metadata_urls_for_root_server_url() {
+        let urls = authorization_server_metadata_urls("https://mcp.example.com/")
+            .expect("server URL should parse");
+
+        assert_eq!(
+            urls.iter().map(Url::as_str).collect::<Vec<_>>(),
+            vec!["https://mcp.example.com/.well-known/oauth-authorization-server"]
+        );
+    }
+}
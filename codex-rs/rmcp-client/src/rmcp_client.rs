@@ -232,6 +232,20 @@ enum ClientOperationError {
     Timeout { label: String, duration: Duration },
 }
 
+/// Returns true when an error from [`RmcpClient::call_tool`] (or the other
+/// request helpers) happened while sending the request, i.e. before the
+/// server can have processed it, so retrying cannot re-execute a side
+/// effect. Timeouts and errors returned by the server are not retry-safe:
+/// the call may have run to completion on the other side.
+pub fn is_transport_send_error(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<ClientOperationError>(),
+        Some(ClientOperationError::Service(
+            rmcp::service::ServiceError::TransportSend(_)
+        ))
+    )
+}
+
 fn remaining_operation_timeout(
     label: &str,
     timeout: Option<Duration>,
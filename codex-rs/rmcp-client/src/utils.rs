@@ -119,6 +119,55 @@ pub(crate) fn build_default_headers(
     Ok(headers)
 }
 
+/// Applies environment proxies (`HTTPS_PROXY`/`ALL_PROXY` with `NO_PROXY`)
+/// and the shared custom-CA policy (`CODEX_CA_CERTIFICATE`, falling back to
+/// `SSL_CERT_FILE`) to an OAuth/discovery client builder. Platform proxy
+/// autodetection stays disabled to avoid the `system-configuration` probing
+/// panic (#8912), but explicit corporate proxies and trust anchors work.
+pub(crate) fn apply_network_policy(mut builder: ClientBuilder) -> ClientBuilder {
+    builder = builder.no_proxy();
+    if let Some(proxy_url) = non_empty_env("HTTPS_PROXY").or_else(|| non_empty_env("ALL_PROXY")) {
+        match reqwest::Proxy::all(&proxy_url) {
+            Ok(proxy) => {
+                let no_proxy = non_empty_env("NO_PROXY")
+                    .and_then(|value| reqwest::NoProxy::from_string(&value));
+                builder = builder.proxy(proxy.no_proxy(no_proxy));
+            }
+            Err(err) => {
+                tracing::warn!("ignoring invalid proxy url from environment: {err}");
+            }
+        }
+    }
+    if let Some(ca_path) =
+        non_empty_env("CODEX_CA_CERTIFICATE").or_else(|| non_empty_env("SSL_CERT_FILE"))
+    {
+        match std::fs::read(&ca_path) {
+            Ok(pem) => match reqwest::Certificate::from_pem_bundle(&pem) {
+                Ok(certificates) => {
+                    for certificate in certificates {
+                        builder = builder.add_root_certificate(certificate);
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("ignoring unusable CA bundle at {ca_path}: {err}");
+                }
+            },
+            Err(err) => {
+                tracing::warn!("failed to read CA bundle at {ca_path}: {err}");
+            }
+        }
+    }
+    builder
+}
+
+fn non_empty_env(upper: &str) -> Option<String> {
+    let lower = upper.to_ascii_lowercase();
+    env::var(upper)
+        .ok()
+        .or_else(|| env::var(lower).ok())
+        .filter(|value| !value.is_empty())
+}
+
 pub(crate) fn apply_default_headers(
     builder: ClientBuilder,
     default_headers: &HeaderMap,
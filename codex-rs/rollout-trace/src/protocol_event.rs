@@ -354,6 +354,15 @@ pub(crate) fn tool_runtime_trace_event(event: &EventMsg) -> Option<ToolRuntimeTr
         | EventMsg::TurnModerationMetadata(_)
         | EventMsg::ContextCompacted(_)
         | EventMsg::ThreadRolledBack(_)
+        | EventMsg::ThreadCheckpoint(_)
+        | EventMsg::GitSnapshot(_)
+        | EventMsg::TurnReverted(_)
+        | EventMsg::ApprovalLog(_)
+        | EventMsg::FilesChanged(_)
+        | EventMsg::EffectiveConfig(_)
+        | EventMsg::ConfigReloaded(_)
+        | EventMsg::SessionStatus(_)
+        | EventMsg::SessionInfo(_)
         | EventMsg::ThreadGoalUpdated(_)
         | EventMsg::TurnStarted(_)
         | EventMsg::ThreadSettingsApplied(_)
@@ -367,6 +376,8 @@ pub(crate) fn tool_runtime_trace_event(event: &EventMsg) -> Option<ToolRuntimeTr
         | EventMsg::SessionConfigured(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpStatus(_)
+        | EventMsg::ConversationExported(_)
         | EventMsg::WebSearchBegin(_)
         | EventMsg::WebSearchEnd(_)
         | EventMsg::ImageGenerationBegin(_)
@@ -386,6 +397,7 @@ pub(crate) fn tool_runtime_trace_event(event: &EventMsg) -> Option<ToolRuntimeTr
         | EventMsg::GuardianAssessment(_)
         | EventMsg::DeprecationNotice(_)
         | EventMsg::StreamError(_)
+        | EventMsg::StreamRetrying(_)
         | EventMsg::PatchApplyUpdated(_)
         | EventMsg::TurnDiff(_)
         | EventMsg::RealtimeConversationListVoicesResponse(_)
@@ -415,6 +427,15 @@ pub(crate) fn wrapped_protocol_event_type(event: &EventMsg) -> Option<&'static s
         EventMsg::TurnComplete(_) => Some("turn_complete"),
         EventMsg::TurnAborted(_) => Some("turn_aborted"),
         EventMsg::ThreadRolledBack(_) => Some("thread_rolled_back"),
+        EventMsg::ThreadCheckpoint(_) => Some("thread_checkpoint"),
+        EventMsg::GitSnapshot(_) => Some("git_snapshot"),
+        EventMsg::ApprovalLog(_) => Some("approval_log"),
+        EventMsg::FilesChanged(_) => Some("files_changed"),
+        EventMsg::EffectiveConfig(_) => Some("effective_config"),
+        EventMsg::ConfigReloaded(_) => Some("config_reloaded"),
+        EventMsg::SessionStatus(_) => Some("session_status"),
+        EventMsg::SessionInfo(_) => Some("session_info"),
+        EventMsg::TurnReverted(_) => Some("turn_reverted"),
         EventMsg::Error(_) => Some("error"),
         EventMsg::Warning(_) => Some("warning"),
         EventMsg::ShutdownComplete => Some("shutdown_complete"),
@@ -438,6 +459,8 @@ pub(crate) fn wrapped_protocol_event_type(event: &EventMsg) -> Option<&'static s
         | EventMsg::ThreadGoalUpdated(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpStatus(_)
+        | EventMsg::ConversationExported(_)
         | EventMsg::McpToolCallBegin(_)
         | EventMsg::McpToolCallEnd(_)
         | EventMsg::WebSearchBegin(_)
@@ -459,6 +482,7 @@ pub(crate) fn wrapped_protocol_event_type(event: &EventMsg) -> Option<&'static s
         | EventMsg::GuardianAssessment(_)
         | EventMsg::DeprecationNotice(_)
         | EventMsg::StreamError(_)
+        | EventMsg::StreamRetrying(_)
         | EventMsg::PatchApplyBegin(_)
         | EventMsg::PatchApplyUpdated(_)
         | EventMsg::PatchApplyEnd(_)
@@ -45,8 +45,18 @@ pub(crate) async fn file_modified_time(path: &Path) -> io::Result<Option<time::O
 /// If the requested path disappears during a representation transition, this briefly retries
 /// resolution so callers do not need to know which representation is on disk.
 pub async fn open_rollout_line_reader(path: &Path) -> io::Result<RolloutLineReader> {
+    open_rollout_line_reader_with_cipher(path, crate::crypt::SessionCipher::from_env()).await
+}
+
+/// Like [`open_rollout_line_reader`], but decrypts with the given session
+/// cipher instead of the environment-derived one. Use this when reading on
+/// behalf of a session whose config carries its own encryption key.
+pub async fn open_rollout_line_reader_with_cipher(
+    path: &Path,
+    cipher: crate::crypt::SessionCipher,
+) -> io::Result<RolloutLineReader> {
     for _ in 0..MAX_NOT_FOUND_RETRIES {
-        match reader::open_once(path).await {
+        match reader::open_once(path, cipher.clone()).await {
             Ok(reader) => return Ok(reader),
             Err(err) if err.kind() == io::ErrorKind::NotFound => {
                 tokio::time::sleep(OPEN_ROLLOUT_LINE_READER_RETRY_DELAY).await;
@@ -54,7 +64,7 @@ pub async fn open_rollout_line_reader(path: &Path) -> io::Result<RolloutLineRead
             Err(err) => return Err(err),
         }
     }
-    reader::open_once(path).await
+    reader::open_once(path, cipher).await
 }
 
 /// Returns the compressed `.jsonl.zst` path for a rollout path.
@@ -193,6 +203,7 @@ impl RolloutFile {
 /// Line-oriented rollout reader returned by [`open_rollout_line_reader`].
 pub struct RolloutLineReader {
     inner: RolloutLineReaderInner,
+    cipher: crate::crypt::SessionCipher,
 }
 
 enum RolloutLineReaderInner {
@@ -203,8 +214,8 @@ enum RolloutLineReaderInner {
 impl RolloutLineReader {
     /// Reads the next JSONL record from the rollout.
     pub async fn next_line(&mut self) -> io::Result<Option<String>> {
-        match &mut self.inner {
-            RolloutLineReaderInner::Plain(lines) => lines.next_line().await,
+        let line = match &mut self.inner {
+            RolloutLineReaderInner::Plain(lines) => lines.next_line().await?,
             RolloutLineReaderInner::Blocking(slot) => {
                 let Some(mut reader) = slot.take() else {
                     return Err(io::Error::other("compressed rollout reader is busy"));
@@ -214,9 +225,11 @@ impl RolloutLineReader {
                         .await
                         .map_err(io::Error::other)?;
                 *slot = Some(reader);
-                line
+                line?
             }
-        }
+        };
+        line.map(|line| self.cipher.decrypt_line_if_needed(line))
+            .transpose()
     }
 }
 
@@ -982,7 +995,10 @@ mod reader {
     use super::path;
     use tokio::io::AsyncBufReadExt;
 
-    pub(super) async fn open_once(path: &Path) -> io::Result<RolloutLineReader> {
+    pub(super) async fn open_once(
+        path: &Path,
+        cipher: crate::crypt::SessionCipher,
+    ) -> io::Result<RolloutLineReader> {
         let path = path::existing_rollout_path(path)
             .await
             .unwrap_or_else(|| path.to_path_buf());
@@ -998,11 +1014,13 @@ mod reader {
             .map_err(io::Error::other)??;
             return Ok(RolloutLineReader {
                 inner: RolloutLineReaderInner::Blocking(Some(reader)),
+                cipher,
             });
         }
         let file = tokio::fs::File::open(path).await?;
         Ok(RolloutLineReader {
             inner: RolloutLineReaderInner::Plain(tokio::io::BufReader::new(file).lines()),
+            cipher,
         })
     }
 }
@@ -8,6 +8,14 @@ pub trait RolloutConfigView {
     fn cwd(&self) -> &Path;
     fn model_provider_id(&self) -> &str;
     fn generate_memories(&self) -> bool;
+    /// Hex-encoded 32-byte key enabling encryption-at-rest for rollout files.
+    fn sessions_encryption_key(&self) -> Option<&str> {
+        None
+    }
+    /// Whether to redact well-known secret patterns from rollout lines.
+    fn redact_secrets(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -0,0 +1,187 @@
+//! Optional encryption-at-rest for rollout files.
+//!
+//! When a sessions encryption key is configured, every JSONL line is sealed
+//! with XChaCha20-Poly1305 before it reaches disk and is decrypted
+//! transparently by [`crate::open_rollout_line_reader`]. Encrypted lines are
+//! stored as `enc:<base64(nonce || ciphertext)>`, so plaintext and encrypted
+//! rollouts can coexist and resume keeps working across the transition.
+//!
+//! The cipher is per-recorder state: each [`SessionCipher`] is built from one
+//! session's config and travels with that recorder's writer and readers, so
+//! sessions with different keys (or no key) can coexist in one process.
+
+use std::io;
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::Key;
+use chacha20poly1305::KeyInit;
+use chacha20poly1305::XChaCha20Poly1305;
+use chacha20poly1305::XNonce;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::aead::AeadCore;
+use chacha20poly1305::aead::OsRng;
+
+/// Prefix marking an encrypted rollout line.
+const ENCRYPTED_LINE_PREFIX: &str = "enc:";
+/// XChaCha20-Poly1305 nonce length in bytes.
+const XCHACHA_NONCE_LEN: usize = 24;
+/// Environment variable consulted when no key is configured programmatically,
+/// so read-only tooling outside a session can still open encrypted rollouts.
+const SESSIONS_ENCRYPTION_KEY_ENV_VAR: &str = "CODEX_SESSIONS_ENCRYPTION_KEY";
+
+/// Sessions encryption state for one recorder or reader. Cloning is cheap and
+/// shares the underlying cipher.
+#[derive(Clone, Default)]
+pub struct SessionCipher {
+    cipher: Option<Arc<XChaCha20Poly1305>>,
+}
+
+impl SessionCipher {
+    /// Builds the cipher for one session from its configured key; a missing
+    /// key falls back to the environment variable.
+    pub fn from_config(key_hex: Option<&str>) -> io::Result<Self> {
+        let cipher = match key_hex {
+            Some(key_hex) => Some(Arc::new(cipher_from_hex_key(key_hex)?)),
+            None => cipher_from_env(),
+        };
+        Ok(Self { cipher })
+    }
+
+    /// Cipher derived from the environment alone, for readers opened outside
+    /// any session (search, export, resume pickers).
+    pub fn from_env() -> Self {
+        Self {
+            cipher: cipher_from_env(),
+        }
+    }
+
+    /// Returns true when rollout lines will be encrypted on write.
+    pub fn is_enabled(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    /// Seal a JSON line for writing when encryption is enabled; otherwise
+    /// return the line unchanged. The trailing newline is appended by the
+    /// caller.
+    pub(crate) fn encrypt_line_if_enabled(&self, line: &str) -> io::Result<String> {
+        match &self.cipher {
+            Some(cipher) => seal_line(cipher, line),
+            None => Ok(line.to_string()),
+        }
+    }
+
+    /// Decrypt a line read from a rollout file when it carries the encrypted
+    /// prefix; plaintext lines pass through unchanged.
+    pub(crate) fn decrypt_line_if_needed(&self, line: String) -> io::Result<String> {
+        let Some(encoded) = line.strip_prefix(ENCRYPTED_LINE_PREFIX) else {
+            return Ok(line);
+        };
+        let Some(cipher) = &self.cipher else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "rollout line is encrypted but no sessions encryption key is configured",
+            ));
+        };
+        open_sealed_line(cipher, encoded)
+    }
+}
+
+impl std::fmt::Debug for SessionCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionCipher")
+            .field("enabled", &self.is_enabled())
+            .finish()
+    }
+}
+
+fn cipher_from_env() -> Option<Arc<XChaCha20Poly1305>> {
+    let key_hex = std::env::var(SESSIONS_ENCRYPTION_KEY_ENV_VAR).ok()?;
+    match cipher_from_hex_key(&key_hex) {
+        Ok(cipher) => Some(Arc::new(cipher)),
+        Err(err) => {
+            tracing::warn!("ignoring invalid {SESSIONS_ENCRYPTION_KEY_ENV_VAR}: {err}");
+            None
+        }
+    }
+}
+
+fn cipher_from_hex_key(key_hex: &str) -> io::Result<XChaCha20Poly1305> {
+    let key_bytes = decode_hex(key_hex.trim())?;
+    if key_bytes.len() != 32 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "sessions encryption key must be 32 bytes (64 hex characters), got {} bytes",
+                key_bytes.len()
+            ),
+        ));
+    }
+    Ok(XChaCha20Poly1305::new(Key::from_slice(&key_bytes)))
+}
+
+fn decode_hex(value: &str) -> io::Result<Vec<u8>> {
+    if !value.is_ascii() || value.len() % 2 != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "sessions encryption key must be hex-encoded",
+        ));
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|index| {
+            u8::from_str_radix(&value[index..index + 2], 16).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "sessions encryption key must be hex-encoded",
+                )
+            })
+        })
+        .collect()
+}
+
+fn seal_line(cipher: &XChaCha20Poly1305, line: &str) -> io::Result<String> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, line.as_bytes())
+        .map_err(|_| io::Error::other("failed to encrypt rollout line"))?;
+    let mut sealed = Vec::with_capacity(nonce.len() + ciphertext.len());
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(format!("{ENCRYPTED_LINE_PREFIX}{}", BASE64.encode(sealed)))
+}
+
+fn open_sealed_line(cipher: &XChaCha20Poly1305, encoded: &str) -> io::Result<String> {
+    let sealed = BASE64.decode(encoded).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid encrypted rollout line encoding: {err}"),
+        )
+    })?;
+    if sealed.len() < XCHACHA_NONCE_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "encrypted rollout line is truncated",
+        ));
+    }
+    let (nonce, ciphertext) = sealed.split_at(XCHACHA_NONCE_LEN);
+    let plaintext = cipher
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "failed to decrypt rollout line; wrong sessions encryption key?",
+            )
+        })?;
+    String::from_utf8(plaintext).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("decrypted rollout line is not valid UTF-8: {err}"),
+        )
+    })
+}
+
+#[cfg(test)]
+#[path = "crypt_tests.rs"]
+mod tests;
@@ -0,0 +1,79 @@
+use pretty_assertions::assert_eq;
+
+use super::ENCRYPTED_LINE_PREFIX;
+use super::SessionCipher;
+use super::cipher_from_hex_key;
+use super::open_sealed_line;
+use super::seal_line;
+
+const TEST_KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+#[test]
+fn round_trips_a_line_with_a_valid_key() {
+    let cipher = cipher_from_hex_key(TEST_KEY).expect("key should parse");
+
+    let line = r#"{"timestamp":"2025-01-01T00:00:00.000Z","type":"session_meta"}"#;
+    let sealed = seal_line(&cipher, line).expect("encryption should succeed");
+    assert!(
+        sealed.starts_with(ENCRYPTED_LINE_PREFIX),
+        "line was not sealed: {sealed}"
+    );
+    assert!(!sealed.contains("session_meta"));
+
+    let encoded = sealed
+        .strip_prefix(ENCRYPTED_LINE_PREFIX)
+        .expect("sealed line should carry the prefix");
+    let decrypted = open_sealed_line(&cipher, encoded).expect("decryption should succeed");
+    assert_eq!(decrypted, line);
+}
+
+#[test]
+fn wrong_key_fails_to_decrypt() {
+    let cipher = cipher_from_hex_key(TEST_KEY).expect("key should parse");
+    let other =
+        cipher_from_hex_key("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+            .expect("key should parse");
+
+    let sealed = seal_line(&cipher, "{}").expect("encryption should succeed");
+    let encoded = sealed
+        .strip_prefix(ENCRYPTED_LINE_PREFIX)
+        .expect("sealed line should carry the prefix");
+
+    let error = open_sealed_line(&other, encoded).expect_err("decryption should fail");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn truncated_sealed_line_is_an_error() {
+    let cipher = cipher_from_hex_key(TEST_KEY).expect("key should parse");
+
+    let error = open_sealed_line(&cipher, "AAAA").expect_err("decryption should fail");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn rejects_keys_that_are_not_32_hex_bytes() {
+    assert!(SessionCipher::from_config(Some("abcd")).is_err());
+    assert!(SessionCipher::from_config(Some("not-hex")).is_err());
+    assert!(cipher_from_hex_key("ΩΩΩΩ").is_err());
+}
+
+#[test]
+fn ciphers_for_different_sessions_are_independent() {
+    let keyed = SessionCipher::from_config(Some(TEST_KEY)).expect("key should parse");
+    let sealed = keyed
+        .encrypt_line_if_enabled("{}")
+        .expect("encryption should succeed");
+    assert!(sealed.starts_with(ENCRYPTED_LINE_PREFIX));
+
+    // A second, unkeyed session must not disturb the first one's cipher.
+    let unkeyed = SessionCipher::default();
+    assert!(!unkeyed.is_enabled());
+    assert!(keyed.is_enabled());
+    assert_eq!(
+        keyed
+            .decrypt_line_if_needed(sealed)
+            .expect("decryption should succeed"),
+        "{}"
+    );
+}
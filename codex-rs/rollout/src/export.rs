@@ -0,0 +1,247 @@
+//! Export/import of sessions as portable `.codexsession` archives.
+//!
+//! An archive bundles the rollout (decompressed and decrypted), plus any
+//! local images referenced by rollout items, into a single zstd-compressed
+//! JSON envelope so a session can be moved between machines or attached to a
+//! bug report and resumed elsewhere.
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::compression;
+use crate::list::rollout_date_parts;
+
+/// File extension for exported session archives.
+pub const SESSION_ARCHIVE_EXTENSION: &str = "codexsession";
+
+const SESSION_ARCHIVE_VERSION: u32 = 1;
+const IMPORTED_ATTACHMENTS_SUBDIR: &str = "attachments";
+const ARCHIVE_COMPRESSION_LEVEL: i32 = 3;
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp", "bmp"];
+
+#[derive(Serialize, Deserialize)]
+struct SessionArchive {
+    version: u32,
+    /// Original rollout file name (`rollout-<timestamp>-<id>.jsonl`).
+    file_name: String,
+    /// Rollout JSONL lines in order, decompressed and decrypted.
+    lines: Vec<String>,
+    /// Referenced local images, keyed by the path recorded in the rollout.
+    #[serde(default)]
+    images: Vec<ArchivedImage>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ArchivedImage {
+    /// Path as it appears inside the rollout.
+    path: String,
+    /// Base64-encoded file contents.
+    data: String,
+}
+
+/// Bundle the rollout at `rollout_path` into a `.codexsession` archive at
+/// `destination` (the extension is appended when missing). Returns the final
+/// archive path.
+pub async fn export_session_archive(
+    rollout_path: &Path,
+    destination: &Path,
+) -> io::Result<PathBuf> {
+    let file_name = rollout_path
+        .file_name()
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "rollout path has no file name")
+        })?
+        .to_string_lossy()
+        // Export always writes plain JSONL lines, even when the on-disk
+        // representation is compressed.
+        .trim_end_matches(".zst")
+        .to_string();
+
+    let mut lines = Vec::new();
+    let mut reader = compression::open_rollout_line_reader(rollout_path).await?;
+    while let Some(line) = reader.next_line().await? {
+        if !line.trim().is_empty() {
+            lines.push(line);
+        }
+    }
+
+    let images = collect_referenced_images(&lines).await;
+    let archive = SessionArchive {
+        version: SESSION_ARCHIVE_VERSION,
+        file_name,
+        lines,
+        images,
+    };
+
+    let destination = if destination
+        .extension()
+        .is_some_and(|ext| ext == SESSION_ARCHIVE_EXTENSION)
+    {
+        destination.to_path_buf()
+    } else {
+        let mut destination = destination.as_os_str().to_os_string();
+        destination.push(format!(".{SESSION_ARCHIVE_EXTENSION}"));
+        PathBuf::from(destination)
+    };
+
+    let json = serde_json::to_vec(&archive)?;
+    let compressed = tokio::task::spawn_blocking(move || {
+        zstd::stream::encode_all(json.as_slice(), ARCHIVE_COMPRESSION_LEVEL)
+    })
+    .await
+    .map_err(io::Error::other)??;
+    if let Some(parent) = destination.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(&destination, compressed).await?;
+    Ok(destination)
+}
+
+/// Unpack a `.codexsession` archive into `codex_home/sessions`, restoring any
+/// bundled images under an attachments directory and rewriting their rollout
+/// references. Returns the imported rollout path, ready for resume.
+pub async fn import_session_archive(archive_path: &Path, codex_home: &Path) -> io::Result<PathBuf> {
+    let compressed = tokio::fs::read(archive_path).await?;
+    let json = tokio::task::spawn_blocking(move || zstd::stream::decode_all(compressed.as_slice()))
+        .await
+        .map_err(io::Error::other)??;
+    let archive: SessionArchive = serde_json::from_slice(&json).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid session archive: {err}"),
+        )
+    })?;
+    if archive.version != SESSION_ARCHIVE_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported session archive version {}", archive.version),
+        ));
+    }
+
+    let (year, month, day) = rollout_date_parts(std::ffi::OsStr::new(&archive.file_name))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "archive rollout name `{}` is not recognized",
+                    archive.file_name
+                ),
+            )
+        })?;
+    let sessions_dir = codex_home
+        .join(crate::SESSIONS_SUBDIR)
+        .join(year)
+        .join(month)
+        .join(day);
+    tokio::fs::create_dir_all(&sessions_dir).await?;
+    let rollout_path = sessions_dir.join(&archive.file_name);
+    if tokio::fs::try_exists(&rollout_path).await? {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("rollout already exists at {}", rollout_path.display()),
+        ));
+    }
+
+    let mut lines = archive.lines;
+    if !archive.images.is_empty() {
+        let stem = archive.file_name.trim_end_matches(".jsonl");
+        let attachments_dir = codex_home
+            .join(crate::SESSIONS_SUBDIR)
+            .join(IMPORTED_ATTACHMENTS_SUBDIR)
+            .join(stem);
+        tokio::fs::create_dir_all(&attachments_dir).await?;
+        for (index, image) in archive.images.iter().enumerate() {
+            let Ok(data) = BASE64.decode(&image.data) else {
+                continue;
+            };
+            let extension = Path::new(&image.path)
+                .extension()
+                .map(|ext| ext.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "png".to_string());
+            let restored = attachments_dir.join(format!("image-{index}.{extension}"));
+            tokio::fs::write(&restored, data).await?;
+            rewrite_image_references(&mut lines, &image.path, &restored);
+        }
+    }
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    tokio::fs::write(&rollout_path, contents).await?;
+    Ok(rollout_path)
+}
+
+/// Collect existing local image files referenced by rollout lines. Detection
+/// walks each line's JSON for string values that look like image paths; this
+/// is best-effort, so missing or unreadable files are skipped.
+async fn collect_referenced_images(lines: &[String]) -> Vec<ArchivedImage> {
+    let mut seen = std::collections::BTreeSet::new();
+    for line in lines {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        collect_image_paths(&value, &mut seen);
+    }
+
+    let mut images = Vec::new();
+    for path in seen {
+        let Ok(data) = tokio::fs::read(&path).await else {
+            continue;
+        };
+        images.push(ArchivedImage {
+            path,
+            data: BASE64.encode(data),
+        });
+    }
+    images
+}
+
+fn collect_image_paths(value: &serde_json::Value, paths: &mut std::collections::BTreeSet<String>) {
+    match value {
+        serde_json::Value::String(text) => {
+            let is_image = Path::new(text)
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+                .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.as_str()));
+            if is_image && Path::new(text).is_absolute() {
+                paths.insert(text.clone());
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_image_paths(item, paths);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for item in map.values() {
+                collect_image_paths(item, paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn rewrite_image_references(lines: &mut [String], original: &str, restored: &Path) {
+    let Ok(original_json) = serde_json::to_string(original) else {
+        return;
+    };
+    let Ok(restored_json) = serde_json::to_string(&restored.to_string_lossy()) else {
+        return;
+    };
+    for line in lines.iter_mut() {
+        if line.contains(original_json.as_str()) {
+            *line = line.replace(original_json.as_str(), restored_json.as_str());
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "export_tests.rs"]
+mod tests;
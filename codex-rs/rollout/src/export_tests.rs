@@ -0,0 +1,113 @@
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use super::export_session_archive;
+use super::import_session_archive;
+
+const ROLLOUT_FILE_NAME: &str =
+    "rollout-2025-05-07T17-24-21-5973b6c0-94b8-487b-a530-2aeb6098ae0e.jsonl";
+
+#[tokio::test]
+async fn export_and_import_round_trips_a_rollout() {
+    let source_home = TempDir::new().expect("create source home");
+    let rollout_path = source_home.path().join(ROLLOUT_FILE_NAME);
+    let lines = [
+        r#"{"timestamp":"2025-05-07T17:24:21.000Z","type":"session_meta","payload":{}}"#,
+        r#"{"timestamp":"2025-05-07T17:24:22.000Z","type":"response_item","payload":{}}"#,
+    ];
+    tokio::fs::write(&rollout_path, format!("{}\n", lines.join("\n")))
+        .await
+        .expect("write rollout");
+
+    let archive_path =
+        export_session_archive(&rollout_path, &source_home.path().join("bug-report"))
+            .await
+            .expect("export should succeed");
+    assert_eq!(
+        archive_path.extension().and_then(|ext| ext.to_str()),
+        Some("codexsession")
+    );
+
+    let dest_home = TempDir::new().expect("create dest home");
+    let imported = import_session_archive(&archive_path, dest_home.path())
+        .await
+        .expect("import should succeed");
+
+    assert_eq!(
+        imported,
+        dest_home
+            .path()
+            .join("sessions/2025/05/07")
+            .join(ROLLOUT_FILE_NAME)
+    );
+    let contents = tokio::fs::read_to_string(&imported)
+        .await
+        .expect("read imported rollout");
+    assert_eq!(contents, format!("{}\n", lines.join("\n")));
+}
+
+#[tokio::test]
+async fn import_refuses_to_overwrite_an_existing_rollout() {
+    let source_home = TempDir::new().expect("create source home");
+    let rollout_path = source_home.path().join(ROLLOUT_FILE_NAME);
+    tokio::fs::write(&rollout_path, "{}\n")
+        .await
+        .expect("write rollout");
+    let archive_path = export_session_archive(&rollout_path, &source_home.path().join("archive"))
+        .await
+        .expect("export should succeed");
+
+    let dest_home = TempDir::new().expect("create dest home");
+    import_session_archive(&archive_path, dest_home.path())
+        .await
+        .expect("first import should succeed");
+    let error = import_session_archive(&archive_path, dest_home.path())
+        .await
+        .expect_err("second import should fail");
+
+    assert_eq!(error.kind(), std::io::ErrorKind::AlreadyExists);
+}
+
+#[tokio::test]
+async fn export_bundles_referenced_images() {
+    let source_home = TempDir::new().expect("create source home");
+    let image_path = source_home.path().join("screenshot.png");
+    tokio::fs::write(&image_path, b"not-really-a-png")
+        .await
+        .expect("write image");
+
+    let rollout_path = source_home.path().join(ROLLOUT_FILE_NAME);
+    let line = format!(
+        r#"{{"timestamp":"2025-05-07T17:24:21.000Z","type":"response_item","payload":{{"path":{}}}}}"#,
+        serde_json::to_string(&image_path.to_string_lossy()).expect("encode path"),
+    );
+    tokio::fs::write(&rollout_path, format!("{line}\n"))
+        .await
+        .expect("write rollout");
+
+    let archive_path = export_session_archive(&rollout_path, &source_home.path().join("archive"))
+        .await
+        .expect("export should succeed");
+
+    let dest_home = TempDir::new().expect("create dest home");
+    let imported = import_session_archive(&archive_path, dest_home.path())
+        .await
+        .expect("import should succeed");
+
+    let contents = tokio::fs::read_to_string(&imported)
+        .await
+        .expect("read imported rollout");
+    assert!(
+        !contents.contains(&*image_path.to_string_lossy()),
+        "imported rollout still references the original path: {contents}"
+    );
+    let restored_dir = dest_home
+        .path()
+        .join("sessions/attachments")
+        .join(ROLLOUT_FILE_NAME.trim_end_matches(".jsonl"));
+    let restored = restored_dir.join("image-0.png");
+    let data = tokio::fs::read(&restored)
+        .await
+        .expect("read restored image");
+    assert_eq!(data, b"not-really-a-png");
+}
@@ -6,6 +6,7 @@ use codex_protocol::protocol::SessionSource;
 
 pub(crate) mod compression;
 pub(crate) mod config;
+mod crypt;
 pub(crate) mod list;
 pub(crate) mod metadata;
 mod persistence_metrics;
@@ -33,11 +34,16 @@ pub use codex_protocol::protocol::SessionMeta;
 pub use compression::RolloutLineReader;
 pub use compression::existing_rollout_path;
 pub use compression::open_rollout_line_reader;
+pub use compression::open_rollout_line_reader_with_cipher;
 pub use compression::plain_rollout_path;
 pub use compression::spawn_rollout_compression_worker;
 pub use config::Config;
 pub use config::RolloutConfig;
 pub use config::RolloutConfigView;
+pub use crypt::SessionCipher;
+pub use export::SESSION_ARCHIVE_EXTENSION;
+pub use export::export_session_archive;
+pub use export::import_session_archive;
 pub use list::Cursor;
 pub use list::SortDirection;
 pub use list::ThreadItem;
@@ -94,6 +94,8 @@ pub fn should_persist_event_msg(ev: &EventMsg, history_mode: ThreadHistoryMode)
         EventMsg::TokenCount(_)
         | EventMsg::ThreadGoalUpdated(_)
         | EventMsg::ThreadRolledBack(_)
+        | EventMsg::ThreadCheckpoint(_)
+        | EventMsg::GitSnapshot(_)
         | EventMsg::TurnAborted(_)
         | EventMsg::TurnStarted(_)
         | EventMsg::TurnComplete(_)
@@ -149,12 +151,22 @@ pub fn should_persist_event_msg(ev: &EventMsg, history_mode: ThreadHistoryMode)
         | EventMsg::ElicitationRequest(_)
         | EventMsg::ApplyPatchApprovalRequest(_)
         | EventMsg::StreamError(_)
+        | EventMsg::StreamRetrying(_)
         | EventMsg::PatchApplyBegin(_)
         | EventMsg::PatchApplyUpdated(_)
         | EventMsg::TurnDiff(_)
         | EventMsg::RealtimeConversationListVoicesResponse(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpStatus(_)
+        | EventMsg::ConversationExported(_)
+        | EventMsg::TurnReverted(_)
+        | EventMsg::ApprovalLog(_)
+        | EventMsg::FilesChanged(_)
+        | EventMsg::EffectiveConfig(_)
+        | EventMsg::ConfigReloaded(_)
+        | EventMsg::SessionStatus(_)
+        | EventMsg::SessionInfo(_)
         | EventMsg::WebSearchBegin(_)
         | EventMsg::PlanUpdate(_)
         | EventMsg::ShutdownComplete
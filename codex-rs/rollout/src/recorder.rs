@@ -51,6 +51,7 @@ use crate::state_db;
 use crate::state_db::StateDbHandle;
 use codex_git_utils::collect_git_info;
 use codex_git_utils::get_git_repo_root;
+use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::GitInfo as ProtocolGitInfo;
 use codex_protocol::protocol::InitialHistory;
 use codex_protocol::protocol::MultiAgentVersion;
@@ -751,6 +752,8 @@ impl RolloutRecorder {
         config: &impl RolloutConfigView,
         params: RolloutRecorderParams,
     ) -> std::io::Result<Self> {
+        let cipher = crate::crypt::SessionCipher::from_config(config.sessions_encryption_key())?;
+        let redact_secrets = config.redact_secrets();
         let (file, deferred_log_file_info, rollout_path, meta) = match params {
             RolloutRecorderParams::Create {
                 session_id,
@@ -847,6 +850,8 @@ impl RolloutRecorder {
                 meta,
                 cwd,
                 rollout_path_for_spawn.clone(),
+                cipher,
+                redact_secrets,
             )
             .await;
             if let Err(err) = result {
@@ -930,6 +935,10 @@ impl RolloutRecorder {
         })?
     }
 
+    /// Reads back all rollout items from a file. Encrypted rollouts are
+    /// decrypted with the environment-derived key; callers holding a session
+    /// config should read via
+    /// [`crate::open_rollout_line_reader_with_cipher`] instead.
     pub async fn load_rollout_items(
         path: &Path,
     ) -> std::io::Result<(Vec<RolloutItem>, Option<ThreadId>, usize)> {
@@ -1555,6 +1564,10 @@ struct RolloutWriterState {
     cwd: PathBuf,
     rollout_path: PathBuf,
     last_logged_error: Option<String>,
+    /// This recorder's encryption state, reused when the writer is reopened.
+    cipher: crate::crypt::SessionCipher,
+    /// Whether this recorder redacts secrets from written lines.
+    redact_secrets: bool,
 }
 
 impl RolloutWriterState {
@@ -1564,15 +1577,24 @@ impl RolloutWriterState {
         meta: Option<SessionMeta>,
         cwd: PathBuf,
         rollout_path: PathBuf,
+        cipher: crate::crypt::SessionCipher,
+        redact_secrets: bool,
     ) -> Self {
         Self {
-            writer: file.map(|file| JsonlWriter { file }),
+            writer: file.map(|file| JsonlWriter {
+                file,
+                active_turn_id: None,
+                cipher: cipher.clone(),
+                redact_secrets,
+            }),
             deferred_log_file_info,
             pending_items: Vec::new(),
             meta,
             cwd,
             rollout_path,
             last_logged_error: None,
+            cipher,
+            redact_secrets,
         }
     }
 
@@ -1665,7 +1687,10 @@ impl RolloutWriterState {
             .unwrap_or(self.rollout_path.as_path());
         let file = open_log_file(path)?;
         self.writer = Some(JsonlWriter {
+            active_turn_id: None,
             file: tokio::fs::File::from_std(file),
+            cipher: self.cipher.clone(),
+            redact_secrets: self.redact_secrets,
         });
         self.deferred_log_file_info = None;
         Ok(())
@@ -1722,8 +1747,18 @@ async fn rollout_writer(
     meta: Option<SessionMeta>,
     cwd: PathBuf,
     rollout_path: PathBuf,
+    cipher: crate::crypt::SessionCipher,
+    redact_secrets: bool,
 ) -> std::io::Result<()> {
-    let mut state = RolloutWriterState::new(file, deferred_log_file_info, meta, cwd, rollout_path);
+    let mut state = RolloutWriterState::new(
+        file,
+        deferred_log_file_info,
+        meta,
+        cwd,
+        rollout_path,
+        cipher,
+        redact_secrets,
+    );
 
     // Process rollout commands
     while let Some(cmd) = rx.recv().await {
@@ -1793,17 +1828,30 @@ pub async fn append_rollout_item_to_path(
         .append(true)
         .open(rollout_path)
         .await?;
-    let mut writer = JsonlWriter { file };
+    let mut writer = JsonlWriter {
+        file,
+        active_turn_id: None,
+        cipher: crate::crypt::SessionCipher::from_env(),
+        redact_secrets: false,
+    };
     writer.write_rollout_item(item).await
 }
 
 struct JsonlWriter {
     file: tokio::fs::File,
+    /// Turn id stamped onto v2 envelope lines while a turn is active.
+    active_turn_id: Option<String>,
+    /// This recorder's encryption state applied to every written line.
+    cipher: crate::crypt::SessionCipher,
+    /// Whether secrets are redacted from written lines.
+    redact_secrets: bool,
 }
 
 #[derive(serde::Serialize)]
 struct RolloutLineRef<'a> {
     timestamp: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    turn: Option<String>,
     #[serde(flatten)]
     item: &'a RolloutItem,
 }
@@ -1817,14 +1865,32 @@ impl JsonlWriter {
             .format(timestamp_format)
             .map_err(|e| IoError::other(format!("failed to format timestamp: {e}")))?;
 
+        // Track explicit turn boundaries so every line carries the turn it
+        // belongs to: the TurnStarted line opens the span and the
+        // TurnComplete/TurnAborted line closes it (inclusive).
+        if let RolloutItem::EventMsg(EventMsg::TurnStarted(event)) = rollout_item {
+            self.active_turn_id = Some(event.turn_id.clone());
+        }
         let line = RolloutLineRef {
             timestamp,
+            turn: self.active_turn_id.clone(),
             item: rollout_item,
         };
-        self.write_line(&line).await
+        let result = self.write_line(&line).await;
+        if matches!(
+            rollout_item,
+            RolloutItem::EventMsg(EventMsg::TurnComplete(_) | EventMsg::TurnAborted(_))
+        ) {
+            self.active_turn_id = None;
+        }
+        result
     }
     async fn write_line(&mut self, item: &impl serde::Serialize) -> std::io::Result<()> {
-        let mut json = serde_json::to_string(item)?;
+        let json = crate::redaction::redact_line_if_enabled(
+            serde_json::to_string(item)?,
+            self.redact_secrets,
+        );
+        let mut json = self.cipher.encrypt_line_if_enabled(&json)?;
         json.push('\n');
         self.file.write_all(json.as_bytes()).await?;
         self.file.flush().await?;
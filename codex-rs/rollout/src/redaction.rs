@@ -0,0 +1,20 @@
+//! Optional secret redaction before rollout lines are written.
+//!
+//! When enabled, every serialized rollout line is passed through
+//! [`codex_secrets::redact_secrets`] before it reaches disk (and before
+//! encryption-at-rest, when that is also configured), so credentials printed
+//! by builds or pasted into messages are not persisted verbatim.
+//!
+//! Like the encryption cipher, redaction is per-writer state taken from one
+//! session's config rather than a process-global flag, so sessions with
+//! different settings can coexist in one process.
+
+/// Apply best-effort secret redaction to a serialized rollout line when
+/// enabled; otherwise return the line unchanged.
+pub(crate) fn redact_line_if_enabled(line: String, enabled: bool) -> String {
+    if enabled {
+        codex_secrets::redact_secrets(line)
+    } else {
+        line
+    }
+}
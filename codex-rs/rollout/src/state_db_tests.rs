@@ -156,6 +156,7 @@ fn write_rollout_with_user_message(
     let lines = [
         RolloutLine {
             timestamp: "2026-06-01T14:26:25Z".to_string(),
+            turn: None,
             item: RolloutItem::SessionMeta(SessionMetaLine {
                 meta: SessionMeta {
                     session_id: thread_id.into(),
@@ -185,6 +186,7 @@ fn write_rollout_with_user_message(
         },
         RolloutLine {
             timestamp: "2026-06-01T14:26:26Z".to_string(),
+            turn: None,
             item: RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
                 message: message.to_string(),
                 ..Default::default()
@@ -305,6 +305,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("webview/README.md"),
+                range: None,
             }],
         );
     }
@@ -318,6 +319,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }],
         );
     }
@@ -331,6 +333,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }],
         );
     }
@@ -344,6 +347,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }],
         );
     }
@@ -357,6 +361,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }],
         );
     }
@@ -370,6 +375,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }],
         );
     }
@@ -382,6 +388,7 @@ mod tests {
                 cmd: "cat foo.txt".to_string(),
                 name: "foo.txt".to_string(),
                 path: PathBuf::from("foo/foo.txt"),
+                range: None,
             }],
         );
     }
@@ -394,6 +401,7 @@ mod tests {
                 cmd: "cat foo.txt".to_string(),
                 name: "foo.txt".to_string(),
                 path: PathBuf::from("-weird/foo.txt"),
+                range: None,
             }],
         );
     }
@@ -406,6 +414,7 @@ mod tests {
                 cmd: "cat foo.txt".to_string(),
                 name: "foo.txt".to_string(),
                 path: PathBuf::from("dir2/foo.txt"),
+                range: None,
             }],
         );
     }
@@ -429,6 +438,7 @@ mod tests {
                 cmd: "cat foo.txt".to_string(),
                 name: "foo.txt".to_string(),
                 path: PathBuf::from("foo/foo.txt"),
+                range: None,
             }],
         );
     }
@@ -486,6 +496,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("Cargo.toml"),
+                range: Some((1, 50)),
             }],
         );
     }
@@ -499,6 +510,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("Cargo.toml"),
+                range: None,
             }],
         );
     }
@@ -512,6 +524,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("tui/Cargo.toml"),
+                range: Some((1, 200)),
             }],
         );
     }
@@ -525,6 +538,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: Some((522, u32::MAX)),
             }],
         );
     }
@@ -539,6 +553,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }]
         );
     }
@@ -552,6 +567,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }],
         );
     }
@@ -847,6 +863,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "parse_command.rs".to_string(),
                 path: PathBuf::from("core/src/parse_command.rs"),
+                range: Some((1200, 1720)),
             }],
         );
     }
@@ -860,6 +877,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "history_cell.rs".to_string(),
                 path: PathBuf::from("tui/src/history_cell.rs"),
+                range: Some((2000, 2200)),
             }],
         );
     }
@@ -873,6 +891,7 @@ mod tests {
                 cmd: inner.to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("Cargo.toml"),
+                range: None,
             }],
         );
     }
@@ -887,6 +906,7 @@ mod tests {
                 cmd: "cat -- ansi-escape/Cargo.toml".to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("ansi-escape/Cargo.toml"),
+                range: None,
             }],
         );
     }
@@ -916,6 +936,7 @@ mod tests {
                 cmd: "sed -n '260,640p' exec/src/event_processor_with_human_output.rs".to_string(),
                 name: "event_processor_with_human_output.rs".to_string(),
                 path: PathBuf::from("exec/src/event_processor_with_human_output.rs"),
+                range: Some((260, 640)),
             }],
         );
     }
@@ -992,6 +1013,7 @@ mod tests {
                 cmd: r#"cat "pkg\\src\\main.rs""#.to_string(),
                 name: "main.rs".to_string(),
                 path: PathBuf::from(r#"pkg\src\main.rs"#),
+                range: None,
             }],
         );
     }
@@ -1004,6 +1026,7 @@ mod tests {
                 cmd: "head -n50 Cargo.toml".to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("Cargo.toml"),
+                range: Some((1, 50)),
             }],
         );
     }
@@ -1029,6 +1052,7 @@ mod tests {
                 cmd: "tail -n+10 README.md".to_string(),
                 name: "README.md".to_string(),
                 path: PathBuf::from("README.md"),
+                range: None,
             }],
         );
     }
@@ -1130,6 +1154,7 @@ mod tests {
                 cmd: "cat -- ./-strange-file-name".to_string(),
                 name: "-strange-file-name".to_string(),
                 path: PathBuf::from("./-strange-file-name"),
+                range: None,
             }],
         );
 
@@ -1140,6 +1165,7 @@ mod tests {
                 cmd: "sed -n '12,20p' Cargo.toml".to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("Cargo.toml"),
+                range: Some((12, 20)),
             }],
         );
     }
@@ -1220,6 +1246,7 @@ mod tests {
                 cmd: "sed -n '1,10p' Cargo.toml".to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("Cargo.toml"),
+                range: Some((1, 10)),
             }],
         );
     }
@@ -1231,6 +1258,7 @@ mod tests {
                 cmd: "sed -n '1,10p' Cargo.toml".to_string(),
                 name: "Cargo.toml".to_string(),
                 path: PathBuf::from("Cargo.toml"),
+                range: Some((1, 10)),
             }],
         );
     }
@@ -1311,16 +1339,27 @@ pub fn parse_command_impl(command: &[String]) -> Vec<ParsedCommand> {
         }
         let parsed = summarize_main_tokens(tokens);
         let parsed = match parsed {
-            ParsedCommand::Read { cmd, name, path } => {
+            ParsedCommand::Read {
+                cmd,
+                name,
+                path,
+                range,
+            } => {
                 if let Some(base) = &cwd {
                     let full = join_paths(base, &path.to_string_lossy());
                     ParsedCommand::Read {
                         cmd,
                         name,
                         path: PathBuf::from(full),
+                        range,
                     }
                 } else {
-                    ParsedCommand::Read { cmd, name, path }
+                    ParsedCommand::Read {
+                        cmd,
+                        name,
+                        path,
+                        range,
+                    }
                 }
             }
             other => other,
@@ -1459,6 +1498,24 @@ fn sed_read_path(args: &[String]) -> Option<String> {
     }
 }
 
+/// Extracts the 1-based inclusive line range from a `sed -n` range script
+/// argument such as `10,20p` or `42p`.
+fn sed_range(args: &[String]) -> Option<(u32, u32)> {
+    trim_at_connector(args).iter().find_map(|arg| {
+        if !is_valid_sed_n_arg(Some(arg)) {
+            return None;
+        }
+        let core = arg.strip_suffix('p')?;
+        match core.split_once(',') {
+            Some((start, end)) => Some((start.parse().ok()?, end.parse().ok()?)),
+            None => {
+                let line = core.parse().ok()?;
+                Some((line, line))
+            }
+        }
+    })
+}
+
 /// Normalize a command by:
 /// - Removing `yes`/`no`/`bash -c`/`bash -lc`/`zsh -c`/`zsh -lc` prefixes.
 /// - Splitting on `|` and `&&`/`||`/`;
@@ -1856,16 +1913,27 @@ pub fn parse_shell_script(script: &str) -> Vec<ParsedCommand> {
             }
             let parsed = summarize_main_tokens(&tokens);
             let parsed = match parsed {
-                ParsedCommand::Read { cmd, name, path } => {
+                ParsedCommand::Read {
+                    cmd,
+                    name,
+                    path,
+                    range,
+                } => {
                     if let Some(base) = &cwd {
                         let full = join_paths(base, &path.to_string_lossy());
                         ParsedCommand::Read {
                             cmd,
                             name,
                             path: PathBuf::from(full),
+                            range,
                         }
                     } else {
-                        ParsedCommand::Read { cmd, name, path }
+                        ParsedCommand::Read {
+                            cmd,
+                            name,
+                            path,
+                            range,
+                        }
                     }
                 }
                 other => other,
@@ -1892,7 +1960,12 @@ pub fn parse_shell_script(script: &str) -> Vec<ParsedCommand> {
             commands = commands
                 .into_iter()
                 .map(|pc| match pc {
-                    ParsedCommand::Read { name, cmd, path } => {
+                    ParsedCommand::Read {
+                        name,
+                        cmd,
+                        path,
+                        range,
+                    } => {
                         if had_connectors {
                             let has_pipe = script_tokens.iter().any(|t| t == "|");
                             let has_sed_n = script_tokens.windows(2).any(|w| {
@@ -1904,15 +1977,22 @@ pub fn parse_shell_script(script: &str) -> Vec<ParsedCommand> {
                                     cmd: script.to_string(),
                                     name,
                                     path,
+                                    range: range.or_else(|| sed_range(&script_tokens)),
                                 }
                             } else {
-                                ParsedCommand::Read { cmd, name, path }
+                                ParsedCommand::Read {
+                                    cmd,
+                                    name,
+                                    path,
+                                    range,
+                                }
                             }
                         } else {
                             ParsedCommand::Read {
                                 cmd: shlex_join(&script_tokens),
                                 name,
                                 path,
+                                range,
                             }
                         }
                     }
@@ -2259,6 +2339,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 }
             } else {
                 ParsedCommand::Unknown {
@@ -2284,6 +2365,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 }
             } else {
                 ParsedCommand::Unknown {
@@ -2313,6 +2395,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 }
             } else {
                 ParsedCommand::Unknown {
@@ -2327,6 +2410,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 }
             } else {
                 ParsedCommand::Unknown {
@@ -2346,6 +2430,13 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                 _ => false,
             };
             if has_valid_n {
+                let count = match tail.split_first() {
+                    Some((first, rest)) if first == "-n" => {
+                        rest.first().and_then(|n| n.parse::<u32>().ok())
+                    }
+                    Some((first, _)) if first.starts_with("-n") => first[2..].parse::<u32>().ok(),
+                    _ => None,
+                };
                 // Build candidates skipping the numeric value consumed by `-n` when separated.
                 let mut candidates: Vec<&String> = Vec::new();
                 let mut i = 0;
@@ -2367,6 +2458,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                         cmd: shlex_join(main_cmd),
                         name,
                         path: PathBuf::from(path),
+                        range: count.map(|count| (1, count)),
                     };
                 }
             }
@@ -2378,6 +2470,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 };
             }
             ParsedCommand::Unknown {
@@ -2414,6 +2507,16 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     candidates.push(&tail[i]);
                     i += 1;
                 }
+                let start = match tail.split_first() {
+                    Some((first, rest)) if first == "-n" => rest
+                        .first()
+                        .and_then(|n| n.strip_prefix('+'))
+                        .and_then(|n| n.parse::<u32>().ok()),
+                    Some((first, _)) if first.starts_with("-n") => first[2..]
+                        .strip_prefix('+')
+                        .and_then(|n| n.parse::<u32>().ok()),
+                    _ => None,
+                };
                 if let Some(p) = candidates.into_iter().find(|p| !p.starts_with('-')) {
                     let path = p.clone();
                     let name = short_display_path(&path);
@@ -2421,6 +2524,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                         cmd: shlex_join(main_cmd),
                         name,
                         path: PathBuf::from(path),
+                        range: start.map(|start| (start, u32::MAX)),
                     };
                 }
             }
@@ -2432,6 +2536,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 };
             }
             ParsedCommand::Unknown {
@@ -2445,6 +2550,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 }
             } else {
                 ParsedCommand::Unknown {
@@ -2462,6 +2568,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: None,
                 }
             } else {
                 ParsedCommand::Unknown {
@@ -2476,6 +2583,7 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     cmd: shlex_join(main_cmd),
                     name,
                     path: PathBuf::from(path),
+                    range: sed_range(tail),
                 }
             } else {
                 ParsedCommand::Unknown {
@@ -0,0 +1,54 @@
+use codex_config::CloudConfigBundleLoader;
+use codex_config::LoaderOverrides;
+use codex_core::config::Config;
+use codex_core::config::ConfigBuilder;
+use codex_core::config::ConfigOverrides;
+use tempfile::TempDir;
+
+/// Returns a default `Config` whose on-disk state is confined to the provided
+/// temporary directory. Using a per-test directory keeps tests hermetic and
+/// avoids clobbering a developer’s real `~/.codex`.
+pub async fn load_default_config_for_test(codex_home: &TempDir) -> Config {
+    load_default_config_for_test_with_overrides(
+        codex_home,
+        default_test_overrides(),
+        CloudConfigBundleLoader::default(),
+    )
+    .await
+}
+
+/// [`load_default_config_for_test`] with explicit harness overrides and cloud
+/// bundle requirements applied during config construction.
+pub async fn load_default_config_for_test_with_overrides(
+    codex_home: &TempDir,
+    harness_overrides: ConfigOverrides,
+    cloud_config_bundle: CloudConfigBundleLoader,
+) -> Config {
+    ConfigBuilder::default()
+        .loader_overrides(LoaderOverrides::without_managed_config_for_tests())
+        .codex_home(codex_home.path().to_path_buf())
+        .harness_overrides(harness_overrides)
+        .cloud_config_bundle(cloud_config_bundle)
+        .build()
+        .await
+        .expect("defaults for test should always succeed")
+}
+
+#[cfg(target_os = "linux")]
+fn default_test_overrides() -> ConfigOverrides {
+    // Linux sandboxing re-executes the current binary; test processes built
+    // with arg0 dispatch handle this, and `cargo_bin` covers harnesses that
+    // ship the standalone codex-linux-sandbox binary instead.
+    let codex_linux_sandbox_exe = std::env::current_exe()
+        .ok()
+        .or_else(|| codex_utils_cargo_bin::cargo_bin("codex-linux-sandbox").ok());
+    ConfigOverrides {
+        codex_linux_sandbox_exe,
+        ..ConfigOverrides::default()
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn default_test_overrides() -> ConfigOverrides {
+    ConfigOverrides::default()
+}
@@ -0,0 +1,40 @@
+use codex_core::CodexThread;
+use codex_protocol::protocol::EventMsg;
+
+pub async fn wait_for_event<F>(codex: &CodexThread, predicate: F) -> EventMsg
+where
+    F: FnMut(&EventMsg) -> bool,
+{
+    use tokio::time::Duration;
+    wait_for_event_with_timeout(codex, predicate, Duration::from_secs(1)).await
+}
+
+pub async fn wait_for_event_match<T, F>(codex: &CodexThread, matcher: F) -> T
+where
+    F: Fn(&EventMsg) -> Option<T>,
+{
+    let ev = wait_for_event(codex, |ev| matcher(ev).is_some()).await;
+    matcher(&ev).expect("EventMsg should match matcher predicate")
+}
+
+pub async fn wait_for_event_with_timeout<F>(
+    codex: &CodexThread,
+    mut predicate: F,
+    wait_time: tokio::time::Duration,
+) -> EventMsg
+where
+    F: FnMut(&EventMsg) -> bool,
+{
+    use tokio::time::Duration;
+    use tokio::time::timeout;
+    loop {
+        // Allow a bit more time to accommodate async startup work (e.g. config IO, tool discovery)
+        let ev = timeout(wait_time.max(Duration::from_secs(10)), codex.next_event())
+            .await
+            .expect("timeout waiting for event")
+            .expect("stream ended unexpectedly");
+        if predicate(&ev.msg) {
+            return ev.msg;
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Conversation-test helpers for projects embedding `codex-core`.
+//!
+//! Provides the pieces needed to write deterministic conversation tests
+//! without copying test modules between repositories:
+//!
+//! - [`responses`]: a wiremock-backed mock model provider (`start_mock_server`,
+//!   `mount_sse_once`, the `ev_*` SSE event builders, and request inspection).
+//! - [`SseResponseBuilder`]: a builder-style wrapper over the `ev_*` helpers
+//!   for composing and mounting a full SSE response.
+//! - [`wait_for_event`] / [`wait_for_event_match`] /
+//!   [`wait_for_event_with_timeout`]: drive a [`codex_core::CodexThread`]
+//!   until an expected event arrives.
+//! - [`load_default_config_for_test`]: a hermetic default `Config` confined
+//!   to a per-test `CODEX_HOME`.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+pub mod responses;
+pub mod streaming_sse;
+
+mod config;
+mod events;
+mod sse_builder;
+
+pub use config::load_default_config_for_test;
+pub use config::load_default_config_for_test_with_overrides;
+pub use events::wait_for_event;
+pub use events::wait_for_event_match;
+pub use events::wait_for_event_with_timeout;
+pub use sse_builder::SseResponseBuilder;
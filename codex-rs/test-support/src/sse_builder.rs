@@ -0,0 +1,82 @@
+use serde_json::Value;
+use wiremock::MockServer;
+
+use crate::responses;
+use crate::responses::ResponseMock;
+
+/// Builder-style composition of a single mock SSE response.
+///
+/// Wraps the `ev_*` helpers in [`crate::responses`] so a full response can be
+/// assembled and mounted fluently:
+///
+/// ```ignore
+/// let mock = SseResponseBuilder::new()
+///     .created("resp-1")
+///     .assistant_message("msg-1", "done")
+///     .completed("resp-1")
+///     .mount_once(&server)
+///     .await;
+/// ```
+#[derive(Default)]
+pub struct SseResponseBuilder {
+    events: Vec<Value>,
+}
+
+impl SseResponseBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn created(mut self, response_id: &str) -> Self {
+        self.events
+            .push(responses::ev_response_created(response_id));
+        self
+    }
+
+    pub fn assistant_message(mut self, item_id: &str, text: &str) -> Self {
+        self.events
+            .push(responses::ev_assistant_message(item_id, text));
+        self
+    }
+
+    pub fn output_text_delta(mut self, delta: &str) -> Self {
+        self.events.push(responses::ev_output_text_delta(delta));
+        self
+    }
+
+    pub fn function_call(mut self, call_id: &str, name: &str, arguments: &str) -> Self {
+        self.events
+            .push(responses::ev_function_call(call_id, name, arguments));
+        self
+    }
+
+    pub fn completed(mut self, response_id: &str) -> Self {
+        self.events.push(responses::ev_completed(response_id));
+        self
+    }
+
+    pub fn completed_with_tokens(mut self, response_id: &str, total_tokens: i64) -> Self {
+        self.events.push(responses::ev_completed_with_tokens(
+            response_id,
+            total_tokens,
+        ));
+        self
+    }
+
+    /// Appends an arbitrary raw SSE event for cases the named helpers do not
+    /// cover.
+    pub fn event(mut self, event: Value) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Renders the accumulated events as an SSE body.
+    pub fn build(self) -> String {
+        responses::sse(self.events)
+    }
+
+    /// Mounts the response on the mock server, serving it exactly once.
+    pub async fn mount_once(self, server: &MockServer) -> ResponseMock {
+        responses::mount_sse_once(server, self.build()).await
+    }
+}
@@ -284,6 +284,27 @@ fn new_config(model: Option<String>, arg0_paths: Arg0DispatchPaths) -> anyhow::R
         code_mode: Default::default(),
         use_experimental_unified_exec_tool: false,
         background_terminal_max_timeout: 300_000,
+        normalize_pty_output: true,
+        ascii_output: false,
+        colorblind_output: false,
+        model_temperature: None,
+        model_top_p: None,
+        model_seed: None,
+        fetch_url_tool: false,
+        web_search_provider: None,
+        render_read_documents: false,
+        auto_name_threads: true,
+        parallel: Default::default(),
+        redact_secrets: false,
+        git_snapshots: false,
+        config_watch: false,
+        search_workspace_tool: false,
+        file_io_tools: false,
+        tui_theme_overrides: None,
+        sessions_encryption_key: None,
+        compact_user_message_max_tokens: None,
+        dry_run: false,
+        patch_output_dir: None,
         ghost_snapshot: GhostSnapshotConfig::default(),
         multi_agent_v2: MultiAgentV2Config::default(),
         token_budget: None,
@@ -384,16 +384,18 @@ impl App {
                         .add_error_message(format!("Failed to retry with a faster model: {err}"));
                     return Ok(AppRunControl::Continue);
                 }
-                let rollback_response =
-                    match app_server.thread_rollback(thread_id, /*num_turns*/ 1).await {
-                        Ok(response) => response,
-                        Err(err) => {
-                            self.chat_widget.add_error_message(format!(
-                                "Failed to retry with a faster model: {err}"
-                            ));
-                            return Ok(AppRunControl::Continue);
-                        }
-                    };
+                let rollback_response = match app_server
+                    .thread_rollback(thread_id, /*num_turns*/ 1, /*undo_file_changes*/ false)
+                    .await
+                {
+                    Ok(response) => response,
+                    Err(err) => {
+                        self.chat_widget.add_error_message(format!(
+                            "Failed to retry with a faster model: {err}"
+                        ));
+                        return Ok(AppRunControl::Continue);
+                    }
+                };
 
                 self.chat_widget.prepare_safety_buffering_retry();
                 self.handle_thread_rollback_response_with_origin(
@@ -1313,6 +1315,29 @@ impl App {
                     let _ = (preset, profile_selection);
                 }
             }
+            AppEvent::ApplyAgentRole { role } => {
+                // Validate against the current config before arming the role
+                // for subsequent threads.
+                let mut probe = self.config.clone();
+                match codex_core::apply_agent_role(&mut probe, &role).await {
+                    Ok(()) => {
+                        self.active_role = Some(role.clone());
+                        self.chat_widget.add_info_message(
+                            format!("Role `{role}` armed; starting a new thread with it."),
+                            /*hint*/ None,
+                        );
+                        self.start_fresh_session_with_summary_hint(
+                            tui, app_server, /*session_start_source*/ None,
+                            /*initial_user_message*/ None,
+                        )
+                        .await;
+                    }
+                    Err(err) => {
+                        self.chat_widget
+                            .add_error_message(format!("Failed to apply role `{role}`: {err}"));
+                    }
+                }
+            }
             AppEvent::BeginWindowsSandboxGrantReadRoot { path } => {
                 #[cfg(target_os = "windows")]
                 {
@@ -2035,13 +2060,23 @@ impl App {
                 self.chat_widget.handle_manage_skills_closed();
             }
             AppEvent::FullScreenApprovalRequest(request) => match request {
-                ApprovalRequest::ApplyPatch { cwd, changes, .. } => {
+                ApprovalRequest::ApplyPatch {
+                    thread_id,
+                    id,
+                    cwd,
+                    changes,
+                    ..
+                } => {
                     let _ = tui.enter_alt_screen();
-                    let diff_summary = DiffSummary::new(changes, cwd);
-                    self.overlay = Some(Overlay::new_static_with_renderables(
-                        vec![diff_summary.into()],
-                        "P A T C H".to_string(),
+                    self.overlay = Some(Overlay::new_diff(
+                        changes,
+                        cwd,
                         self.keymap.pager.clone(),
+                        Some(crate::pager_overlay::DiffOverlayApproval {
+                            app_event_tx: self.app_event_tx.clone(),
+                            thread_id,
+                            item_id: id,
+                        }),
                     ));
                 }
                 ApprovalRequest::Exec { command, .. } => {
@@ -196,7 +196,9 @@ impl App {
             // with the composer focused and empty. In any other state, forward
             // Esc so the active UI (e.g. status indicator, modals, popups)
             // handles it.
-            if self.should_handle_backtrack_esc(key_event) {
+            if self.backtrack.staged.is_some() && self.chat_widget.no_modal_or_popup_active() {
+                self.cancel_staged_backtrack(tui);
+            } else if self.should_handle_backtrack_esc(key_event) {
                 self.handle_backtrack_esc_key(tui);
             } else if self.should_reject_side_backtrack_esc(key_event) {
                 self.reject_side_backtrack_esc();
@@ -223,6 +225,18 @@ impl App {
                     tui.frame_requester().schedule_frame();
                 }
             }
+            // Enter sends a staged backtrack edit: fork first, then let the
+            // widget submit the edited message as the next user input.
+            KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.backtrack.staged.is_some() && !self.chat_widget.composer_is_empty() => {
+                if let Some(staged) = self.backtrack.staged.take() {
+                    self.submit_backtrack_rollback(staged.selection);
+                }
+                self.chat_widget.handle_key_event(key_event);
+            }
             // Enter confirms backtrack when primed + count > 0. Otherwise pass to widget.
             KeyEvent {
                 code: KeyCode::Enter,
@@ -546,6 +546,12 @@ impl App {
             &self.cli_kv_overrides,
             &self.harness_overrides,
         );
+        if let Some(role) = self.active_role.clone()
+            && let Err(err) = codex_core::apply_agent_role(&mut config, &role).await
+        {
+            self.chat_widget
+                .add_error_message(format!("Failed to apply role `{role}`: {err}"));
+        }
         let summary = session_summary(
             self.chat_widget.token_usage(),
             self.chat_widget.thread_id(),
@@ -4067,6 +4067,7 @@ async fn make_test_app() -> App {
         cloud_config_bundle: CloudConfigBundleLoader::default(),
         runtime_approval_policy_override: None,
         runtime_permission_profile_override: None,
+        active_role: None,
         file_search,
         transcript_cells: Vec::new(),
         overlay: None,
@@ -4132,6 +4133,7 @@ async fn make_test_app_with_channels() -> (
             cloud_config_bundle: CloudConfigBundleLoader::default(),
             runtime_approval_policy_override: None,
             runtime_permission_profile_override: None,
+            active_role: None,
             file_search,
             transcript_cells: Vec::new(),
             overlay: None,
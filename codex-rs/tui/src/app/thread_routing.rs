@@ -698,8 +698,14 @@ impl App {
                     .await?;
                 Ok(true)
             }
-            AppCommand::ThreadRollback { num_turns } => {
-                let response = match app_server.thread_rollback(thread_id, *num_turns).await {
+            AppCommand::ThreadRollback {
+                num_turns,
+                undo_file_changes,
+            } => {
+                let response = match app_server
+                    .thread_rollback(thread_id, *num_turns, *undo_file_changes)
+                    .await
+                {
                     Ok(response) => response,
                     Err(err) => {
                         self.handle_backtrack_rollback_failed();
@@ -71,6 +71,17 @@ pub(crate) struct BacktrackState {
     /// This acts as a guardrail: once we request a rollback, we block additional backtrack
     /// submissions until core responds with either a success or failure event.
     pub(crate) pending_rollback: Option<PendingBacktrackRollback>,
+    /// A confirmed backtrack staged for edit-before-fork: the composer holds
+    /// the selected message, and the rollback is submitted only when the user
+    /// sends it (Esc cancels and restores the overlay preview).
+    pub(crate) staged: Option<StagedBacktrack>,
+}
+
+/// A confirmed backtrack selection whose fork is deferred until send.
+#[derive(Debug, Clone)]
+pub(crate) struct StagedBacktrack {
+    pub(crate) selection: BacktrackSelection,
+    pub(crate) num_turns: u32,
 }
 
 /// A user-visible backtrack choice that can be confirmed into a rollback request.
@@ -193,50 +204,121 @@ impl App {
     /// The composer prefill is applied immediately as a UX convenience; it does not imply that
     /// core has accepted the rollback.
     pub(crate) fn apply_backtrack_rollback(&mut self, selection: BacktrackSelection) {
+        let prefill = selection.prefill.clone();
+        let text_elements = selection.text_elements.clone();
+        let local_image_paths = selection.local_image_paths.clone();
+        let remote_image_urls = selection.remote_image_urls.clone();
+        let has_remote_image_urls = !remote_image_urls.is_empty();
+        if !self.submit_backtrack_rollback(selection) {
+            return;
+        }
+        self.chat_widget.set_remote_image_urls(remote_image_urls);
+        if !prefill.is_empty()
+            || !text_elements.is_empty()
+            || !local_image_paths.is_empty()
+            || has_remote_image_urls
+        {
+            self.chat_widget
+                .set_composer_text(prefill, text_elements, local_image_paths);
+        }
+    }
+
+    /// Submits the rollback op for a selection and records the pending guard,
+    /// without touching the composer. Returns whether the rollback was sent.
+    pub(crate) fn submit_backtrack_rollback(&mut self, selection: BacktrackSelection) -> bool {
         if self.chat_widget.side_conversation_active() {
             self.reset_backtrack_state();
             self.chat_widget
                 .add_error_message(SIDE_EDIT_PREVIOUS_UNAVAILABLE_MESSAGE.to_string());
-            return;
+            return false;
         }
 
         let user_total = user_count(&self.transcript_cells);
         if user_total == 0 {
-            return;
+            return false;
         }
 
         if self.backtrack.pending_rollback.is_some() {
             self.chat_widget
                 .add_error_message("Backtrack rollback already in progress.".to_string());
-            return;
+            return false;
         }
 
         let num_turns = user_total.saturating_sub(selection.nth_user_message);
         let num_turns = u32::try_from(num_turns).unwrap_or(u32::MAX);
         if num_turns == 0 {
-            return;
+            return false;
         }
 
-        let prefill = selection.prefill.clone();
-        let text_elements = selection.text_elements.clone();
-        let local_image_paths = selection.local_image_paths.clone();
-        let remote_image_urls = selection.remote_image_urls.clone();
-        let has_remote_image_urls = !remote_image_urls.is_empty();
         self.backtrack.pending_rollback = Some(PendingBacktrackRollback {
             selection,
             thread_id: self.chat_widget.thread_id(),
         });
         self.chat_widget
             .submit_op(AppCommand::thread_rollback(num_turns));
-        self.chat_widget.set_remote_image_urls(remote_image_urls);
-        if !prefill.is_empty()
-            || !text_elements.is_empty()
-            || !local_image_paths.is_empty()
-            || has_remote_image_urls
-        {
+        true
+    }
+
+    /// Stages a confirmed backtrack for edit-before-fork: prefills the
+    /// composer, announces how many later messages the fork will drop, and
+    /// defers the rollback until the user sends (Esc cancels).
+    pub(crate) fn stage_backtrack_edit(
+        &mut self,
+        tui: &mut tui::Tui,
+        selection: BacktrackSelection,
+    ) {
+        if self.chat_widget.side_conversation_active() {
+            self.reset_backtrack_state();
             self.chat_widget
-                .set_composer_text(prefill, text_elements, local_image_paths);
+                .add_error_message(SIDE_EDIT_PREVIOUS_UNAVAILABLE_MESSAGE.to_string());
+            return;
+        }
+        let user_total = user_count(&self.transcript_cells);
+        let num_turns = u32::try_from(user_total.saturating_sub(selection.nth_user_message))
+            .unwrap_or(u32::MAX);
+        if num_turns == 0 {
+            return;
         }
+        self.chat_widget
+            .set_remote_image_urls(selection.remote_image_urls.clone());
+        self.chat_widget.set_composer_text(
+            selection.prefill.clone(),
+            selection.text_elements.clone(),
+            selection.local_image_paths.clone(),
+        );
+        let noun = if num_turns == 1 {
+            "message"
+        } else {
+            "messages"
+        };
+        self.chat_widget.add_info_message(
+            format!(
+                "Editing a previous message: {num_turns} {noun} from here on will be dropped when you send."
+            ),
+            Some("press Esc to cancel and return to the transcript".to_string()),
+        );
+        self.backtrack.staged = Some(StagedBacktrack {
+            selection,
+            num_turns,
+        });
+        tui.frame_requester().schedule_frame();
+    }
+
+    /// Cancels a staged backtrack edit: clears the prefill and restores the
+    /// transcript overlay preview on the same selection.
+    pub(crate) fn cancel_staged_backtrack(&mut self, tui: &mut tui::Tui) {
+        let Some(staged) = self.backtrack.staged.take() else {
+            return;
+        };
+        self.chat_widget
+            .set_composer_text(String::new(), Vec::new(), Vec::new());
+        self.chat_widget.set_remote_image_urls(Vec::new());
+        self.open_transcript_overlay(tui);
+        self.backtrack.primed = true;
+        self.backtrack.base_id = self.chat_widget.thread_id();
+        self.backtrack.overlay_preview_active = true;
+        self.apply_backtrack_selection_internal(staged.selection.nth_user_message);
+        tui.frame_requester().schedule_frame();
     }
 
     pub(crate) fn apply_cancelled_turn_edit(&mut self, prompt: UserMessage) {
@@ -464,8 +546,7 @@ impl App {
         let selection = self.backtrack_selection(nth_user_message);
         self.close_transcript_overlay(tui);
         if let Some(selection) = selection {
-            self.apply_backtrack_rollback(selection);
-            tui.frame_requester().schedule_frame();
+            self.stage_backtrack_edit(tui, selection);
         }
     }
 
@@ -506,6 +587,7 @@ impl App {
         self.backtrack.primed = false;
         self.backtrack.base_id = None;
         self.backtrack.nth_user_message = usize::MAX;
+        self.backtrack.staged = None;
         // In case a hint is somehow still visible (e.g., race with overlay open/close).
         self.chat_widget.clear_esc_backtrack_hint();
     }
@@ -515,8 +597,7 @@ impl App {
         tui: &mut tui::Tui,
         selection: BacktrackSelection,
     ) {
-        self.apply_backtrack_rollback(selection);
-        tui.frame_requester().schedule_frame();
+        self.stage_backtrack_edit(tui, selection);
     }
 
     pub(crate) fn handle_backtrack_rollback_succeeded(&mut self, num_turns: u32) {
@@ -688,7 +769,7 @@ fn nth_user_position(
         .find_map(|(i, idx)| (i == nth).then_some(idx))
 }
 
-fn user_positions_iter(
+pub(crate) fn user_positions_iter(
     cells: &[Arc<dyn crate::history_cell::HistoryCell>],
 ) -> impl Iterator<Item = usize> + '_ {
     let session_start_type = TypeId::of::<SessionInfoCell>();
@@ -95,6 +95,8 @@ pub(crate) enum AppCommand {
     Shutdown,
     ThreadRollback {
         num_turns: u32,
+        /// Also restore the git snapshot recorded before the dropped turns.
+        undo_file_changes: bool,
     },
     Review {
         target: ReviewTarget,
@@ -257,7 +259,19 @@ impl AppCommand {
     }
 
     pub(crate) fn thread_rollback(num_turns: u32) -> Self {
-        Self::ThreadRollback { num_turns }
+        Self::ThreadRollback {
+            num_turns,
+            undo_file_changes: false,
+        }
+    }
+
+    /// Rollback that also reverts the dropped turns' file changes from git
+    /// snapshots, backing the `/undo` command.
+    pub(crate) fn undo_turns(num_turns: u32) -> Self {
+        Self::ThreadRollback {
+            num_turns,
+            undo_file_changes: true,
+        }
     }
 
     pub(crate) fn review(target: ReviewTarget) -> Self {
@@ -809,6 +809,11 @@ pub(crate) enum AppEvent {
         path: String,
     },
 
+    /// Apply a named role preset; it takes effect on the next thread.
+    ApplyAgentRole {
+        role: String,
+    },
+
     /// Result of attempting to grant read access for an additional directory.
     #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
     WindowsSandboxGrantReadRootCompleted {
@@ -1080,6 +1080,7 @@ impl AppServerSession {
         &mut self,
         thread_id: ThreadId,
         num_turns: u32,
+        undo_file_changes: bool,
     ) -> Result<ThreadRollbackResponse> {
         let request_id = self.next_request_id();
         self.client
@@ -1088,6 +1089,7 @@ impl AppServerSession {
                 params: ThreadRollbackParams {
                     thread_id: thread_id.to_string(),
                     num_turns,
+                    undo_file_changes: Some(undo_file_changes),
                 },
             })
             .await
@@ -629,7 +629,12 @@ impl AppLinkView {
             }
             .dim(),
         ]));
-        let url_line = Line::from(vec![self.url.clone().cyan().underlined()]);
+        let url_line = Line::from(vec![
+            self.url
+                .clone()
+                .fg(crate::ui_theme::ui_theme().accent)
+                .underlined(),
+        ]);
         lines.extend(adaptive_wrap_lines(
             vec![url_line],
             RtOptions::new(usable_width),
@@ -707,7 +707,7 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
             {
                 header.push(Line::from(vec![
                     "Permission rule: ".into(),
-                    rule_line.cyan(),
+                    rule_line.fg(crate::ui_theme::ui_theme().accent),
                 ]));
                 header.push(Line::from(""));
             }
@@ -750,7 +750,7 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
             if let Some(rule_line) = format_requested_permissions_rule(permissions) {
                 header.push(Line::from(vec![
                     "Permission rule: ".into(),
-                    rule_line.cyan(),
+                    rule_line.fg(crate::ui_theme::ui_theme().accent),
                 ]));
             }
             Box::new(Paragraph::new(header).wrap(Wrap { trim: false }))
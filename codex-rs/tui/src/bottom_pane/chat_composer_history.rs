@@ -11,6 +11,7 @@
 //! arrives, and boundary hits must not advance hidden cursor state. Search deduplication is scoped
 //! to a single active search session and uses exact prompt text; it does not mutate stored history
 //! or change normal history browsing.
+use codex_utils_fuzzy_match::fuzzy_match;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -702,7 +703,13 @@ impl ChatComposerHistory {
         let Some(search) = self.search.as_ref() else {
             return false;
         };
-        search.query.is_empty() || entry.text.to_lowercase().contains(&search.query_lower)
+        if search.query.is_empty() {
+            return true;
+        }
+        // Prefer exact substring hits, but fall back to the shared fuzzy
+        // matcher so "ct" still finds "cargo test".
+        entry.text.to_lowercase().contains(&search.query_lower)
+            || fuzzy_match(&entry.text, &search.query).is_some()
     }
 
     fn search_result_is_unique(&self, entry: &HistoryEntry) -> bool {
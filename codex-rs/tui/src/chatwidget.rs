@@ -546,6 +546,8 @@ pub(crate) struct ChatWidget {
     model_catalog: Arc<ModelCatalog>,
     session_telemetry: SessionTelemetry,
     session_header: SessionHeader,
+    /// Set once a heuristic auto-title has been submitted for this thread.
+    auto_name_submitted: bool,
     initial_user_message: Option<UserMessage>,
     status_account_display: Option<StatusAccountDisplay>,
     runtime_model_provider_base_url: Option<String>,
@@ -1899,6 +1901,23 @@ impl ChatWidget {
         self.thread_id
     }
 
+    /// Derives a heuristic title from the first prompt of an unnamed thread
+    /// and submits it as the thread name, so sessions are identifiable by
+    /// more than timestamps and ids. Disabled via `auto_name_threads`.
+    pub(crate) fn maybe_auto_name_thread(&mut self, prompt: &str) {
+        if !self.config.auto_name_threads
+            || self.auto_name_submitted
+            || self.thread_name().is_some()
+        {
+            return;
+        }
+        let Some(name) = heuristic_thread_name(prompt) else {
+            return;
+        };
+        self.auto_name_submitted = true;
+        self.app_event_tx.set_thread_name(name);
+    }
+
     pub(crate) fn thread_name(&self) -> Option<String> {
         self.thread_name.clone()
     }
@@ -2070,5 +2089,26 @@ fn extract_first_bold(s: &str) -> Option<String> {
     None
 }
 
+/// Builds a compact word-boundary-truncated title from the first non-empty
+/// line of a prompt; returns `None` when nothing presentable remains.
+fn heuristic_thread_name(prompt: &str) -> Option<String> {
+    const MAX_TITLE_CHARS: usize = 48;
+    let first_line = prompt.lines().find(|line| !line.trim().is_empty())?;
+    let cleaned = first_line
+        .trim()
+        .trim_start_matches(['#', '>', '-', '*', ' ']);
+    let mut name = String::new();
+    for word in cleaned.split_whitespace() {
+        if !name.is_empty() && name.len() + word.len() + 1 > MAX_TITLE_CHARS {
+            break;
+        }
+        if !name.is_empty() {
+            name.push(' ');
+        }
+        name.push_str(word);
+    }
+    codex_core::util::normalize_thread_name(&name)
+}
+
 #[cfg(test)]
 pub(crate) mod tests;
@@ -118,6 +118,7 @@ impl ChatWidget {
             model_catalog,
             session_telemetry,
             session_header: SessionHeader::new(header_model),
+            auto_name_submitted: false,
             initial_user_message,
             status_account_display,
             runtime_model_provider_base_url,
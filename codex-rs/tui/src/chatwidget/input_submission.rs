@@ -354,6 +354,7 @@ impl ChatWidget {
         if !self.submit_op(op.clone()) {
             return (false, None);
         }
+        self.maybe_auto_name_thread(&text);
         if render_in_history {
             self.input_queue.user_turn_pending_start = true;
         }
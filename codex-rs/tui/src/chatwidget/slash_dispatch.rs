@@ -260,6 +260,21 @@ impl ChatWidget {
                 }
                 self.app_event_tx.compact();
             }
+            SlashCommand::Undo => {
+                if self.bottom_pane.is_task_running() {
+                    self.add_error_message("Cannot undo while a turn is in progress.".to_string());
+                } else {
+                    self.submit_op(AppCommand::undo_turns(/*num_turns*/ 1));
+                    self.add_info_message(
+                        "Undoing the last turn.".to_string(),
+                        Some(
+                            "your last message and the agent's response will be dropped; \
+                             file changes are restored when git snapshots are enabled"
+                                .to_string(),
+                        ),
+                    );
+                }
+            }
             SlashCommand::Review => {
                 self.open_review_popup();
             }
@@ -367,6 +382,9 @@ impl ChatWidget {
                     "Usage: /sandbox-add-read-dir <absolute-directory-path>".to_string(),
                 );
             }
+            SlashCommand::Role => {
+                self.add_error_message("Usage: /role <name> (e.g. /role planner)".to_string());
+            }
             SlashCommand::Experimental => {
                 self.open_experimental_popup();
             }
@@ -879,6 +897,11 @@ impl ChatWidget {
                 self.app_event_tx
                     .send(AppEvent::BeginWindowsSandboxGrantReadRoot { path: args });
             }
+            SlashCommand::Role if !trimmed.is_empty() => {
+                self.app_event_tx.send(AppEvent::ApplyAgentRole {
+                    role: trimmed.to_string(),
+                });
+            }
             SlashCommand::Pets
                 if matches!(
                     args.trim().to_ascii_lowercase().as_str(),
@@ -1072,6 +1095,8 @@ impl ChatWidget {
             | SlashCommand::Model
             | SlashCommand::Personality
             | SlashCommand::Plan
+            | SlashCommand::Role
+            | SlashCommand::Undo
             | SlashCommand::Goal
             | SlashCommand::Side
             | SlashCommand::Btw
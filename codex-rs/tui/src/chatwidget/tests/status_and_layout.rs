@@ -4009,6 +4009,7 @@ async fn chatwidget_exec_and_status_layout_vt100_snapshot() {
             name: "diff_render.rs".into(),
             cmd: "cat diff_render.rs".into(),
             path: "diff_render.rs".into(),
+            range: None,
         },
     ];
     let command_actions = parsed_cmd
@@ -5,6 +5,7 @@ use super::model::ExecCall;
 use super::model::ExecCell;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::history_cell::HistoryCell;
+use crate::history_cell::TranscriptCellKind;
 use crate::history_cell::plain_lines;
 use crate::motion::MotionMode;
 use crate::motion::ReducedMotionIndicator;
@@ -193,6 +194,10 @@ fn activity_marker(start_time: Option<Instant>, animations_enabled: bool) -> Spa
 }
 
 impl HistoryCell for ExecCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::Command
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         if self.is_exploring_cell() {
             self.exploring_display_lines(width)
@@ -231,10 +236,16 @@ impl HistoryCell for ExecCell {
                     .map(format_duration)
                     .unwrap_or_else(|| "unknown".to_string());
                 let mut result: Line = if output.exit_code == 0 {
-                    Line::from("✓".green().bold())
+                    Line::from(
+                        crate::glyphs::check()
+                            .fg(crate::ui_theme::ui_theme().success)
+                            .bold(),
+                    )
                 } else {
                     Line::from(vec![
-                        "✗".red().bold(),
+                        crate::glyphs::cross()
+                            .fg(crate::ui_theme::ui_theme().error)
+                            .bold(),
                         format!(" ({})", output.exit_code).into(),
                     ])
                 };
@@ -308,7 +319,7 @@ impl ExecCell {
                     .parsed
                     .iter()
                     .map(|parsed| match parsed {
-                        ParsedCommand::Read { name, .. } => name.clone(),
+                        ParsedCommand::Read { name, range, .. } => read_display_name(name, *range),
                         _ => unreachable!(),
                     })
                     .unique();
@@ -320,8 +331,8 @@ impl ExecCell {
                 let mut lines = Vec::new();
                 for parsed in &call.parsed {
                     match parsed {
-                        ParsedCommand::Read { name, .. } => {
-                            lines.push(("Read", vec![name.clone().into()]));
+                        ParsedCommand::Read { name, range, .. } => {
+                            lines.push(("Read", vec![read_display_name(name, *range).into()]));
                         }
                         ParsedCommand::ListFiles { cmd, path } => {
                             lines.push(("List", vec![path.clone().unwrap_or(cmd.clone()).into()]));
@@ -369,8 +380,12 @@ impl ExecCell {
         let layout = EXEC_DISPLAY_LAYOUT;
         let success = call.output.as_ref().map(|o| o.exit_code == 0);
         let bullet = match success {
-            Some(true) => "•".green().bold(),
-            Some(false) => "•".red().bold(),
+            Some(true) => crate::glyphs::bullet()
+                .fg(crate::ui_theme::ui_theme().success)
+                .bold(),
+            Some(false) => crate::glyphs::bullet()
+                .fg(crate::ui_theme::ui_theme().error)
+                .bold(),
             None => activity_marker(call.start_time, self.animations_enabled()),
         };
         let is_interaction = call.is_unified_exec_interaction();
@@ -1093,3 +1108,13 @@ mod tests {
         );
     }
 }
+
+/// Displays a read target with its line range when known, e.g.
+/// `foo.rs:120–180` (open-ended ranges render as `foo.rs:120–`).
+fn read_display_name(name: &str, range: Option<(u32, u32)>) -> String {
+    match range {
+        Some((start, end)) if end == u32::MAX => format!("{name}:{start}–"),
+        Some((start, end)) => format!("{name}:{start}–{end}"),
+        None => name.to_string(),
+    }
+}
@@ -0,0 +1,43 @@
+//! ASCII fallbacks for status glyphs.
+//!
+//! Some terminals and fonts render `✓`/`✗`-style symbols badly; when
+//! `ascii_output = true` in config, widgets ask this module for markers so
+//! the whole TUI degrades to ASCII consistently.
+
+use std::sync::OnceLock;
+
+static ASCII_ONLY: OnceLock<bool> = OnceLock::new();
+
+/// Installs the ASCII-only preference from config; first call wins.
+pub(crate) fn init_ascii_output(enabled: bool) {
+    let _ = ASCII_ONLY.set(enabled);
+}
+
+fn ascii_only() -> bool {
+    ASCII_ONLY.get().copied().unwrap_or(false)
+}
+
+/// Success marker (`✓`).
+pub(crate) fn check() -> &'static str {
+    if ascii_only() { "ok" } else { "✓" }
+}
+
+/// Failure marker (`✗`).
+pub(crate) fn cross() -> &'static str {
+    if ascii_only() { "x" } else { "✗" }
+}
+
+/// Failure marker with the trailing space used by prefixed history cells.
+pub(crate) fn cross_padded() -> &'static str {
+    if ascii_only() { "x " } else { "✗ " }
+}
+
+/// Success marker with the trailing space used by prefixed history cells.
+pub(crate) fn check_padded() -> &'static str {
+    if ascii_only() { "ok " } else { "✔ " }
+}
+
+/// Status bullet used for boolean run markers.
+pub(crate) fn bullet() -> &'static str {
+    if ascii_only() { "*" } else { "•" }
+}
@@ -69,10 +69,13 @@ pub fn new_approval_decision_cell(
                         " this time".bold(),
                     ]
                 };
-                ("✔ ".green(), summary)
+                (
+                    crate::glyphs::check_padded().fg(crate::ui_theme::ui_theme().success),
+                    summary,
+                )
             }
             ApprovalDecisionSubject::NetworkAccess { target } => (
-                "✔ ".green(),
+                crate::glyphs::check_padded().fg(crate::ui_theme::ui_theme().success),
                 vec![
                     actor.subject().into(),
                     "approved".bold(),
@@ -87,7 +90,7 @@ pub fn new_approval_decision_cell(
         } => {
             let snippet = Span::from(exec_snippet(&proposed_execpolicy_amendment.command)).dim();
             (
-                "✔ ".green(),
+                crate::glyphs::check_padded().fg(crate::ui_theme::ui_theme().success),
                 vec![
                     actor.subject().into(),
                     "approved".bold(),
@@ -114,10 +117,13 @@ pub fn new_approval_decision_cell(
                         " every time this session".bold(),
                     ]
                 };
-                ("✔ ".green(), summary)
+                (
+                    crate::glyphs::check_padded().fg(crate::ui_theme::ui_theme().success),
+                    summary,
+                )
             }
             ApprovalDecisionSubject::NetworkAccess { target } => (
-                "✔ ".green(),
+                crate::glyphs::check_padded().fg(crate::ui_theme::ui_theme().success),
                 vec![
                     actor.subject().into(),
                     "approved".bold(),
@@ -136,7 +142,7 @@ pub fn new_approval_decision_cell(
             };
             match network_policy_amendment.action {
                 NetworkPolicyRuleAction::Allow => (
-                    "✔ ".green(),
+                    crate::glyphs::check_padded().fg(crate::ui_theme::ui_theme().success),
                     vec![
                         actor.subject().into(),
                         "persisted".bold(),
@@ -145,7 +151,7 @@ pub fn new_approval_decision_cell(
                     ],
                 ),
                 NetworkPolicyRuleAction::Deny => (
-                    "✗ ".red(),
+                    crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
                     vec![
                         actor.subject().into(),
                         "denied".bold(),
@@ -186,10 +192,13 @@ pub fn new_approval_decision_cell(
                         }
                     }
                 };
-                ("✗ ".red(), summary)
+                (
+                    crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
+                    summary,
+                )
             }
             ApprovalDecisionSubject::NetworkAccess { target } => (
-                "✗ ".red(),
+                crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
                 vec![
                     actor.subject().into(),
                     "did not approve".bold(),
@@ -214,10 +223,13 @@ pub fn new_approval_decision_cell(
                         " before this request could be approved".into(),
                     ]
                 };
-                ("✗ ".red(), summary)
+                (
+                    crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
+                    summary,
+                )
             }
             ApprovalDecisionSubject::NetworkAccess { target } => (
-                "✗ ".red(),
+                crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
                 vec![
                     "Review ".into(),
                     "timed out".bold(),
@@ -242,10 +254,13 @@ pub fn new_approval_decision_cell(
                         " this request".into(),
                     ]
                 };
-                ("✗ ".red(), summary)
+                (
+                    crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
+                    summary,
+                )
             }
             ApprovalDecisionSubject::NetworkAccess { target } => (
-                "✗ ".red(),
+                crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
                 vec![
                     actor.subject().into(),
                     "canceled".bold(),
@@ -295,7 +310,7 @@ pub fn new_guardian_denied_patch_request(files: Vec<String>) -> Box<dyn HistoryC
 
     Box::new(PrefixedWrappedHistoryCell::new(
         Line::from(summary),
-        "✗ ".red(),
+        crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
         "  ",
     ))
 }
@@ -307,7 +322,11 @@ pub fn new_guardian_denied_action_request(summary: String) -> Box<dyn HistoryCel
         " for ".into(),
         Span::from(summary).dim(),
     ]);
-    Box::new(PrefixedWrappedHistoryCell::new(line, "✗ ".red(), "  "))
+    Box::new(PrefixedWrappedHistoryCell::new(
+        line,
+        crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
+        "  ",
+    ))
 }
 
 pub fn new_guardian_approved_action_request(summary: String) -> Box<dyn HistoryCell> {
@@ -317,7 +336,11 @@ pub fn new_guardian_approved_action_request(summary: String) -> Box<dyn HistoryC
         " for ".into(),
         Span::from(summary).dim(),
     ]);
-    Box::new(PrefixedWrappedHistoryCell::new(line, "✔ ".green(), "  "))
+    Box::new(PrefixedWrappedHistoryCell::new(
+        line,
+        crate::glyphs::check_padded().fg(crate::ui_theme::ui_theme().success),
+        "  ",
+    ))
 }
 
 pub fn new_guardian_timed_out_patch_request(files: Vec<String>) -> Box<dyn HistoryCell> {
@@ -337,7 +360,7 @@ pub fn new_guardian_timed_out_patch_request(files: Vec<String>) -> Box<dyn Histo
 
     Box::new(PrefixedWrappedHistoryCell::new(
         Line::from(summary),
-        "✗ ".red(),
+        crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
         "  ",
     ))
 }
@@ -349,7 +372,11 @@ pub fn new_guardian_timed_out_action_request(summary: String) -> Box<dyn History
         " before ".into(),
         Span::from(summary).dim(),
     ]);
-    Box::new(PrefixedWrappedHistoryCell::new(line, "✗ ".red(), "  "))
+    Box::new(PrefixedWrappedHistoryCell::new(
+        line,
+        crate::glyphs::cross_padded().fg(crate::ui_theme::ui_theme().error),
+        "  ",
+    ))
 }
 
 /// Cyan history cell line showing the current review status.
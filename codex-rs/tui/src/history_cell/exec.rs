@@ -18,6 +18,10 @@ impl UnifiedExecInteractionCell {
 }
 
 impl HistoryCell for UnifiedExecInteractionCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::Command
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         if width == 0 {
             return Vec::new();
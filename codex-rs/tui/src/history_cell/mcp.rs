@@ -117,6 +117,10 @@ impl McpToolCallCell {
 }
 
 impl HistoryCell for McpToolCallCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::ToolOutput
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         let mut lines: Vec<Line<'static>> = Vec::new();
         let status = self.success();
@@ -106,6 +106,10 @@ fn trim_trailing_blank_lines(mut lines: Vec<Line<'static>>) -> Vec<Line<'static>
 }
 
 impl HistoryCell for UserHistoryCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::UserMessage
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         let message = sanitize_user_text(&self.message);
         let text_elements = if message == self.message {
@@ -266,6 +270,10 @@ impl ReasoningSummaryCell {
 }
 
 impl HistoryCell for ReasoningSummaryCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::Reasoning
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         if self.transcript_only {
             Vec::new()
@@ -311,6 +319,10 @@ impl AgentMessageCell {
 }
 
 impl HistoryCell for AgentMessageCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::AgentMessage
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         visible_lines(self.display_hyperlink_lines(width))
     }
@@ -382,6 +394,10 @@ impl AgentMarkdownCell {
 }
 
 impl HistoryCell for AgentMarkdownCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::AgentMessage
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         visible_lines(self.display_hyperlink_lines(width))
     }
@@ -437,6 +453,10 @@ impl StreamingAgentTailCell {
 }
 
 impl HistoryCell for StreamingAgentTailCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::AgentMessage
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         visible_lines(self.display_hyperlink_lines(width))
     }
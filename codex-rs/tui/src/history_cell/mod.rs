@@ -186,7 +186,24 @@ pub(crate) fn plain_lines(lines: impl IntoIterator<Item = Line<'static>>) -> Vec
 /// intact by adaptive wrapping). Concrete types only need to override
 /// heights when they apply additional layout logic beyond what
 /// `Paragraph::line_count` captures.
+/// Coarse source category used by transcript filtering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TranscriptCellKind {
+    UserMessage,
+    AgentMessage,
+    Reasoning,
+    Command,
+    ToolOutput,
+    Other,
+}
+
 pub(crate) trait HistoryCell: std::fmt::Debug + Send + Sync + Any {
+    /// Source category this cell was created from, used by the transcript
+    /// overlay's event-type filter.
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::Other
+    }
+
     /// Returns the logical lines for the main chat viewport.
     fn display_lines(&self, width: u16) -> Vec<Line<'static>>;
 
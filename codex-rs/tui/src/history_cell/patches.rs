@@ -84,7 +84,13 @@ pub(crate) fn new_image_generation_call(
 ) -> PlainHistoryCell {
     let detail = revised_prompt.unwrap_or(call_id);
     let heading = if status == "failed" {
-        vec!["✗ ".red().bold(), "Image generation failed".bold()].into()
+        vec![
+            crate::glyphs::cross_padded()
+                .fg(crate::ui_theme::ui_theme().error)
+                .bold(),
+            "Image generation failed".bold(),
+        ]
+        .into()
     } else {
         vec!["• ".dim(), "Generated Image:".bold()].into()
     };
@@ -1643,11 +1643,13 @@ fn coalesces_sequential_reads_within_one_call() {
                     name: "shimmer.rs".into(),
                     cmd: "cat shimmer.rs".into(),
                     path: "shimmer.rs".into(),
+                    range: None,
                 },
                 ParsedCommand::Read {
                     name: "status_indicator_widget.rs".into(),
                     cmd: "cat status_indicator_widget.rs".into(),
                     path: "status_indicator_widget.rs".into(),
+                    range: None,
                 },
             ],
             output: None,
@@ -1696,6 +1698,7 @@ fn coalesces_reads_across_multiple_calls() {
                 name: "shimmer.rs".into(),
                 cmd: "cat shimmer.rs".into(),
                 path: "shimmer.rs".into(),
+                range: None,
             }],
             ExecCommandSource::Agent,
             /*interaction_input*/ None,
@@ -1711,6 +1714,7 @@ fn coalesces_reads_across_multiple_calls() {
                 name: "status_indicator_widget.rs".into(),
                 cmd: "cat status_indicator_widget.rs".into(),
                 path: "status_indicator_widget.rs".into(),
+                range: None,
             }],
             ExecCommandSource::Agent,
             /*interaction_input*/ None,
@@ -1734,16 +1738,19 @@ fn coalesced_reads_dedupe_names() {
                     name: "auth.rs".into(),
                     cmd: "cat auth.rs".into(),
                     path: "auth.rs".into(),
+                    range: None,
                 },
                 ParsedCommand::Read {
                     name: "auth.rs".into(),
                     cmd: "cat auth.rs".into(),
                     path: "auth.rs".into(),
+                    range: None,
                 },
                 ParsedCommand::Read {
                     name: "shimmer.rs".into(),
                     cmd: "cat shimmer.rs".into(),
                     path: "shimmer.rs".into(),
+                    range: None,
                 },
             ],
             output: None,
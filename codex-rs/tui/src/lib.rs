@@ -134,6 +134,7 @@ mod keymap_setup;
 mod line_truncation;
 pub(crate) mod live_wrap;
 pub use live_wrap::RowBuilder;
+mod glyphs;
 mod local_chatgpt_auth;
 mod managed_new_thread_defaults;
 mod markdown;
@@ -184,6 +185,7 @@ mod tooltips;
 mod transcript_reflow;
 mod tui;
 mod ui_consts;
+mod ui_theme;
 pub(crate) mod update_action;
 pub use update_action::UpdateAction;
 #[cfg(not(debug_assertions))]
@@ -1677,6 +1679,11 @@ async fn run_ratatui_app(
     ) {
         config.startup_warnings.push(w);
     }
+    crate::ui_theme::init_ui_theme_with_colorblind_default(
+        config.tui_theme_overrides.as_ref(),
+        config.colorblind_output,
+    );
+    crate::glyphs::init_ascii_output(config.ascii_output);
 
     set_default_client_residency_requirement(config.enforce_residency.value());
     let should_show_trust_screen = should_show_trust_screen(&config);
@@ -545,9 +545,12 @@ impl AuthModeWidget {
 
     fn render_chatgpt_success_message(&self, area: Rect, buf: &mut Buffer) {
         let lines = vec![
-            "✓ Signed in with your ChatGPT account"
-                .fg(Color::Green)
-                .into(),
+            format!(
+                "{} Signed in with your ChatGPT account",
+                crate::glyphs::check()
+            )
+            .fg(crate::ui_theme::ui_theme().success)
+            .into(),
             "".into(),
             "  Before you start:".into(),
             "".into(),
@@ -592,9 +595,12 @@ impl AuthModeWidget {
 
     fn render_chatgpt_success(&self, area: Rect, buf: &mut Buffer) {
         let lines = vec![
-            "✓ Signed in with your ChatGPT account"
-                .fg(Color::Green)
-                .into(),
+            format!(
+                "{} Signed in with your ChatGPT account",
+                crate::glyphs::check()
+            )
+            .fg(crate::ui_theme::ui_theme().success)
+            .into(),
         ];
 
         Paragraph::new(lines)
@@ -604,7 +610,9 @@ impl AuthModeWidget {
 
     fn render_api_key_configured(&self, area: Rect, buf: &mut Buffer) {
         let lines = vec![
-            "✓ API key configured".fg(Color::Green).into(),
+            format!("{} API key configured", crate::glyphs::check())
+                .fg(crate::ui_theme::ui_theme().success)
+                .into(),
             "".into(),
             "  Codex will use usage-based billing with your API key.".into(),
         ];
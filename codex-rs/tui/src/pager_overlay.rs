@@ -20,6 +20,7 @@ use std::sync::Arc;
 
 use crate::chatwidget::ActiveCellTranscriptKey;
 use crate::history_cell::HistoryCell;
+use crate::history_cell::TranscriptCellKind;
 use crate::history_cell::UserHistoryCell;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
@@ -36,6 +37,7 @@ use crate::tui;
 use crate::tui::TuiEvent;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
+use crossterm::event::MouseEvent;
 use ratatui::buffer::Buffer;
 use ratatui::buffer::Cell;
 use ratatui::layout::Rect;
@@ -53,6 +55,7 @@ use ratatui::widgets::Wrap;
 pub(crate) enum Overlay {
     Transcript(TranscriptOverlay),
     Static(StaticOverlay),
+    Diff(DiffOverlay),
 }
 
 impl Overlay {
@@ -76,10 +79,23 @@ impl Overlay {
         Self::Static(StaticOverlay::with_renderables(renderables, title, keymap))
     }
 
+    pub(crate) fn new_diff(
+        changes: std::collections::HashMap<
+            std::path::PathBuf,
+            codex_protocol::protocol::FileChange,
+        >,
+        cwd: codex_utils_absolute_path::AbsolutePathBuf,
+        keymap: PagerKeymap,
+        approval: Option<DiffOverlayApproval>,
+    ) -> Self {
+        Self::Diff(DiffOverlay::new(changes, cwd, keymap, approval))
+    }
+
     pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
         match self {
             Overlay::Transcript(o) => o.handle_event(tui, event),
             Overlay::Static(o) => o.handle_event(tui, event),
+            Overlay::Diff(o) => o.handle_event(tui, event),
         }
     }
 
@@ -87,6 +103,7 @@ impl Overlay {
         match self {
             Overlay::Transcript(o) => o.is_done(),
             Overlay::Static(o) => o.is_done(),
+            Overlay::Diff(o) => o.is_done(),
         }
     }
 }
@@ -116,6 +133,25 @@ fn render_key_hints(area: Rect, buf: &mut Buffer, pairs: &[(Vec<KeyBinding>, &st
     Paragraph::new(vec![Line::from(spans).dim()]).render_ref(area, buf);
 }
 
+/// Incremental `/` search state for a pager view.
+#[derive(Default)]
+struct SearchState {
+    /// Query text (being typed or confirmed).
+    query: String,
+    /// Whether the user is currently typing in the search prompt.
+    entering: bool,
+    /// Indices of renderable chunks whose text contains the query.
+    matches: Vec<usize>,
+    /// Position within `matches` of the focused match.
+    current: usize,
+}
+
+impl SearchState {
+    fn is_active(&self) -> bool {
+        self.entering || !self.query.is_empty()
+    }
+}
+
 /// Generic widget for rendering a pager view.
 struct PagerView {
     renderables: Vec<Box<dyn Renderable>>,
@@ -126,6 +162,16 @@ struct PagerView {
     last_rendered_height: Option<usize>,
     /// If set, on next render ensure this chunk is visible.
     pending_scroll_chunk: Option<usize>,
+    /// Plain-text contents per renderable, used by `/` search. Empty when the
+    /// overlay's content is not searchable.
+    searchable_texts: Vec<String>,
+    search: SearchState,
+    /// In-progress mouse selection, as inclusive start/end buffer positions.
+    selection: Option<((u16, u16), (u16, u16))>,
+    /// When set, the next render extracts the selected text and copies it.
+    copy_selection_on_render: bool,
+    /// Keeps the platform clipboard alive while copied text is in use.
+    clipboard_lease: Option<crate::clipboard_copy::ClipboardLease>,
 }
 
 impl PagerView {
@@ -143,7 +189,203 @@ impl PagerView {
             last_content_height: None,
             last_rendered_height: None,
             pending_scroll_chunk: None,
+            searchable_texts: Vec::new(),
+            search: SearchState::default(),
+            selection: None,
+            copy_selection_on_render: false,
+            clipboard_lease: None,
+        }
+    }
+
+    /// Handle a mouse event: wheel scrolling plus drag selection with
+    /// copy-to-clipboard on release.
+    fn handle_mouse_event(&mut self, tui: &mut tui::Tui, mouse_event: MouseEvent) {
+        use crossterm::event::MouseButton;
+        use crossterm::event::MouseEventKind;
+        const WHEEL_SCROLL_LINES: usize = 3;
+        match mouse_event.kind {
+            MouseEventKind::ScrollUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(WHEEL_SCROLL_LINES);
+            }
+            MouseEventKind::ScrollDown => {
+                self.scroll_offset = self.scroll_offset.saturating_add(WHEEL_SCROLL_LINES);
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let position = (mouse_event.column, mouse_event.row);
+                self.selection = Some((position, position));
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                if let Some((_, end)) = self.selection.as_mut() {
+                    *end = (mouse_event.column, mouse_event.row);
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                if let Some((start, end)) = self.selection {
+                    if start == end {
+                        self.selection = None;
+                    } else {
+                        self.copy_selection_on_render = true;
+                    }
+                }
+            }
+            _ => return,
         }
+        tui.frame_requester()
+            .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+    }
+
+    /// Normalized (top-left, bottom-right) selection positions in reading
+    /// order.
+    fn ordered_selection(&self) -> Option<((u16, u16), (u16, u16))> {
+        let ((start_x, start_y), (end_x, end_y)) = self.selection?;
+        if (start_y, start_x) <= (end_y, end_x) {
+            Some(((start_x, start_y), (end_x, end_y)))
+        } else {
+            Some(((end_x, end_y), (start_x, start_y)))
+        }
+    }
+
+    /// Apply the selection highlight and, when a copy is pending, extract the
+    /// selected text from the rendered buffer and copy it.
+    fn apply_selection(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(((start_x, start_y), (end_x, end_y))) = self.ordered_selection() else {
+            return;
+        };
+        let mut selected_text = String::new();
+        for y in area.y..area.bottom() {
+            if y < start_y || y > end_y {
+                continue;
+            }
+            let from_x = if y == start_y {
+                start_x.max(area.x)
+            } else {
+                area.x
+            };
+            let to_x = if y == end_y {
+                end_x.min(area.right().saturating_sub(1))
+            } else {
+                area.right().saturating_sub(1)
+            };
+            let mut row_text = String::new();
+            for x in from_x..=to_x {
+                if x < area.x || x >= area.right() {
+                    continue;
+                }
+                let cell = &mut buf[(x, y)];
+                row_text.push_str(cell.symbol());
+                let style = cell.style();
+                cell.set_style(style.reversed());
+            }
+            if !selected_text.is_empty() {
+                selected_text.push('\n');
+            }
+            selected_text.push_str(row_text.trim_end());
+        }
+        if self.copy_selection_on_render {
+            self.copy_selection_on_render = false;
+            self.selection = None;
+            match crate::clipboard_copy::copy_to_clipboard(&selected_text) {
+                Ok(lease) => self.clipboard_lease = lease,
+                Err(err) => {
+                    tracing::warn!("failed to copy pager selection: {err}");
+                }
+            }
+        }
+    }
+
+    fn set_searchable_texts(&mut self, texts: Vec<String>) {
+        self.searchable_texts = texts;
+        if !self.search.query.is_empty() {
+            self.recompute_search_matches();
+        }
+    }
+
+    fn recompute_search_matches(&mut self) {
+        let query = self.search.query.to_lowercase();
+        self.search.matches = if query.is_empty() {
+            Vec::new()
+        } else {
+            self.searchable_texts
+                .iter()
+                .enumerate()
+                .filter(|(_, text)| text.to_lowercase().contains(&query))
+                .map(|(index, _)| index)
+                .collect()
+        };
+        self.search.current = 0;
+        if let Some(index) = self.search.matches.first() {
+            self.scroll_chunk_into_view(*index);
+        }
+    }
+
+    fn jump_to_search_match(&mut self, forward: bool) {
+        if self.search.matches.is_empty() {
+            return;
+        }
+        let len = self.search.matches.len();
+        self.search.current = if forward {
+            (self.search.current + 1) % len
+        } else {
+            (self.search.current + len - 1) % len
+        };
+        self.scroll_chunk_into_view(self.search.matches[self.search.current]);
+    }
+
+    /// Handle a key while search is entering or active. Returns true when the
+    /// key was consumed.
+    fn handle_search_key(&mut self, key_event: KeyEvent) -> bool {
+        use crossterm::event::KeyModifiers;
+        if self.search.entering {
+            match key_event.code {
+                KeyCode::Esc => {
+                    self.search = SearchState::default();
+                }
+                KeyCode::Enter => {
+                    self.search.entering = false;
+                }
+                KeyCode::Backspace => {
+                    self.search.query.pop();
+                    self.recompute_search_matches();
+                }
+                KeyCode::Char(ch) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.search.query.push(ch);
+                    self.recompute_search_matches();
+                }
+                _ => {}
+            }
+            return true;
+        }
+        if key_event.code == KeyCode::Char('/')
+            && !key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && !key_event.modifiers.contains(KeyModifiers::ALT)
+        {
+            if self.searchable_texts.is_empty() {
+                return false;
+            }
+            self.search = SearchState {
+                entering: true,
+                ..SearchState::default()
+            };
+            return true;
+        }
+        if !self.search.query.is_empty() {
+            match key_event.code {
+                KeyCode::Char('n') => {
+                    self.jump_to_search_match(/*forward*/ true);
+                    return true;
+                }
+                KeyCode::Char('N') => {
+                    self.jump_to_search_match(/*forward*/ false);
+                    return true;
+                }
+                KeyCode::Esc => {
+                    self.search = SearchState::default();
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        false
     }
 
     fn content_height(&self, width: u16) -> usize {
@@ -170,6 +412,10 @@ impl PagerView {
             .min(content_height.saturating_sub(content_area.height as usize));
 
         self.render_content(content_area, buf);
+        if !self.search.query.is_empty() {
+            highlight_query_matches(content_area, buf, &self.search.query);
+        }
+        self.apply_selection(content_area, buf);
 
         self.render_bottom_bar(area, content_area, buf, content_height);
     }
@@ -178,7 +424,21 @@ impl PagerView {
         Span::from("/ ".repeat(area.width as usize / 2))
             .dim()
             .render_ref(area, buf);
-        let header = format!("/ {}", self.title);
+        let mut header = format!("/ {}", self.title);
+        if self.search.is_active() {
+            let query = &self.search.query;
+            if self.search.entering {
+                header.push_str(&format!("  /{query}\u{258c}"));
+            } else if self.search.matches.is_empty() {
+                header.push_str(&format!("  /{query} (no matches)"));
+            } else {
+                header.push_str(&format!(
+                    "  /{query} ({}/{})",
+                    self.search.current + 1,
+                    self.search.matches.len()
+                ));
+            }
+        }
         header.dim().render_ref(area, buf);
     }
 
@@ -251,6 +511,11 @@ impl PagerView {
     }
 
     fn handle_key_event(&mut self, tui: &mut tui::Tui, key_event: KeyEvent) -> Result<()> {
+        if self.handle_search_key(key_event) {
+            tui.frame_requester()
+                .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+            return Ok(());
+        }
         match key_event {
             e if self.keymap.scroll_up.is_pressed(e) => {
                 self.scroll_offset = self.scroll_offset.saturating_sub(1);
@@ -360,6 +625,218 @@ impl PagerView {
     }
 }
 
+/// A pending patch approval that the diff overlay can resolve directly.
+pub(crate) struct DiffOverlayApproval {
+    pub app_event_tx: crate::app_event_sender::AppEventSender,
+    pub thread_id: codex_protocol::ThreadId,
+    pub item_id: String,
+}
+
+/// Full-screen viewer for proposed patches: one navigable chunk per file with
+/// the usual red/green hunk coloring, plus accept/reject keys while an
+/// approval is pending.
+pub(crate) struct DiffOverlay {
+    view: PagerView,
+    is_done: bool,
+    file_count: usize,
+    current_file: usize,
+    approval: Option<DiffOverlayApproval>,
+}
+
+impl DiffOverlay {
+    pub(crate) fn new(
+        changes: std::collections::HashMap<
+            std::path::PathBuf,
+            codex_protocol::protocol::FileChange,
+        >,
+        cwd: codex_utils_absolute_path::AbsolutePathBuf,
+        keymap: PagerKeymap,
+        approval: Option<DiffOverlayApproval>,
+    ) -> Self {
+        let mut files: Vec<_> = changes.into_iter().collect();
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let file_count = files.len();
+        let renderables: Vec<Box<dyn Renderable>> = files
+            .into_iter()
+            .map(|(path, change)| {
+                let summary = crate::diff_render::DiffSummary::new(
+                    std::collections::HashMap::from([(path, change)]),
+                    cwd.clone(),
+                );
+                let renderable: Box<dyn Renderable> = summary.into();
+                Box::new(InsetRenderable::new(
+                    renderable,
+                    Insets::tlbr(
+                        /*top*/ 1, /*left*/ 0, /*bottom*/ 0, /*right*/ 0,
+                    ),
+                )) as Box<dyn Renderable>
+            })
+            .collect();
+        Self {
+            view: PagerView::new(renderables, "D I F F".to_string(), 0, keymap),
+            is_done: false,
+            file_count,
+            current_file: 0,
+            approval,
+        }
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        self.is_done
+    }
+
+    fn jump_to_file(&mut self, forward: bool) {
+        if self.file_count == 0 {
+            return;
+        }
+        self.current_file = if forward {
+            (self.current_file + 1) % self.file_count
+        } else {
+            (self.current_file + self.file_count - 1) % self.file_count
+        };
+        self.view.scroll_chunk_into_view(self.current_file);
+    }
+
+    fn resolve_approval(&mut self, accept: bool) {
+        if let Some(approval) = self.approval.take() {
+            let decision = if accept {
+                codex_app_server_protocol::FileChangeApprovalDecision::Accept
+            } else {
+                codex_app_server_protocol::FileChangeApprovalDecision::Decline
+            };
+            approval
+                .app_event_tx
+                .patch_approval(approval.thread_id, approval.item_id, decision);
+            self.is_done = true;
+        }
+    }
+
+    fn render_hints(&self, area: Rect, buf: &mut Buffer) {
+        let line1 = Rect::new(area.x, area.y, area.width, 1);
+        let line2 = Rect::new(area.x, area.y.saturating_add(1), area.width, 1);
+        render_key_hints(
+            line1,
+            buf,
+            &[
+                (
+                    first_or_empty(&self.view.keymap.scroll_up)
+                        .into_iter()
+                        .chain(first_or_empty(&self.view.keymap.scroll_down))
+                        .collect(),
+                    "to scroll",
+                ),
+                (
+                    vec![
+                        key_hint::plain(KeyCode::Char('[')),
+                        key_hint::plain(KeyCode::Char(']')),
+                    ],
+                    "to switch file",
+                ),
+                (vec![key_hint::plain(KeyCode::Char('/'))], "to search"),
+            ],
+        );
+        let mut pairs: Vec<(Vec<KeyBinding>, &str)> =
+            vec![(first_or_empty(&self.view.keymap.close), "to quit")];
+        if self.approval.is_some() {
+            pairs.push((vec![key_hint::plain(KeyCode::Char('y'))], "to approve"));
+            pairs.push((vec![key_hint::plain(KeyCode::Char('n'))], "to reject"));
+        }
+        render_key_hints(line2, buf, &pairs);
+    }
+
+    pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let top_h = area.height.saturating_sub(3);
+        let top = Rect::new(area.x, area.y, area.width, top_h);
+        let bottom = Rect::new(area.x, area.y + top_h, area.width, 3);
+        self.view.render(top, buf);
+        self.render_hints(bottom, buf);
+    }
+
+    pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
+        match event {
+            TuiEvent::Key(key_event) => match key_event {
+                e if self.view.keymap.close.is_pressed(e) => {
+                    self.is_done = true;
+                    Ok(())
+                }
+                e if !self.view.search.is_active() && e.code == KeyCode::Char('[') => {
+                    self.jump_to_file(/*forward*/ false);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if !self.view.search.is_active() && e.code == KeyCode::Char(']') => {
+                    self.jump_to_file(/*forward*/ true);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if self.approval.is_some()
+                    && !self.view.search.is_active()
+                    && e.code == KeyCode::Char('y') =>
+                {
+                    self.resolve_approval(/*accept*/ true);
+                    Ok(())
+                }
+                e if self.approval.is_some()
+                    && !self.view.search.is_active()
+                    && e.code == KeyCode::Char('n') =>
+                {
+                    self.resolve_approval(/*accept*/ false);
+                    Ok(())
+                }
+                other => self.view.handle_key_event(tui, other),
+            },
+            TuiEvent::Mouse(mouse_event) => {
+                self.view.handle_mouse_event(tui, mouse_event);
+                Ok(())
+            }
+            TuiEvent::Draw | TuiEvent::Resize => {
+                tui.draw(u16::MAX, |frame| {
+                    self.render(frame.area(), frame.buffer);
+                })?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Reverse the style of on-screen text matching `query` (case-insensitive).
+///
+/// Matching happens on the rendered buffer, so wrapped lines highlight
+/// correctly without the pager knowing how each renderable laid out its text.
+fn highlight_query_matches(area: Rect, buf: &mut Buffer, query: &str) {
+    let query = query.to_lowercase();
+    if query.is_empty() {
+        return;
+    }
+    for y in area.y..area.bottom() {
+        // Build the row text with a byte-offset -> column mapping so styles
+        // land on the right cells even with multi-byte symbols.
+        let mut row_text = String::new();
+        let mut cell_offsets: Vec<(usize, u16)> = Vec::new();
+        for x in area.x..area.right() {
+            let symbol = buf[(x, y)].symbol().to_lowercase();
+            cell_offsets.push((row_text.len(), x));
+            row_text.push_str(&symbol);
+        }
+        let mut start = 0;
+        while let Some(found) = row_text[start..].find(&query) {
+            let begin = start + found;
+            let end = begin + query.len();
+            for (offset, x) in &cell_offsets {
+                if *offset >= begin && *offset < end {
+                    let cell = &mut buf[(*x, y)];
+                    let style = cell.style();
+                    cell.set_style(style.reversed());
+                }
+            }
+            start = end;
+        }
+    }
+}
+
 /// A renderable that caches its desired height.
 struct CachedRenderable {
     renderable: Box<dyn Renderable>,
@@ -432,6 +909,54 @@ impl Renderable for HyperlinkLinesRenderable {
     }
 }
 
+/// Event-type filter for the transcript overlay, cycled with `f`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum TranscriptFilter {
+    #[default]
+    All,
+    /// Only user and assistant messages.
+    MessagesOnly,
+    /// Everything except reasoning.
+    HideReasoning,
+    /// Everything except commands and tool output.
+    HideCommands,
+}
+
+impl TranscriptFilter {
+    fn next(self) -> Self {
+        match self {
+            Self::All => Self::MessagesOnly,
+            Self::MessagesOnly => Self::HideReasoning,
+            Self::HideReasoning => Self::HideCommands,
+            Self::HideCommands => Self::All,
+        }
+    }
+
+    fn shows(self, kind: TranscriptCellKind) -> bool {
+        match self {
+            Self::All => true,
+            Self::MessagesOnly => matches!(
+                kind,
+                TranscriptCellKind::UserMessage | TranscriptCellKind::AgentMessage
+            ),
+            Self::HideReasoning => kind != TranscriptCellKind::Reasoning,
+            Self::HideCommands => !matches!(
+                kind,
+                TranscriptCellKind::Command | TranscriptCellKind::ToolOutput
+            ),
+        }
+    }
+
+    fn label(self) -> Option<&'static str> {
+        match self {
+            Self::All => None,
+            Self::MessagesOnly => Some("messages only"),
+            Self::HideReasoning => Some("reasoning hidden"),
+            Self::HideCommands => Some("commands hidden"),
+        }
+    }
+}
+
 pub(crate) struct TranscriptOverlay {
     /// Pager UI state and the renderables currently displayed.
     ///
@@ -443,6 +968,10 @@ pub(crate) struct TranscriptOverlay {
     highlight_cell: Option<usize>,
     /// Cache key for the render-only live tail appended after committed cells.
     live_tail_key: Option<LiveTailKey>,
+    /// Active event-type filter applied when rebuilding renderables.
+    filter: TranscriptFilter,
+    /// Cell index of the last user message jumped to with `[`/`]`.
+    nav_user_cursor: Option<usize>,
     is_done: bool,
 }
 
@@ -467,20 +996,44 @@ impl TranscriptOverlay {
     /// This overlay does not own the "active cell"; callers may optionally append a live tail via
     /// `sync_live_tail` during draws to reflect in-flight activity.
     pub(crate) fn new(transcript_cells: Vec<Arc<dyn HistoryCell>>, keymap: PagerKeymap) -> Self {
+        let mut view = PagerView::new(
+            Self::render_cells(&transcript_cells, /*highlight_cell*/ None),
+            "T R A N S C R I P T".to_string(),
+            usize::MAX,
+            keymap,
+        );
+        view.set_searchable_texts(Self::searchable_texts(&transcript_cells));
         Self {
-            view: PagerView::new(
-                Self::render_cells(&transcript_cells, /*highlight_cell*/ None),
-                "T R A N S C R I P T".to_string(),
-                usize::MAX,
-                keymap,
-            ),
+            view,
             cells: transcript_cells,
             highlight_cell: None,
             live_tail_key: None,
+            filter: TranscriptFilter::default(),
+            nav_user_cursor: None,
             is_done: false,
         }
     }
 
+    /// Plain-text contents per committed cell, aligned with the renderables
+    /// produced by [`Self::render_cells`], for `/` search.
+    fn searchable_texts(cells: &[Arc<dyn HistoryCell>]) -> Vec<String> {
+        cells
+            .iter()
+            .map(|cell| {
+                cell.transcript_lines(u16::MAX)
+                    .iter()
+                    .map(|line| {
+                        line.spans
+                            .iter()
+                            .map(|span| span.content.as_ref())
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect()
+    }
+
     fn render_cells(
         cells: &[Arc<dyn HistoryCell>],
         highlight_cell: Option<usize>,
@@ -534,7 +1087,11 @@ impl TranscriptOverlay {
         let had_prior_cells = !self.cells.is_empty();
         let tail_renderable = self.take_live_tail_renderable();
         self.cells.push(cell);
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        let visible = self.visible_cells();
+        self.view.renderables =
+            Self::render_cells(&visible, self.highlight_for_visible_cells(&visible));
+        self.view
+            .set_searchable_texts(Self::searchable_texts(&visible));
         if let Some(tail) = tail_renderable {
             let tail = if !had_prior_cells
                 && self
@@ -688,7 +1245,11 @@ impl TranscriptOverlay {
 
     fn rebuild_renderables(&mut self) {
         let tail_renderable = self.take_live_tail_renderable();
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        let visible = self.visible_cells();
+        self.view.renderables =
+            Self::render_cells(&visible, self.highlight_for_visible_cells(&visible));
+        self.view
+            .set_searchable_texts(Self::searchable_texts(&visible));
         if let Some(tail) = tail_renderable {
             self.view.renderables.push(tail);
         }
@@ -749,6 +1310,15 @@ impl TranscriptOverlay {
                         .collect(),
                     "to jump",
                 ),
+                (vec![key_hint::plain(KeyCode::Char('/'))], "to search"),
+                (vec![key_hint::plain(KeyCode::Char('f'))], "to filter"),
+                (
+                    vec![
+                        key_hint::plain(KeyCode::Char('[')),
+                        key_hint::plain(KeyCode::Char(']')),
+                    ],
+                    "to jump messages",
+                ),
             ],
         );
 
@@ -789,8 +1359,36 @@ impl TranscriptOverlay {
                     self.is_done = true;
                     Ok(())
                 }
+                e if !self.view.search.is_active()
+                    && e.code == KeyCode::Char('f')
+                    && e.modifiers.is_empty() =>
+                {
+                    self.cycle_filter();
+                    tui.frame_requester().schedule_frame();
+                    Ok(())
+                }
+                e if !self.view.search.is_active()
+                    && e.code == KeyCode::Char('[')
+                    && e.modifiers.is_empty() =>
+                {
+                    self.jump_user_message(/*towards_start*/ true);
+                    tui.frame_requester().schedule_frame();
+                    Ok(())
+                }
+                e if !self.view.search.is_active()
+                    && e.code == KeyCode::Char(']')
+                    && e.modifiers.is_empty() =>
+                {
+                    self.jump_user_message(/*towards_start*/ false);
+                    tui.frame_requester().schedule_frame();
+                    Ok(())
+                }
                 other => self.view.handle_key_event(tui, other),
             },
+            TuiEvent::Mouse(mouse_event) => {
+                self.view.handle_mouse_event(tui, mouse_event);
+                Ok(())
+            }
             TuiEvent::Draw | TuiEvent::Resize => {
                 tui.draw(u16::MAX, |frame| {
                     self.render(frame.area(), frame.buffer);
@@ -804,6 +1402,70 @@ impl TranscriptOverlay {
         self.is_done
     }
 
+    /// Committed cells passing the active event-type filter.
+    fn visible_cells(&self) -> Vec<Arc<dyn HistoryCell>> {
+        self.cells
+            .iter()
+            .filter(|cell| self.filter.shows(cell.transcript_kind()))
+            .cloned()
+            .collect()
+    }
+
+    /// Maps the highlight index (over all committed cells) into the filtered
+    /// view; hidden highlights drop the marker rather than mis-highlighting.
+    fn highlight_for_visible_cells(&self, visible: &[Arc<dyn HistoryCell>]) -> Option<usize> {
+        let highlight = self.highlight_cell?;
+        if self.filter == TranscriptFilter::All {
+            return Some(highlight);
+        }
+        let target = self.cells.get(highlight)?;
+        visible.iter().position(|cell| Arc::ptr_eq(cell, target))
+    }
+
+    /// Moves the viewport to the previous/next user message boundary,
+    /// reusing the backtrack helper that locates user messages in the
+    /// committed transcript.
+    fn jump_user_message(&mut self, towards_start: bool) {
+        let positions: Vec<usize> =
+            crate::app_backtrack::user_positions_iter(&self.cells).collect();
+        let next = match (self.nav_user_cursor, towards_start) {
+            (None, true) => positions.last().copied(),
+            (None, false) => positions.first().copied(),
+            (Some(current), true) => positions
+                .iter()
+                .rev()
+                .find(|&&idx| idx < current)
+                .or(positions.first())
+                .copied(),
+            (Some(current), false) => positions
+                .iter()
+                .find(|&&idx| idx > current)
+                .or(positions.last())
+                .copied(),
+        };
+        let Some(next) = next else {
+            return;
+        };
+        self.nav_user_cursor = Some(next);
+        let Some(target) = self.cells.get(next) else {
+            return;
+        };
+        let visible = self.visible_cells();
+        if let Some(visible_idx) = visible.iter().position(|cell| Arc::ptr_eq(cell, target)) {
+            self.view.scroll_chunk_into_view(visible_idx);
+        }
+    }
+
+    fn cycle_filter(&mut self) {
+        self.filter = self.filter.next();
+        self.view.title = match self.filter.label() {
+            Some(label) => format!("T R A N S C R I P T  ({label})"),
+            None => "T R A N S C R I P T".to_string(),
+        };
+        self.rebuild_renderables();
+        self.view.scroll_offset = usize::MAX;
+    }
+
     #[cfg(test)]
     pub(crate) fn committed_cell_count(&self) -> usize {
         self.cells.len()
@@ -821,12 +1483,24 @@ impl StaticOverlay {
         title: String,
         keymap: PagerKeymap,
     ) -> Self {
+        let searchable_text = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
         let paragraph = Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false });
-        Self::with_renderables(
+        let mut overlay = Self::with_renderables(
             vec![Box::new(CachedRenderable::new(paragraph))],
             title,
             keymap,
-        )
+        );
+        overlay.view.set_searchable_texts(vec![searchable_text]);
+        overlay
     }
 
     pub(crate) fn with_renderables(
@@ -843,33 +1517,35 @@ impl StaticOverlay {
     fn render_hints(&self, area: Rect, buf: &mut Buffer) {
         let line1 = Rect::new(area.x, area.y, area.width, 1);
         let line2 = Rect::new(area.x, area.y.saturating_add(1), area.width, 1);
-        render_key_hints(
-            line1,
-            buf,
-            &[
-                (
-                    first_or_empty(&self.view.keymap.scroll_up)
-                        .into_iter()
-                        .chain(first_or_empty(&self.view.keymap.scroll_down))
-                        .collect(),
-                    "to scroll",
-                ),
-                (
-                    first_or_empty(&self.view.keymap.page_up)
-                        .into_iter()
-                        .chain(first_or_empty(&self.view.keymap.page_down))
-                        .collect(),
-                    "to page",
-                ),
-                (
-                    first_or_empty(&self.view.keymap.jump_top)
-                        .into_iter()
-                        .chain(first_or_empty(&self.view.keymap.jump_bottom))
-                        .collect(),
-                    "to jump",
-                ),
-            ],
-        );
+        let mut pairs: Vec<(Vec<KeyBinding>, &str)> = vec![
+            (
+                first_or_empty(&self.view.keymap.scroll_up)
+                    .into_iter()
+                    .chain(first_or_empty(&self.view.keymap.scroll_down))
+                    .collect(),
+                "to scroll",
+            ),
+            (
+                first_or_empty(&self.view.keymap.page_up)
+                    .into_iter()
+                    .chain(first_or_empty(&self.view.keymap.page_down))
+                    .collect(),
+                "to page",
+            ),
+            (
+                first_or_empty(&self.view.keymap.jump_top)
+                    .into_iter()
+                    .chain(first_or_empty(&self.view.keymap.jump_bottom))
+                    .collect(),
+                "to jump",
+            ),
+        ];
+        // Overlays built from raw renderables have no searchable text, so
+        // `/` would be a no-op; only advertise it when search can run.
+        if !self.view.searchable_texts.is_empty() {
+            pairs.push((vec![key_hint::plain(KeyCode::Char('/'))], "to search"));
+        }
+        render_key_hints(line1, buf, &pairs);
         let pairs: Vec<(Vec<KeyBinding>, &str)> =
             vec![(first_or_empty(&self.view.keymap.close), "to quit")];
         render_key_hints(line2, buf, &pairs);
@@ -894,6 +1570,10 @@ impl StaticOverlay {
                 }
                 other => self.view.handle_key_event(tui, other),
             },
+            TuiEvent::Mouse(mouse_event) => {
+                self.view.handle_mouse_event(tui, mouse_event);
+                Ok(())
+            }
             TuiEvent::Draw | TuiEvent::Resize => {
                 tui.draw(u16::MAX, |frame| {
                     self.render(frame.area(), frame.buffer);
@@ -38,7 +38,9 @@ pub enum SlashCommand {
     App,
     Init,
     Compact,
+    Undo,
     Plan,
+    Role,
     Goal,
     Agent,
     Side,
@@ -118,7 +120,9 @@ impl SlashCommand {
                 "include current selection, open files, and other context from your IDE"
             }
             SlashCommand::Personality => "choose a communication style for Codex",
+            SlashCommand::Undo => "undo the last turn, dropping your last message",
             SlashCommand::Plan => "switch to Plan mode",
+            SlashCommand::Role => "apply a role preset for new threads: /role <name>",
             SlashCommand::Goal => "set or view the goal for a long-running task",
             SlashCommand::Agent | SlashCommand::MultiAgents => "switch the active agent thread",
             SlashCommand::Side | SlashCommand::Btw => {
@@ -156,6 +160,7 @@ impl SlashCommand {
             SlashCommand::Review
                 | SlashCommand::Rename
                 | SlashCommand::Plan
+                | SlashCommand::Role
                 | SlashCommand::Goal
                 | SlashCommand::Ide
                 | SlashCommand::Keymap
@@ -202,6 +207,8 @@ impl SlashCommand {
             | SlashCommand::Import
             | SlashCommand::Review
             | SlashCommand::Plan
+            | SlashCommand::Role
+            | SlashCommand::Undo
             | SlashCommand::Clear
             | SlashCommand::Logout
             | SlashCommand::MemoryDrop
@@ -520,6 +520,9 @@ pub enum TuiEvent {
     /// Resize is separate from `Draw` so the app can run feature-gated pre-render logic without
     /// changing the default draw path for scheduled frames.
     Resize,
+    /// A terminal mouse event. Only delivered while an alt-screen overlay has
+    /// enabled mouse capture.
+    Mouse(crossterm::event::MouseEvent),
     /// A scheduled repaint that does not necessarily correspond to a terminal size change.
     Draw,
 }
@@ -740,6 +743,12 @@ impl Tui {
         let _ = execute!(self.terminal.backend_mut(), EnterAlternateScreen);
         // Enable "alternate scroll" so terminals may translate wheel to arrows
         let _ = execute!(self.terminal.backend_mut(), EnableAlternateScroll);
+        // Capture mouse events so overlays get real wheel/drag events; native
+        // terminal selection remains available in the inline (non-alt) view.
+        let _ = execute!(
+            self.terminal.backend_mut(),
+            crossterm::event::EnableMouseCapture
+        );
         if let Ok(size) = self.terminal.size() {
             self.alt_saved_viewport = Some(self.terminal.viewport_area);
             self.terminal.set_viewport_area(ratatui::layout::Rect::new(
@@ -759,6 +768,10 @@ impl Tui {
         if !self.alt_screen_enabled {
             return Ok(());
         }
+        let _ = execute!(
+            self.terminal.backend_mut(),
+            crossterm::event::DisableMouseCapture
+        );
         // Disable alternate scroll when leaving alt-screen
         let _ = execute!(self.terminal.backend_mut(), DisableAlternateScroll);
         let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen);
@@ -0,0 +1,157 @@
+//! UI chrome colors resolved from `tui.theme_overrides`.
+//!
+//! Widgets ask for semantic colors (`accent`, `success`, `warning`, `error`)
+//! instead of hardcoding `Color::Cyan` and friends, so presets and
+//! per-element overrides apply consistently. Only ANSI color names are
+//! accepted; RGB is rejected to keep output legible across terminal palettes
+//! (see `tui/styles.md`).
+
+use std::sync::OnceLock;
+
+use codex_config::types::TuiColorPreset;
+use codex_config::types::TuiThemeOverrides;
+use ratatui::style::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct UiTheme {
+    pub accent: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+}
+
+impl UiTheme {
+    const fn preset(preset: TuiColorPreset) -> Self {
+        match preset {
+            TuiColorPreset::Default => Self {
+                accent: Color::Cyan,
+                success: Color::Green,
+                warning: Color::Yellow,
+                error: Color::Red,
+            },
+            // Non-bright variants read better on light backgrounds.
+            TuiColorPreset::Light => Self {
+                accent: Color::Blue,
+                success: Color::Green,
+                warning: Color::Magenta,
+                error: Color::Red,
+            },
+            TuiColorPreset::HighContrast => Self {
+                accent: Color::LightCyan,
+                success: Color::LightGreen,
+                warning: Color::LightYellow,
+                error: Color::LightRed,
+            },
+            // Blue/yellow/magenta stay distinguishable for the common
+            // red-green color vision deficiencies.
+            TuiColorPreset::Colorblind => Self {
+                accent: Color::Cyan,
+                success: Color::Blue,
+                warning: Color::Yellow,
+                error: Color::Magenta,
+            },
+        }
+    }
+}
+
+static UI_THEME: OnceLock<UiTheme> = OnceLock::new();
+
+/// Resolve and install the UI theme from config. Later calls win only if no
+/// theme was installed yet; widgets fall back to the default preset.
+pub(crate) fn init_ui_theme(overrides: Option<&TuiThemeOverrides>) {
+    let theme = resolve_ui_theme(overrides);
+    let _ = UI_THEME.set(theme);
+}
+
+/// [`init_ui_theme`], but the top-level `colorblind_output` flag selects the
+/// colorblind preset when no explicit theme overrides are configured.
+pub(crate) fn init_ui_theme_with_colorblind_default(
+    overrides: Option<&TuiThemeOverrides>,
+    colorblind_output: bool,
+) {
+    if overrides.is_none() && colorblind_output {
+        let _ = UI_THEME.set(UiTheme::preset(TuiColorPreset::Colorblind));
+        return;
+    }
+    init_ui_theme(overrides);
+}
+
+pub(crate) fn ui_theme() -> UiTheme {
+    *UI_THEME.get_or_init(|| UiTheme::preset(TuiColorPreset::Default))
+}
+
+fn resolve_ui_theme(overrides: Option<&TuiThemeOverrides>) -> UiTheme {
+    let Some(overrides) = overrides else {
+        return UiTheme::preset(TuiColorPreset::Default);
+    };
+    let mut theme = UiTheme::preset(overrides.preset);
+    apply_override(&mut theme.accent, overrides.accent.as_deref());
+    apply_override(&mut theme.success, overrides.success.as_deref());
+    apply_override(&mut theme.warning, overrides.warning.as_deref());
+    apply_override(&mut theme.error, overrides.error.as_deref());
+    theme
+}
+
+fn apply_override(slot: &mut Color, name: Option<&str>) {
+    if let Some(name) = name {
+        match parse_ansi_color(name) {
+            Some(color) => *slot = color,
+            None => {
+                tracing::warn!("ignoring unknown ANSI color name `{name}` in tui.theme_overrides");
+            }
+        }
+    }
+}
+
+/// Parse an ANSI color name (kebab- or snake-case). RGB is deliberately
+/// unsupported.
+fn parse_ansi_color(name: &str) -> Option<Color> {
+    match name.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "gray" | "grey" | "white" => Some(Color::Gray),
+        "dark-gray" | "dark-grey" => Some(Color::DarkGray),
+        "bright-red" | "light-red" => Some(Color::LightRed),
+        "bright-green" | "light-green" => Some(Color::LightGreen),
+        "bright-yellow" | "light-yellow" => Some(Color::LightYellow),
+        "bright-blue" | "light-blue" => Some(Color::LightBlue),
+        "bright-magenta" | "light-magenta" => Some(Color::LightMagenta),
+        "bright-cyan" | "light-cyan" => Some(Color::LightCyan),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn overrides_apply_on_top_of_preset() {
+        let theme = resolve_ui_theme(Some(&TuiThemeOverrides {
+            preset: TuiColorPreset::HighContrast,
+            accent: Some("magenta".to_string()),
+            success: None,
+            warning: Some("not-a-color".to_string()),
+            error: None,
+        }));
+
+        assert_eq!(theme.accent, Color::Magenta);
+        assert_eq!(theme.success, Color::LightGreen);
+        // Unknown names are ignored rather than failing startup.
+        assert_eq!(theme.warning, Color::LightYellow);
+    }
+
+    #[test]
+    fn no_overrides_yield_the_default_preset() {
+        assert_eq!(
+            resolve_ui_theme(None),
+            UiTheme::preset(TuiColorPreset::Default)
+        );
+    }
+}
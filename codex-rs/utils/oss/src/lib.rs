@@ -1,8 +1,10 @@
 //! OSS provider utilities shared between TUI and exec.
 
 use codex_core::config::Config;
+use codex_model_provider_info::LLAMACPP_OSS_PROVIDER_ID;
 use codex_model_provider_info::LMSTUDIO_OSS_PROVIDER_ID;
 use codex_model_provider_info::OLLAMA_OSS_PROVIDER_ID;
+use codex_model_provider_info::VLLM_OSS_PROVIDER_ID;
 
 /// Returns the default model for a given OSS provider.
 pub fn get_default_model_for_oss_provider(provider_id: &str) -> Option<&'static str> {
@@ -30,6 +32,11 @@ pub async fn ensure_oss_provider_ready(
                 .await
                 .map_err(|e| std::io::Error::other(format!("OSS setup failed: {e}")))?;
         }
+        LLAMACPP_OSS_PROVIDER_ID | VLLM_OSS_PROVIDER_ID => {
+            // These servers are user-managed; probe the endpoint instead of
+            // attempting to download models.
+            probe_openai_compatible_endpoint(provider_id, &config.model_provider).await?;
+        }
         _ => {
             // Unknown provider, skip setup
         }
@@ -37,6 +44,36 @@ pub async fn ensure_oss_provider_ready(
     Ok(())
 }
 
+/// Verify an OpenAI-compatible local server is reachable by listing its
+/// models, so startup fails with a clear message instead of a mid-turn error.
+async fn probe_openai_compatible_endpoint(
+    provider_id: &str,
+    provider: &codex_model_provider_info::ModelProviderInfo,
+) -> Result<(), std::io::Error> {
+    let Some(base_url) = provider.base_url.as_deref() else {
+        return Err(std::io::Error::other(format!(
+            "`{provider_id}` provider has no base_url configured"
+        )));
+    };
+    let models_url = format!("{}/models", base_url.trim_end_matches('/'));
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .map_err(std::io::Error::other)?;
+    let response = client.get(&models_url).send().await.map_err(|err| {
+        std::io::Error::other(format!(
+            "`{provider_id}` server is not reachable at {models_url}: {err}. Start it and retry, or pass a different --local-provider."
+        ))
+    })?;
+    if !response.status().is_success() {
+        return Err(std::io::Error::other(format!(
+            "`{provider_id}` server at {models_url} responded with {}",
+            response.status()
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
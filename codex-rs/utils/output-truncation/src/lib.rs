@@ -29,6 +29,160 @@ pub fn truncate_text(content: &str, policy: TruncationPolicy) -> String {
     }
 }
 
+/// Share of the byte budget reserved for error lines salvaged from the cut
+/// middle of exec output.
+const SALVAGE_BUDGET_SHARE: usize = 4;
+/// Cap on the bytes of any single salvaged error line.
+const MAX_SALVAGED_LINE_BYTES: usize = 512;
+
+fn is_error_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("error:")
+        || trimmed.starts_with("error[")
+        || trimmed.starts_with("ERROR")
+        || trimmed.starts_with("fatal:")
+        || trimmed.starts_with("FAILED")
+        || trimmed.contains("panicked at")
+        || trimmed.contains("assertion failed")
+        || trimmed.contains("] FAILED")
+        || trimmed.ends_with("... FAILED")
+        || trimmed.starts_with("Traceback (most recent call last)")
+}
+
+fn is_warning_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("warning:")
+        || trimmed.starts_with("warning[")
+        || trimmed.starts_with("WARN")
+}
+
+/// Greedily keeps whole lines from the head and tail, half of `keep_budget`
+/// each, and returns the `(head_end, tail_start)` line indices of the cut.
+fn select_head_tail(lines: &[&str], keep_budget: usize) -> (usize, usize) {
+    let mut head_end = 0usize;
+    let mut head_bytes = 0usize;
+    for line in lines {
+        let cost = line.len() + 1;
+        if head_bytes + cost > keep_budget / 2 {
+            break;
+        }
+        head_bytes += cost;
+        head_end += 1;
+    }
+    let mut tail_start = lines.len();
+    let mut tail_bytes = 0usize;
+    for line in lines.iter().rev() {
+        let cost = line.len() + 1;
+        if tail_bytes + cost > keep_budget - keep_budget / 2 || tail_start == head_end {
+            break;
+        }
+        tail_bytes += cost;
+        tail_start -= 1;
+    }
+    (head_end, tail_start)
+}
+
+/// Line-aware variant of [`truncate_text`] for exec output: the head and tail
+/// of the output are kept as usual, but error lines from the cut middle
+/// (compiler errors, panics, test failures) are salvaged into the output after
+/// the truncation marker, and the marker reports how many error/warning lines
+/// the cut suppressed — so a noisy build doesn't swallow the actual failure.
+pub fn truncate_exec_text(content: &str, policy: TruncationPolicy) -> String {
+    let max_bytes = policy.byte_budget();
+    if content.len() <= max_bytes {
+        return content.to_string();
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+
+    let (mut head_end, mut tail_start) = select_head_tail(&lines, max_bytes);
+    if head_end == 0 || tail_start == lines.len() || head_end >= tail_start {
+        // A single line blew the head or tail budget (or the ranges overlap):
+        // line-granular truncation would drop whole sides of the output, so
+        // fall back to byte-exact middle truncation.
+        return truncate_text(content, policy);
+    }
+
+    // Only carve out the salvage reserve when the cut middle actually
+    // contains error lines; otherwise the full budget goes to head and tail.
+    let mut salvage_budget = 0usize;
+    if lines[head_end..tail_start].iter().any(|l| is_error_line(l)) {
+        let reserve = max_bytes / SALVAGE_BUDGET_SHARE;
+        let (h, t) = select_head_tail(&lines, max_bytes - reserve);
+        if h > 0 && t < lines.len() && h < t {
+            head_end = h;
+            tail_start = t;
+            salvage_budget = reserve;
+        }
+    }
+
+    let middle = &lines[head_end..tail_start];
+    let mut salvaged: Vec<&str> = Vec::new();
+    let mut salvaged_bytes = 0usize;
+    let mut suppressed_errors = 0usize;
+    let mut suppressed_warnings = 0usize;
+    let mut cut_bytes = 0usize;
+    let mut cut_chars = 0usize;
+    for line in middle {
+        if is_error_line(line) {
+            let cost = line.len() + 1;
+            if cost <= MAX_SALVAGED_LINE_BYTES && salvaged_bytes + cost <= salvage_budget {
+                salvaged.push(line);
+                salvaged_bytes += cost;
+                continue;
+            }
+            suppressed_errors += 1;
+        } else if is_warning_line(line) {
+            suppressed_warnings += 1;
+        }
+        cut_bytes += line.len() + 1;
+        cut_chars += line.chars().count() + 1;
+    }
+
+    let cut = match policy {
+        TruncationPolicy::Bytes(_) => format!("{cut_chars} chars truncated"),
+        TruncationPolicy::Tokens(_) => {
+            format!(
+                "{} tokens truncated",
+                approx_tokens_from_byte_count(cut_bytes)
+            )
+        }
+    };
+    let mut marker = format!("…{cut}");
+    if suppressed_errors > 0 || suppressed_warnings > 0 {
+        marker.push_str(&format!(
+            " ({suppressed_errors} error, {suppressed_warnings} warning lines suppressed)"
+        ));
+    }
+    marker.push('…');
+
+    let mut out: Vec<&str> = Vec::with_capacity(head_end + salvaged.len() + lines.len() + 1);
+    out.extend(&lines[..head_end]);
+    out.push(&marker);
+    out.extend(&salvaged);
+    out.extend(&lines[tail_start..]);
+    let mut result = out.join("\n");
+    if content.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// [`formatted_truncate_text`], but using [`truncate_exec_text`] so error
+/// lines survive the cut.
+pub fn formatted_truncate_exec_text(content: &str, policy: TruncationPolicy) -> String {
+    if content.len() <= policy.byte_budget() {
+        return content.to_string();
+    }
+
+    let original_token_count = approx_token_count(content);
+    let total_lines = content.lines().count();
+    let result = truncate_exec_text(content, policy);
+    format!(
+        "Warning: truncated output (original token count: {original_token_count})\nTotal output lines: {total_lines}\n\n{result}"
+    )
+}
+
 pub fn formatted_truncate_text_content_items_with_policy(
     items: &[FunctionCallOutputContentItem],
     policy: TruncationPolicy,
@@ -3,6 +3,7 @@ use crate::approx_token_count;
 use crate::approx_tokens_from_byte_count_i64;
 use crate::formatted_truncate_text;
 use crate::formatted_truncate_text_content_items_with_policy;
+use crate::truncate_exec_text;
 use crate::truncate_function_output_items_with_policy;
 use crate::truncate_text;
 use codex_protocol::models::DEFAULT_IMAGE_DETAIL;
@@ -328,6 +329,58 @@ fn formatted_truncate_text_content_items_with_policy_merges_all_text_for_token_b
     assert_eq!(original_token_count, Some(5));
 }
 
+#[test]
+fn truncate_exec_text_salvages_error_lines_from_cut_middle() {
+    let mut lines: Vec<String> = (0..40).map(|i| format!("building object {i}")).collect();
+    lines.insert(20, "error[E0308]: mismatched types".to_string());
+    let content = lines.join("\n");
+
+    let out = truncate_exec_text(&content, TruncationPolicy::Bytes(300));
+
+    assert!(
+        out.contains("error[E0308]: mismatched types"),
+        "error line should survive truncation: {out}"
+    );
+    assert!(out.contains("chars truncated"), "marker missing: {out}");
+    assert!(out.starts_with("building object 0\n"));
+    assert!(out.ends_with("building object 39"));
+}
+
+#[test]
+fn truncate_exec_text_counts_suppressed_warnings_in_marker() {
+    let mut lines: Vec<String> = (0..40).map(|i| format!("building object {i}")).collect();
+    lines.insert(10, "warning: unused variable `x`".to_string());
+    lines.insert(20, "warning: unused import".to_string());
+    let content = lines.join("\n");
+
+    let out = truncate_exec_text(&content, TruncationPolicy::Bytes(300));
+
+    assert!(
+        out.contains("(0 error, 2 warning lines suppressed)"),
+        "marker should report suppressed warnings: {out}"
+    );
+}
+
+#[test]
+fn truncate_exec_text_under_limit_returns_original() {
+    let content = "error: short output\nstill under budget";
+
+    assert_eq!(
+        content,
+        truncate_exec_text(content, TruncationPolicy::Bytes(100)),
+    );
+}
+
+#[test]
+fn truncate_exec_text_falls_back_for_single_long_line() {
+    let content = "x".repeat(200);
+
+    assert_eq!(
+        truncate_text(&content, TruncationPolicy::Bytes(40)),
+        truncate_exec_text(&content, TruncationPolicy::Bytes(40)),
+    );
+}
+
 #[test]
 fn byte_count_conversion_clamps_non_positive_values() {
     assert_eq!(approx_tokens_from_byte_count_i64(/*bytes*/ -1), 0);
@@ -42,6 +42,17 @@ impl ChildTerminator for PipeChildTerminator {
                     crate::process_group::interrupt_process_group(self.process_group_id)
                 }
 
+                #[cfg(not(unix))]
+                {
+                    Err(crate::process::unsupported_signal(signal))
+                }
+            }
+            ProcessSignal::Terminate => {
+                #[cfg(unix)]
+                {
+                    crate::process_group::terminate_process_group(self.process_group_id).map(|_| ())
+                }
+
                 #[cfg(not(unix))]
                 {
                     Err(crate::process::unsupported_signal(signal))
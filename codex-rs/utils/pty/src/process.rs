@@ -21,6 +21,9 @@ use tokio::task::JoinHandle;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ProcessSignal {
     Interrupt,
+    /// Polite terminate (SIGTERM for Unix process groups) so TERM-aware
+    /// processes can run cleanup before a hard kill.
+    Terminate,
 }
 
 pub(crate) fn unsupported_signal(signal: ProcessSignal) -> io::Error {
@@ -29,6 +32,10 @@ pub(crate) fn unsupported_signal(signal: ProcessSignal) -> io::Error {
             io::ErrorKind::Unsupported,
             "process interrupt is not supported by this process backend",
         ),
+        ProcessSignal::Terminate => io::Error::new(
+            io::ErrorKind::Unsupported,
+            "process terminate is not supported by this process backend",
+        ),
     }
 }
 
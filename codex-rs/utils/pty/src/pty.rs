@@ -58,16 +58,19 @@ struct PtyChildTerminator {
 
 impl ChildTerminator for PtyChildTerminator {
     fn signal(&mut self, signal: ProcessSignal) -> std::io::Result<()> {
-        match signal {
-            ProcessSignal::Interrupt => {
-                #[cfg(unix)]
-                if let Some(process_group_id) = self.process_group_id {
-                    return crate::process_group::interrupt_process_group(process_group_id);
+        #[cfg(unix)]
+        if let Some(process_group_id) = self.process_group_id {
+            return match signal {
+                ProcessSignal::Interrupt => {
+                    crate::process_group::interrupt_process_group(process_group_id)
                 }
-
-                Err(crate::process::unsupported_signal(signal))
-            }
+                ProcessSignal::Terminate => {
+                    crate::process_group::terminate_process_group(process_group_id).map(|_| ())
+                }
+            };
         }
+
+        Err(crate::process::unsupported_signal(signal))
     }
 
     fn kill(&mut self) -> std::io::Result<()> {
@@ -102,6 +105,9 @@ impl ChildTerminator for RawPidTerminator {
             ProcessSignal::Interrupt => {
                 crate::process_group::interrupt_process_group(self.process_group_id)
             }
+            ProcessSignal::Terminate => {
+                crate::process_group::terminate_process_group(self.process_group_id).map(|_| ())
+            }
         }
     }
 
@@ -2,11 +2,15 @@ mod json;
 mod truncate;
 
 pub use json::to_ascii_json_string;
+pub use truncate::ApproxTokenCounter;
+pub use truncate::TokenCounter;
 pub use truncate::approx_bytes_for_tokens;
 pub use truncate::approx_token_count;
 pub use truncate::approx_tokens_from_byte_count;
+pub use truncate::install_token_counter;
 pub use truncate::truncate_middle_chars;
 pub use truncate::truncate_middle_with_token_budget;
+pub use truncate::truncate_middle_with_token_counter;
 
 // Truncate a &str to a byte budget at a char boundary (prefix)
 #[inline]
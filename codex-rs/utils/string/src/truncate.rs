@@ -1,7 +1,43 @@
 //! Utilities for truncating large chunks of output while preserving a prefix
 //! and suffix on UTF-8 boundaries.
 
+use std::sync::Arc;
+use std::sync::OnceLock;
+
 const APPROX_BYTES_PER_TOKEN: usize = 4;
+/// Upper bound on budget-refinement passes when a real tokenizer is installed.
+const MAX_REFINE_PASSES: usize = 4;
+
+/// Counts tokens in a piece of text for truncation budgeting.
+///
+/// The default is the 4-bytes-per-token heuristic, which badly over- or
+/// under-estimates CJK and code-dense output. Harnesses that have a real
+/// tokenizer (or model-provided counts) can install one process-wide with
+/// [`install_token_counter`]; `truncate_middle_with_token_budget` then
+/// enforces its budget against real counts, falling back to the byte
+/// heuristic only for the initial split estimate.
+pub trait TokenCounter: Send + Sync {
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// The default [`TokenCounter`]: the 4-bytes-per-token heuristic.
+pub struct ApproxTokenCounter;
+
+impl TokenCounter for ApproxTokenCounter {
+    fn count_tokens(&self, text: &str) -> usize {
+        approx_token_count(text)
+    }
+}
+
+static TOKEN_COUNTER: OnceLock<Arc<dyn TokenCounter>> = OnceLock::new();
+
+/// Install a process-wide token counter used by
+/// [`truncate_middle_with_token_budget`]. The first installation wins;
+/// subsequent calls are ignored so library consumers cannot swap the counter
+/// mid-session.
+pub fn install_token_counter(counter: Arc<dyn TokenCounter>) {
+    let _ = TOKEN_COUNTER.set(counter);
+}
 
 /// Truncate a string to `max_bytes` using a character-count marker.
 pub fn truncate_middle_chars(s: &str, max_bytes: usize) -> String {
@@ -13,6 +49,63 @@ pub fn truncate_middle_chars(s: &str, max_bytes: usize) -> String {
 /// truncated string and `Some(original_token_count)` if truncation occurred;
 /// otherwise returns the original string and `None`.
 pub fn truncate_middle_with_token_budget(s: &str, max_tokens: usize) -> (String, Option<u64>) {
+    match TOKEN_COUNTER.get() {
+        Some(counter) => truncate_middle_with_token_counter(s, max_tokens, counter.as_ref()),
+        None => truncate_middle_with_approx_budget(s, max_tokens),
+    }
+}
+
+/// [`truncate_middle_with_token_budget`] with an explicit [`TokenCounter`]
+/// instead of the process-wide one.
+///
+/// The budget is enforced against the counter: the initial split still uses
+/// the byte heuristic to pick a cut point, but the retained prefix and suffix
+/// are re-counted and the split shrunk (a bounded number of passes) until the
+/// retained content fits in `max_tokens`. The truncation marker is excluded
+/// from the budget, matching the heuristic path.
+pub fn truncate_middle_with_token_counter(
+    s: &str,
+    max_tokens: usize,
+    counter: &dyn TokenCounter,
+) -> (String, Option<u64>) {
+    if s.is_empty() {
+        return (String::new(), None);
+    }
+
+    let total_tokens = counter.count_tokens(s);
+    if max_tokens > 0 && total_tokens <= max_tokens {
+        return (s.to_string(), None);
+    }
+
+    let mut max_bytes = approx_bytes_for_tokens(max_tokens);
+    let mut passes = 0usize;
+    loop {
+        let (left_budget, right_budget) = split_budget(max_bytes);
+        let (_, left, right) = split_string(s, left_budget, right_budget);
+        let retained_tokens = counter.count_tokens(left) + counter.count_tokens(right);
+        if retained_tokens <= max_tokens || max_bytes == 0 {
+            let removed =
+                u64::try_from(total_tokens.saturating_sub(retained_tokens)).unwrap_or(u64::MAX);
+            let marker = format_truncation_marker(/*use_tokens*/ true, removed);
+            let truncated = assemble_truncated_output(left, right, &marker);
+            let total = u64::try_from(total_tokens).unwrap_or(u64::MAX);
+            return (truncated, Some(total));
+        }
+        // Retained content is over budget (the byte heuristic under-counted,
+        // e.g. CJK): shrink the byte budget proportionally and re-split. An
+        // empty split always fits, so cap the passes rather than risk a slow
+        // convergence against an adversarial counter.
+        passes += 1;
+        if passes >= MAX_REFINE_PASSES {
+            max_bytes = 0;
+        } else {
+            max_bytes = (max_bytes.saturating_mul(max_tokens) / retained_tokens)
+                .min(max_bytes.saturating_sub(1));
+        }
+    }
+}
+
+fn truncate_middle_with_approx_budget(s: &str, max_tokens: usize) -> (String, Option<u64>) {
     if s.is_empty() {
         return (String::new(), None);
     }
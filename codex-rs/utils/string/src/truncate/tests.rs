@@ -1,8 +1,20 @@
+use super::ApproxTokenCounter;
+use super::TokenCounter;
 use super::split_string;
 use super::truncate_middle_chars;
 use super::truncate_middle_with_token_budget;
+use super::truncate_middle_with_token_counter;
 use pretty_assertions::assert_eq;
 
+/// Counts one token per character, like a worst-case CJK tokenizer.
+struct CharTokenCounter;
+
+impl TokenCounter for CharTokenCounter {
+    fn count_tokens(&self, text: &str) -> usize {
+        text.chars().count()
+    }
+}
+
 #[test]
 fn split_string_works() {
     assert_eq!(
@@ -109,6 +121,40 @@ fn truncate_middle_tokens_handles_utf8_content() {
     assert_eq!(tokens, Some(16));
 }
 
+#[test]
+fn truncate_with_approx_counter_matches_heuristic_path() {
+    let s = "😀😀😀😀😀😀😀😀😀😀\nsecond line with text\n";
+    assert_eq!(
+        truncate_middle_with_token_counter(s, /*max_tokens*/ 8, &ApproxTokenCounter),
+        truncate_middle_with_token_budget(s, /*max_tokens*/ 8)
+    );
+}
+
+#[test]
+fn truncate_with_real_counter_enforces_budget_on_dense_content() {
+    // 40 chars, 120 bytes: the byte heuristic says 30 tokens, a per-char
+    // tokenizer says 40. The retained content must fit the budget by the
+    // real counter, not the heuristic.
+    let s = "あ".repeat(40);
+    let counter = CharTokenCounter;
+    let (out, total) = truncate_middle_with_token_counter(&s, /*max_tokens*/ 10, &counter);
+    assert_eq!(total, Some(40));
+    let retained: usize = out.chars().filter(|c| *c == 'あ').count();
+    assert!(retained <= 10, "retained {retained} tokens, budget 10");
+    assert!(out.contains("tokens truncated"));
+}
+
+#[test]
+fn truncate_with_real_counter_skips_truncation_when_counter_says_it_fits() {
+    // 16 bytes of emoji: the byte heuristic would truncate at a 4-token
+    // budget, but a per-char tokenizer counts exactly 4 tokens.
+    let s = "😀😀😀😀";
+    let (out, total) =
+        truncate_middle_with_token_counter(s, /*max_tokens*/ 4, &CharTokenCounter);
+    assert_eq!(out, s);
+    assert_eq!(total, None);
+}
+
 #[test]
 fn truncate_middle_bytes_handles_utf8_content() {
     let s = "😀😀😀😀😀😀😀😀😀😀\nsecond line with text\n";